@@ -0,0 +1,151 @@
+use crate::gamma::{gamma, EULER};
+use crate::harmonic::harmonic;
+
+/// x 不超过此值时使用含 ln x 的幂级数, 更大的 x 处级数的正负项相消严重
+const ABRAMOWITZ_SERIES_MAX: f64 = 0.5;
+
+/// 梯形公式步长 h = min(ABRAMOWITZ_STEP_SCALE · σ, ABRAMOWITZ_STEP_MAX), σ 为被积函数在鞍点处的宽度
+const ABRAMOWITZ_STEP_SCALE: f64 = 0.3;
+
+/// 被积函数在 |Im u| < π/4 的带状区域内有界, 梯形公式的误差约为 e^(-π^2/(2h)), 因此步长不超过 1/8
+const ABRAMOWITZ_STEP_MAX: f64 = 0.125;
+
+/// 由 Mellin 变换 ∫_0^∞ x^(s-1) f_m(x) dx = Γ(s) Γ((m + s + 1)/2) / 2 的留数得到的幂级数:
+/// f_m(x) = Σ_(k>=0) c_k x^k, 一般情形 c_k = (-1)^k Γ((m + 1 - k)/2) / (2 k!);
+/// k = m + 1 + 2j 时两个 Γ 的极点重合, c_k = (-1)^(k+j) (ψ(k + 1) + ψ(j + 1)/2 - ln x) / (k! j!)
+fn abramowitz_series(m: u32, x: f64) -> f64 {
+    let ln_x = x.ln();
+    let mut sum = 0.0;
+    // x^k / k!
+    let mut power = 1.0;
+    let mut k = 0u32;
+    loop {
+        let sign = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+        let term = if k > m && (k - m - 1).is_multiple_of(2) {
+            let j = (k - m - 1) / 2;
+            let sign = if j.is_multiple_of(2) { sign } else { -sign };
+            let psi = harmonic(k as u64) + 0.5 * harmonic(j as u64) - 1.5 * EULER;
+            let j_factorial: f64 = (1..=j).map(|i| i as f64).product();
+            sign * power / j_factorial * (psi - ln_x)
+        } else {
+            0.5 * sign * gamma(0.5 * (m as f64 + 1.0 - k as f64)) * power
+        };
+        sum += term;
+        if k > m + 2 && term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        k += 1;
+        power *= x / k as f64;
+    }
+}
+
+/// 令 t = e^u 得 f_m(x) = ∫_(-∞)^∞ e^φ(u) du, φ(u) = (m + 1)u - e^(2u) - x e^(-u) 为严格凹函数, 两侧双指数衰减,
+/// 梯形公式指数收敛. 以鞍点 t_0 (2t^3 - (m + 1)t - x = 0 的正根) 为中心, 宽度 σ = 1/√(-φ''), 并提出因子 e^φ(u_0)
+fn abramowitz_integral(m: u32, x: f64) -> f64 {
+    let a = m as f64 + 1.0;
+    // 由上方出发的 Newton 迭代单调收敛到正根
+    let mut t = (0.5 * x).cbrt().max((0.5 * a).sqrt());
+    loop {
+        let step = ((2.0 * t * t - a) * t - x) / (6.0 * t * t - a);
+        t -= step;
+        if step <= 4.0 * f64::EPSILON * t {
+            break;
+        }
+    }
+    let u0 = t.ln();
+    let h = (ABRAMOWITZ_STEP_SCALE / (4.0 * t * t + x / t).sqrt()).min(ABRAMOWITZ_STEP_MAX);
+    let phi = |u: f64| a * u - (2.0 * u).exp() - x * (-u).exp();
+    let phi0 = phi(u0);
+    let mut sum = 1.0;
+    for direction in [1.0, -1.0] {
+        let mut k = 1.0;
+        loop {
+            let term = (phi(u0 + direction * k * h) - phi0).exp();
+            sum += term;
+            if term <= 0.5 * f64::EPSILON * sum {
+                break;
+            }
+            k += 1.0;
+        }
+    }
+    phi0.exp() * sum * h
+}
+
+/// 计算 Abramowitz 函数 f_m(x) = ∫_0^∞ t^m e^(-t^2 - x/t) dt, 要求 x >= 0
+///
+/// 满足 f_m' = -f_(m-1) 与 2f_m = (m - 1) f_(m-2) + x f_(m-3), f_m(0) = Γ((m + 1)/2)/2.
+/// x <= 1/2 时使用由 Mellin 变换得到的含 ln x 的幂级数; 更大的 x 令 t = e^u 后以鞍点为中心使用梯形公式,
+/// 被积函数在鞍点两侧双指数衰减, 收敛很快. x 很大时 f_m(x) ~ √(π/3) (x/2)^(m/3) e^(-3(x/2)^(2/3)) 下溢为 0.
+/// x < 0 或为 NaN 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::abramowitz::abramowitz;
+/// // f_0(0) = √π/2, f_1(0) = 1/2
+/// assert!((abramowitz(0, 0.0) - 0.886226925452758).abs() < 1.0e-15);
+/// assert!((abramowitz(1, 0.0) - 0.5).abs() < 1.0e-15);
+/// ```
+pub fn abramowitz(m: u32, x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return 0.5 * gamma(0.5 * (m as f64 + 1.0));
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if x <= ABRAMOWITZ_SERIES_MAX {
+        abramowitz_series(m, x)
+    } else {
+        abramowitz_integral(m, x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_abramowitz() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(abramowitz(0, 0.0), 0.5 * PI.sqrt(), tol));
+        assert!(approx_eq(abramowitz(1, 0.0), 0.5, tol));
+        assert!(approx_eq(abramowitz(2, 0.0), 0.25 * PI.sqrt(), tol));
+        assert!(approx_eq(abramowitz(0, 1.0e-10), 0.8862269231367552, tol));
+        assert!(approx_eq(abramowitz(0, 0.1), 0.634321582377774, tol));
+        assert!(approx_eq(abramowitz(1, 0.5), 0.25317617388227037, tol));
+        assert!(approx_eq(abramowitz(2, 0.3), 0.32268812514882456, tol));
+        assert!(approx_eq(abramowitz(0, 1.0), 0.1500459645051639, tol));
+        assert!(approx_eq(abramowitz(1, 2.0), 0.05744661431416619, tol));
+        assert!(approx_eq(
+            abramowitz(2, 10.0) / 0.000538086964225593,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(abramowitz(5, 3.0), 0.1632057577005328, tol));
+        assert!(approx_eq(
+            abramowitz(0, 100.0) / 2.1198137244181837e-18,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            abramowitz(1, 1000.0) / 6.82514703163207e-82,
+            1.0,
+            1.0e-13
+        ));
+        // 2 f_3 = 2 f_1 + x f_0
+        let x = 1.5;
+        assert!(approx_eq(
+            2.0 * abramowitz(3, x),
+            2.0 * abramowitz(1, x) + x * abramowitz(0, x),
+            tol
+        ));
+        assert_eq!(abramowitz(2, f64::INFINITY), 0.0);
+        assert!(abramowitz(0, -1.0).is_nan());
+        assert!(abramowitz(0, f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,733 @@
+use crate::bessel::{bessel_ive, bessel_jy_pair, bessel_kve};
+use crate::utils::{double_exponential_sum, eval_poly};
+use std::f64::consts::{FRAC_1_PI, FRAC_PI_3, PI};
+
+/// √3
+const SQRT_3: f64 = 1.7320508075688772;
+
+/// 1 / √3
+const FRAC_1_SQRT_3: f64 = 0.5773502691896257;
+
+/// Ai(0) = 1 / (3^(2/3) Γ(2/3))
+const AIRY_AI_0: f64 = 0.3550280538878172;
+
+/// -Ai'(0) = 1 / (3^(1/3) Γ(1/3))
+const AIRY_AIP_0: f64 = 0.2588194037928068;
+
+/// |x| 不超过此值时使用 Maclaurin 级数
+const AIRY_SERIES_MAX_ARG: f64 = 1.0;
+
+/// 以 Maclaurin 级数计算 (f, f', g, g'), 其中
+/// f(x) = Σ 3^k (1/3)_k x^(3k) / (3k)!, g(x) = Σ 3^k (2/3)_k x^(3k+1) / (3k+1)!,
+/// 于是 Ai = c1 f - c2 g, Bi = √3 (c1 f + c2 g), c1 = Ai(0), c2 = -Ai'(0)
+fn airy_series(x: f64) -> (f64, f64, f64, f64) {
+    let x3 = x * x * x;
+    let (mut f, mut fp, mut g, mut gp) = (1.0, 0.0, x, 1.0);
+    // a 为 f 的第 k 项系数乘以 x^(3k), b 为 g 的第 k 项系数乘以 x^(3k)
+    let (mut a, mut b) = (1.0, 1.0);
+    let mut k = 1.0;
+    loop {
+        let m = 3.0 * k;
+        a *= x3 / ((m - 1.0) * m);
+        b *= x3 / (m * (m + 1.0));
+        f += a;
+        fp += m * a;
+        g += b * x;
+        gp += (m + 1.0) * b;
+        if a.abs() <= f64::EPSILON * f.abs() && b.abs() <= f64::EPSILON * gp.abs() {
+            break;
+        }
+        k += 1.0;
+    }
+    // fp 中每项多乘了 x, 此处统一除去
+    (f, if x == 0.0 { 0.0 } else { fp / x }, g, gp)
+}
+
+/// 计算 Ai(x) 或 Ai'(x) (derivative 为真时), scaled 为真且 x > 0 时乘以 e^ζ, ζ = (2/3) x^(3/2)
+///
+/// x > 0 时 Ai(x) = √(x/3) K_(1/3)(ζ) / π, Ai'(x) = -x K_(2/3)(ζ) / (√3 π);
+/// x < 0 时记 t = -x, Ai(x) = √t (J_(1/3)(ζ) - Y_(1/3)(ζ) / √3) / 2, Ai'(x) = t (J_(2/3)(ζ) + Y_(2/3)(ζ) / √3) / 2
+fn airy_a_general(x: f64, derivative: bool, scaled: bool) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return match (derivative, x > 0.0, scaled) {
+            (false, _, _) | (true, true, false) => 0.0,
+            (true, true, true) => f64::NEG_INFINITY,
+            (true, false, _) => f64::NAN,
+        };
+    }
+    if x.abs() <= AIRY_SERIES_MAX_ARG {
+        let (f, fp, g, gp) = airy_series(x);
+        let v = if derivative {
+            AIRY_AI_0 * fp - AIRY_AIP_0 * gp
+        } else {
+            AIRY_AI_0 * f - AIRY_AIP_0 * g
+        };
+        return if scaled && x > 0.0 {
+            v * (2.0 / 3.0 * x * x.sqrt()).exp()
+        } else {
+            v
+        };
+    }
+    let t = x.abs();
+    let zeta = 2.0 / 3.0 * t * t.sqrt();
+    if x > 0.0 {
+        let v = if derivative {
+            -t * FRAC_1_SQRT_3 * FRAC_1_PI * bessel_kve(2.0 / 3.0, zeta)
+        } else {
+            (t / 3.0).sqrt() * FRAC_1_PI * bessel_kve(1.0 / 3.0, zeta)
+        };
+        if scaled {
+            v
+        } else {
+            let half = (-0.5 * zeta).exp();
+            v * half * half
+        }
+    } else if derivative {
+        let (j, y) = bessel_jy_pair(2.0 / 3.0, zeta);
+        0.5 * t * (j + y * FRAC_1_SQRT_3)
+    } else {
+        let (j, y) = bessel_jy_pair(1.0 / 3.0, zeta);
+        0.5 * t.sqrt() * (j - y * FRAC_1_SQRT_3)
+    }
+}
+
+/// 计算 Bi(x) 或 Bi'(x) (derivative 为真时), scaled 为真且 x > 0 时乘以 e^(-ζ), ζ = (2/3) x^(3/2)
+///
+/// x > 0 时 Bi(x) = √x (2 I_(1/3)(ζ) / √3 + K_(1/3)(ζ) / π), Bi'(x) = x (2 I_(2/3)(ζ) / √3 + K_(2/3)(ζ) / π),
+/// 两项均为正, 没有抵消; x < 0 时记 t = -x, Bi(x) = -√t (J_(1/3)(ζ) / √3 + Y_(1/3)(ζ)) / 2,
+/// Bi'(x) = t (J_(2/3)(ζ) / √3 - Y_(2/3)(ζ)) / 2
+fn airy_b_general(x: f64, derivative: bool, scaled: bool) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return match (derivative, x > 0.0, scaled) {
+            (false, false, _) | (false, true, true) => 0.0,
+            (true, false, _) => f64::NAN,
+            _ => f64::INFINITY,
+        };
+    }
+    if x.abs() <= AIRY_SERIES_MAX_ARG {
+        let (f, fp, g, gp) = airy_series(x);
+        let v = if derivative {
+            SQRT_3 * (AIRY_AI_0 * fp + AIRY_AIP_0 * gp)
+        } else {
+            SQRT_3 * (AIRY_AI_0 * f + AIRY_AIP_0 * g)
+        };
+        return if scaled && x > 0.0 {
+            v * (-2.0 / 3.0 * x * x.sqrt()).exp()
+        } else {
+            v
+        };
+    }
+    let t = x.abs();
+    let zeta = 2.0 / 3.0 * t * t.sqrt();
+    if x > 0.0 {
+        let nu = if derivative { 2.0 / 3.0 } else { 1.0 / 3.0 };
+        let prefactor = if derivative { t } else { t.sqrt() };
+        // K 项带因子 e^(-2ζ), ζ 很大时可以忽略
+        let k = if zeta < 400.0 {
+            bessel_kve(nu, zeta) * (-2.0 * zeta).exp()
+        } else {
+            0.0
+        };
+        let v = prefactor * (2.0 * FRAC_1_SQRT_3 * bessel_ive(nu, zeta) + FRAC_1_PI * k);
+        if scaled {
+            v
+        } else {
+            let half = (0.5 * zeta).exp();
+            v * half * half
+        }
+    } else if derivative {
+        let (j, y) = bessel_jy_pair(2.0 / 3.0, zeta);
+        0.5 * t * (j * FRAC_1_SQRT_3 - y)
+    } else {
+        let (j, y) = bessel_jy_pair(1.0 / 3.0, zeta);
+        -0.5 * t.sqrt() * (j * FRAC_1_SQRT_3 + y)
+    }
+}
+
+/// 计算 Airy 函数 Ai(x)
+///
+/// |x| <= 1 时使用 Maclaurin 级数, x > 1 时由 K_(1/3) 计算, x < -1 时由 J_(1/3), Y_(1/3) 计算 (见 `bessel_jy_pair`).
+/// x → ±∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_ai;
+/// assert!((airy_ai(1.0) - 0.1352924163128814).abs() < 1.0e-16);
+/// ```
+pub fn airy_ai(x: f64) -> f64 {
+    airy_a_general(x, false, false)
+}
+
+/// 计算 Airy 函数的导数 Ai'(x)
+///
+/// 方法与 `airy_ai` 相同, 阶数 1/3 换为 2/3. x → +∞ 时返回 0, x → -∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_ai_prime;
+/// assert!((airy_ai_prime(1.0) + 0.1591474412967932).abs() < 1.0e-16);
+/// ```
+pub fn airy_ai_prime(x: f64) -> f64 {
+    airy_a_general(x, true, false)
+}
+
+/// 计算 Airy 函数 Bi(x)
+///
+/// |x| <= 1 时使用 Maclaurin 级数, x > 1 时由 I_(1/3), K_(1/3) 计算, x < -1 时由 J_(1/3), Y_(1/3) 计算.
+/// x 约大于 104 时上溢为 +∞, x → -∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bi;
+/// assert!((airy_bi(1.0) - 1.2074235949528713).abs() < 1.0e-15);
+/// ```
+pub fn airy_bi(x: f64) -> f64 {
+    airy_b_general(x, false, false)
+}
+
+/// 计算 Airy 函数的导数 Bi'(x)
+///
+/// 方法与 `airy_bi` 相同, 阶数 1/3 换为 2/3. x → +∞ 时返回 +∞, x → -∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bi_prime;
+/// assert!((airy_bi_prime(1.0) - 0.9324359333927756).abs() < 1.0e-15);
+/// ```
+pub fn airy_bi_prime(x: f64) -> f64 {
+    airy_b_general(x, true, false)
+}
+
+/// 计算指数缩放的 Airy 函数, x > 0 时为 e^ζ Ai(x), x <= 0 时为 Ai(x), 其中 ζ = (2/3) x^(3/2)
+///
+/// x > 1 时直接由 `bessel_kve` 计算, 不经过 e^(-ζ), 因此 x 很大 (如 10^5) 时不会下溢
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_aie;
+/// assert!((airy_aie(1.0) - 0.2635136447491401).abs() < 1.0e-15);
+/// ```
+pub fn airy_aie(x: f64) -> f64 {
+    airy_a_general(x, false, true)
+}
+
+/// 计算指数缩放的 Airy 函数导数, x > 0 时为 e^ζ Ai'(x), x <= 0 时为 Ai'(x), 其中 ζ = (2/3) x^(3/2)
+///
+/// 方法与 `airy_aie` 相同. x → +∞ 时 e^ζ Ai'(x) ~ -x^(1/4) / (2√π), 返回 -∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_aie_prime;
+/// assert!((airy_aie_prime(1.0) + 0.30997688896051484).abs() < 1.0e-15);
+/// ```
+pub fn airy_aie_prime(x: f64) -> f64 {
+    airy_a_general(x, true, true)
+}
+
+/// 计算指数缩放的 Airy 函数, x > 0 时为 e^(-ζ) Bi(x), x <= 0 时为 Bi(x), 其中 ζ = (2/3) x^(3/2)
+///
+/// x > 1 时直接由 `bessel_ive` 与 `bessel_kve` 计算, 不经过 e^ζ, 因此 x 很大 (如 10^5) 时不会上溢
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bie;
+/// assert!((airy_bie(1.0) - 0.6199119435726785).abs() < 1.0e-15);
+/// ```
+pub fn airy_bie(x: f64) -> f64 {
+    airy_b_general(x, false, true)
+}
+
+/// 计算指数缩放的 Airy 函数导数, x > 0 时为 e^(-ζ) Bi'(x), x <= 0 时为 Bi'(x), 其中 ζ = (2/3) x^(3/2)
+///
+/// 方法与 `airy_bie` 相同. x → +∞ 时 e^(-ζ) Bi'(x) ~ x^(1/4) / √π, 返回 +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bie_prime;
+/// assert!((airy_bie_prime(1.0) - 0.47872857060498475).abs() < 1.0e-15);
+/// ```
+pub fn airy_bie_prime(x: f64) -> f64 {
+    airy_b_general(x, true, true)
+}
+
+/// t 不小于此值时零点的渐近展开已达到机器精度, 不再作 Newton 迭代
+const AIRY_ZERO_ASYMPTOTIC_MIN_ARG: f64 = 60.0;
+
+/// 计算零点的渐近展开 -T(t) (derivative 为假) 或 -U(t) (derivative 为真), 其中
+/// T(t) ~ t^(2/3) (1 + 5/48 t^(-2) - 5/36 t^(-4) + ...), U(t) ~ t^(2/3) (1 - 7/48 t^(-2) + 35/288 t^(-4) - ...).
+/// t < 2 时级数很快发散, 只保留首项修正
+fn airy_zero_asymptotic(t: f64, derivative: bool) -> f64 {
+    let coeffs: &[f64] = if derivative {
+        &[
+            -476.9464414881744,
+            15.016855549125514,
+            -0.8739535108024692,
+            0.12152777777777778,
+            -0.14583333333333334,
+        ]
+    } else {
+        &[
+            485.5290969259571,
+            -15.509155201673648,
+            0.9298442322530864,
+            -0.1388888888888889,
+            0.10416666666666667,
+        ]
+    };
+    let s = 1.0 / (t * t);
+    let r = if t < 2.0 {
+        coeffs[coeffs.len() - 1]
+    } else {
+        eval_poly(s, coeffs)
+    };
+    -t.powf(2.0 / 3.0) * s.mul_add(r, 1.0)
+}
+
+/// 计算 Ai, Bi, Ai' 或 Bi' 的第 k 个零点 (均为负数), offset 为 t = 3π (4k - offset) / 8 中的偏移
+///
+/// 以渐近展开作为初值, t 较小时再作 Newton 迭代, 其中利用了 Airy 方程 w'' = x w
+fn airy_zero_general(k: u32, offset: f64, bi: bool, derivative: bool) -> f64 {
+    if k == 0 {
+        return f64::NAN;
+    }
+    let t = 0.375 * PI * (4.0 * k as f64 - offset);
+    let mut x = airy_zero_asymptotic(t, derivative);
+    if t >= AIRY_ZERO_ASYMPTOTIC_MIN_ARG {
+        return x;
+    }
+    let f = |x: f64| match (bi, derivative) {
+        (false, false) => (airy_ai(x), airy_ai_prime(x)),
+        (false, true) => (airy_ai_prime(x), x * airy_ai(x)),
+        (true, false) => (airy_bi(x), airy_bi_prime(x)),
+        (true, true) => (airy_bi_prime(x), x * airy_bi(x)),
+    };
+    for _ in 0..10 {
+        let (v, dv) = f(x);
+        let step = v / dv;
+        x -= step;
+        if step.abs() <= f64::EPSILON * x.abs() {
+            break;
+        }
+    }
+    x
+}
+
+/// 计算 Airy 函数 Ai(x) 的第 k 个零点 a_k (k >= 1), a_1 > a_2 > ... 均为负数
+///
+/// a_k = -T(3π (4k - 1) / 8), 以渐近展开作为初值并以 Newton 迭代修正. k = 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_ai_zero;
+/// assert!((airy_ai_zero(1) + 2.338107410459767).abs() < 1.0e-15);
+/// ```
+pub fn airy_ai_zero(k: u32) -> f64 {
+    airy_zero_general(k, 1.0, false, false)
+}
+
+/// 计算 Airy 函数导数 Ai'(x) 的第 k 个零点 a'_k (k >= 1)
+///
+/// a'_k = -U(3π (4k - 3) / 8), 方法与 `airy_ai_zero` 相同. k = 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_ai_prime_zero;
+/// assert!((airy_ai_prime_zero(1) + 1.018792971647471).abs() < 1.0e-15);
+/// ```
+pub fn airy_ai_prime_zero(k: u32) -> f64 {
+    airy_zero_general(k, 3.0, false, true)
+}
+
+/// 计算 Airy 函数 Bi(x) 的第 k 个零点 b_k (k >= 1)
+///
+/// b_k = -T(3π (4k - 3) / 8), 方法与 `airy_ai_zero` 相同. k = 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bi_zero;
+/// assert!((airy_bi_zero(1) + 1.173713222709128).abs() < 1.0e-15);
+/// ```
+pub fn airy_bi_zero(k: u32) -> f64 {
+    airy_zero_general(k, 3.0, true, false)
+}
+
+/// 计算 Airy 函数导数 Bi'(x) 的第 k 个零点 b'_k (k >= 1)
+///
+/// b'_k = -U(3π (4k - 1) / 8), 方法与 `airy_ai_zero` 相同. k = 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::airy_bi_prime_zero;
+/// assert!((airy_bi_prime_zero(1) + 2.294439682614123).abs() < 1.0e-15);
+/// ```
+pub fn airy_bi_prime_zero(k: u32) -> f64 {
+    airy_zero_general(k, 1.0, true, true)
+}
+
+/// 双指数求积公式的步长
+const DE_STEP: f64 = 1.0 / 64.0;
+
+/// 双指数求积公式的截断范围 [-DE_RANGE, DE_RANGE]
+const DE_RANGE: f64 = 4.0;
+
+/// 由双指数 (exp-sinh) 求积公式计算 ∫_0^∞ f(t) dt, 其中 t = c e^((π/2) sinh s), c 为被积函数衰减的特征长度
+fn scorer_exp_sinh(c: f64, f: impl Fn(f64) -> f64) -> f64 {
+    double_exponential_sum(DE_STEP, DE_RANGE, |y, dy| {
+        let t = c * y.exp();
+        dy * t * f(t)
+    })
+}
+
+/// 计算 Gi(x) (hi 为假) 或 Hi(x) (hi 为真), scaled 为真且 hi 为真, x > 0 时乘以 e^(-ζ), ζ = (2/3) x^(3/2)
+///
+/// x >= 0 时 Gi(x) = -(1/π) ∫_0^∞ e^(-t^3/3 - xt/2) cos(√3 xt/2 + 2π/3) dt, 被积函数指数衰减, Hi(x) = Bi(x) - Gi(x);
+/// x < 0 时 Hi(x) = (1/π) ∫_0^∞ e^(-t^3/3 + xt) dt, 被积函数为正, Gi(x) = Bi(x) - Hi(x).
+/// 两种情形下相减的两项中后者都远小于前者或与前者同号 (x >= 0 时 Bi >= Bi(0) > 2 Gi), 没有严重的抵消
+fn scorer_general(x: f64, hi: bool, scaled: bool) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return if hi && x > 0.0 && !scaled {
+            f64::INFINITY
+        } else {
+            0.0
+        };
+    }
+    let c = 1.0 / x.abs().max(1.0);
+    if x >= 0.0 {
+        let gi = -FRAC_1_PI
+            * scorer_exp_sinh(c, |t| {
+                (-t * (t * t / 3.0 + 0.5 * x)).exp()
+                    * (0.5 * SQRT_3 * x).mul_add(t, 2.0 * FRAC_PI_3).cos()
+            });
+        if !hi {
+            gi
+        } else if scaled {
+            airy_bie(x) - gi * (-2.0 / 3.0 * x * x.sqrt()).exp()
+        } else {
+            airy_bi(x) - gi
+        }
+    } else {
+        let h = FRAC_1_PI * scorer_exp_sinh(c, |t| (t * (x - t * t / 3.0)).exp());
+        if hi {
+            h
+        } else {
+            airy_bi(x) - h
+        }
+    }
+}
+
+/// 计算 Scorer 函数 Gi(x) = (1/π) ∫_0^∞ sin(t^3/3 + xt) dt, 它是非齐次 Airy 方程 w'' - x w = -1/π 的解
+///
+/// x >= 0 时由指数衰减的积分表示以双指数求积公式计算, x < 0 时由 Gi(x) = Bi(x) - Hi(x) 计算.
+/// x → ±∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::scorer_gi;
+/// assert!((scorer_gi(1.0) - 0.23521843981043794).abs() < 1.0e-15);
+/// ```
+pub fn scorer_gi(x: f64) -> f64 {
+    scorer_general(x, false, false)
+}
+
+/// 计算 Scorer 函数 Hi(x) = (1/π) ∫_0^∞ e^(-t^3/3 + xt) dt, 它是非齐次 Airy 方程 w'' - x w = 1/π 的解
+///
+/// x < 0 时由积分表示以双指数求积公式计算, x >= 0 时由 Hi(x) = Bi(x) - Gi(x) 计算.
+/// x 约大于 104 时上溢为 +∞, x → -∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::scorer_hi;
+/// assert!((scorer_hi(1.0) - 0.9722051551424333).abs() < 1.0e-15);
+/// ```
+pub fn scorer_hi(x: f64) -> f64 {
+    scorer_general(x, true, false)
+}
+
+/// 计算指数缩放的 Scorer 函数, x > 0 时为 e^(-ζ) Hi(x), x <= 0 时为 Hi(x), 其中 ζ = (2/3) x^(3/2)
+///
+/// x > 0 时由 e^(-ζ) Hi(x) = e^(-ζ) Bi(x) - e^(-ζ) Gi(x) 计算 (见 `airy_bie`), 因此 x 很大时不会上溢.
+/// Gi 在实轴上有界, 不需要缩放
+///
+/// # Example
+///
+/// ```
+/// use special_functions::airy::scorer_hie;
+/// assert!((scorer_hie(1.0) - 0.4991467698618623).abs() < 1.0e-15);
+/// ```
+pub fn scorer_hie(x: f64) -> f64 {
+    scorer_general(x, true, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_airy_ai() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_ai(0.5), 0.23169360648083348, tol));
+        assert!(approx_eq(airy_ai(-0.5), 0.4757280916105396, tol));
+        assert!(approx_eq(airy_ai(2.5), 0.01572592338047049, tol));
+        assert!(approx_eq(airy_ai(-2.5), -0.11232506769296609, tol));
+        assert!(approx_eq(airy_ai(-7.0), 0.18428083525050565, tol));
+        assert!(approx_eq(airy_ai(10.0) / 1.1047532552898686e-10, 1.0, tol));
+        assert!(approx_eq(airy_ai(-100.0), 0.1767533932395529, 1.0e-13));
+        assert_eq!(airy_ai(0.0), 0.3550280538878172);
+        assert_eq!(airy_ai(1.0e5), 0.0);
+        assert_eq!(airy_ai(f64::INFINITY), 0.0);
+        assert!(airy_ai(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_airy_ai_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_ai_prime(0.5), -0.2249105326646839, tol));
+        assert!(approx_eq(airy_ai_prime(-0.5), -0.20408167033954738, tol));
+        assert!(approx_eq(airy_ai_prime(2.5), -0.026250881035903232, tol));
+        assert!(approx_eq(airy_ai_prime(-2.5), 0.6788527342647943, tol));
+        assert!(approx_eq(airy_ai_prime(-7.0), -0.7710081684101265, tol));
+        assert!(approx_eq(
+            airy_ai_prime(10.0) / -3.5206336767389237e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_ai_prime(-100.0),
+            -0.2422970316605838,
+            1.0e-12
+        ));
+        assert_eq!(airy_ai_prime(0.0), -0.2588194037928068);
+        assert!(airy_ai_prime(f64::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_airy_bi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_bi(0.5), 0.8542770431031554, tol));
+        assert!(approx_eq(airy_bi(-0.5), 0.38035265975105387, tol));
+        assert!(approx_eq(airy_bi(2.5) / 6.481660738460579, 1.0, 1.0e-14));
+        assert!(approx_eq(airy_bi(-2.5), -0.4324224718407053, tol));
+        assert!(approx_eq(airy_bi(-7.0), 0.293762071854414, tol));
+        assert!(approx_eq(airy_bi(10.0) / 455641153.54822516, 1.0, 1.0e-14));
+        assert!(approx_eq(airy_bi(-100.0), 0.02427388768016013, 1.0e-13));
+        assert_eq!(airy_bi(1.0e5), f64::INFINITY);
+        assert_eq!(airy_bi(f64::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_airy_bi_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_bi_prime(0.5), 0.5445725641405923, tol));
+        assert!(approx_eq(airy_bi_prime(-0.5), 0.5059337136238472, tol));
+        assert!(approx_eq(
+            airy_bi_prime(2.5) / 9.421423317334302,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(airy_bi_prime(-2.5), -0.2204201548746296, tol));
+        assert!(approx_eq(airy_bi_prime(-7.0), 0.4982445900581135, tol));
+        assert!(approx_eq(
+            airy_bi_prime(10.0) / 1429236134.4828658,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            airy_bi_prime(-100.0),
+            1.7675948932340608,
+            1.0e-13
+        ));
+        assert_eq!(airy_bi_prime(0.0), 0.4482883573538264);
+    }
+
+    #[test]
+    fn test_airy_aie() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_aie(0.5), 0.29327715912994734, tol));
+        assert!(approx_eq(airy_aie(2.5), 0.21932220512871206, tol));
+        assert!(approx_eq(airy_aie(10.0), 0.15812366685434615, tol));
+        assert!(approx_eq(airy_aie(1.0e5), 0.015863355851287307, tol));
+        assert_eq!(airy_aie(-2.5), airy_ai(-2.5));
+        assert_eq!(airy_aie(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_airy_aie_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_aie_prime(0.5), -0.28469116209194256, tol));
+        assert!(approx_eq(airy_aie_prime(2.5), -0.36610893847516224, tol));
+        assert!(approx_eq(airy_aie_prime(10.0), -0.5039093607113109, tol));
+        assert!(approx_eq(
+            airy_aie_prime(1.0e5) / -5.0164336220411085,
+            1.0,
+            tol
+        ));
+        assert_eq!(airy_aie_prime(-2.5), airy_ai_prime(-2.5));
+    }
+
+    #[test]
+    fn test_airy_bie() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_bie(0.5), 0.6748924111156303, tol));
+        assert!(approx_eq(airy_bie(2.5), 0.4647504801960925, tol));
+        assert!(approx_eq(airy_bie(10.0), 0.31834010533673446, tol));
+        assert!(approx_eq(airy_bie(1.0e5), 0.03172671191159268, tol));
+        assert_eq!(airy_bie(-2.5), airy_bi(-2.5));
+        assert_eq!(airy_bie(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_airy_bie_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_bie_prime(0.5), 0.43022096146376937, tol));
+        assert!(approx_eq(airy_bie_prime(2.5), 0.6755384441644994, tol));
+        assert!(approx_eq(airy_bie_prime(10.0), 0.9985559426738374, tol));
+        assert!(approx_eq(
+            airy_bie_prime(1.0e5) / 10.032867151545974,
+            1.0,
+            tol
+        ));
+        assert_eq!(airy_bie_prime(-2.5), airy_bi_prime(-2.5));
+    }
+
+    #[test]
+    fn test_airy_ai_zero() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_ai_zero(1) / -2.338107410459767, 1.0, tol));
+        assert!(approx_eq(airy_ai_zero(2) / -4.08794944413097, 1.0, tol));
+        assert!(approx_eq(airy_ai_zero(10) / -12.828776752865757, 1.0, tol));
+        assert!(approx_eq(airy_ai_zero(100) / -60.4555572741167, 1.0, tol));
+        assert!(airy_ai_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_airy_ai_prime_zero() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            airy_ai_prime_zero(1) / -1.018792971647471,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_ai_prime_zero(2) / -3.2481975821798366,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_ai_prime_zero(10) / -12.384788371845747,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_ai_prime_zero(100) / -60.253295964424794,
+            1.0,
+            tol
+        ));
+        assert!(airy_ai_prime_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_airy_bi_zero() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(airy_bi_zero(1) / -1.173713222709128, 1.0, tol));
+        assert!(approx_eq(airy_bi_zero(2) / -3.271093302836353, 1.0, tol));
+        assert!(approx_eq(airy_bi_zero(10) / -12.386417138582738, 1.0, tol));
+        assert!(approx_eq(airy_bi_zero(100) / -60.25336482580837, 1.0, tol));
+        assert!(airy_bi_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_airy_bi_prime_zero() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            airy_bi_prime_zero(1) / -2.294439682614123,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_bi_prime_zero(2) / -4.073155089071828,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_bi_prime_zero(10) / -12.827258309177218,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            airy_bi_prime_zero(100) / -60.45548887257141,
+            1.0,
+            tol
+        ));
+        assert!(airy_bi_prime_zero(0).is_nan());
+    }
+
+    #[test]
+    fn test_scorer_gi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(scorer_gi(0.0), 0.20497554248200025, tol));
+        assert!(approx_eq(scorer_gi(0.5), 0.2447210432765582, tol));
+        assert!(approx_eq(scorer_gi(-0.5), 0.08724360928458474, tol));
+        assert!(approx_eq(scorer_gi(2.5), 0.13827755841857412, tol));
+        assert!(approx_eq(scorer_gi(-2.5), -0.5502653437386175, tol));
+        assert!(approx_eq(scorer_gi(-7.0), 0.24854088801593676, tol));
+        assert!(approx_eq(scorer_gi(10.0), 0.03189600510067959, tol));
+        assert!(approx_eq(scorer_gi(-100.0), 0.021090795184392632, 1.0e-13));
+        assert_eq!(scorer_gi(f64::INFINITY), 0.0);
+        assert!(scorer_gi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_scorer_hi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(scorer_hi(0.0), 0.4099510849640005, tol));
+        assert!(approx_eq(scorer_hi(0.5), 0.6095559998265973, tol));
+        assert!(approx_eq(scorer_hi(-0.5), 0.2931090504664691, tol));
+        assert!(approx_eq(scorer_hi(2.5) / 6.343383180042005, 1.0, 1.0e-14));
+        assert!(approx_eq(scorer_hi(-2.5), 0.11784287189791218, tol));
+        assert!(approx_eq(scorer_hi(-7.0), 0.04522118383847726, tol));
+        assert!(approx_eq(
+            scorer_hi(10.0) / 455641153.51632914,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(scorer_hi(-100.0), 0.0031830924957675, tol));
+        assert_eq!(scorer_hi(1.0e5), f64::INFINITY);
+        assert_eq!(scorer_hi(f64::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_scorer_hie() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(scorer_hie(0.5), 0.48155890615838014, tol));
+        assert!(approx_eq(scorer_hie(2.5), 0.4548356506071192, tol));
+        assert!(approx_eq(scorer_hie(10.0), 0.31834010531444985, tol));
+        assert!(approx_eq(scorer_hie(50.0), 0.21223196271406528, tol));
+        assert!(approx_eq(scorer_hie(1.0e4), 0.05641896423175517, tol));
+        assert_eq!(scorer_hie(-2.5), scorer_hi(-2.5));
+        assert_eq!(scorer_hie(f64::INFINITY), 0.0);
+    }
+}
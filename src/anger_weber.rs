@@ -0,0 +1,190 @@
+use crate::bessel::{bessel_jn, bessel_jv, bessel_yv};
+use crate::gamma::rgamma;
+use crate::triangle::sincospi;
+use crate::utils::double_exponential_sum;
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_1_PI, PI};
+
+/// 幂级数的适用范围: x <= 2 或 x <= |ν|/2
+const ANGER_WEBER_SERIES_MAX_ARG: f64 = 2.0;
+
+/// 双指数求积公式的步长
+const DE_STEP: f64 = 1.0 / 16.0;
+
+/// 双指数求积公式的截断范围 [-DE_RANGE, DE_RANGE]
+const DE_RANGE: f64 = 4.0;
+
+/// 由幂级数计算 (J_ν(x), E_ν(x)):
+/// J_ν(x) = cos(νπ/2) S_1 + sin(νπ/2) S_2, E_ν(x) = sin(νπ/2) S_1 - cos(νπ/2) S_2, 其中
+/// S_1 = Σ (-1)^k (x/2)^(2k) / (Γ(k + 1 + ν/2) Γ(k + 1 - ν/2)),
+/// S_2 = Σ (-1)^k (x/2)^(2k+1) / (Γ(k + 3/2 + ν/2) Γ(k + 3/2 - ν/2)).
+/// ν 为偶数时 S_1 含 Γ 函数的极点, 但它在 E_ν(x) 中的系数为零, 此时不计算 S_1 (J_ν(x) 由调用者按整数阶处理);
+/// ν 为奇数时同理不计算 S_2
+fn anger_weber_series(nu: f64, x: f64) -> (f64, f64) {
+    let (s, c) = sincospi(0.5 * nu);
+    let half = 0.5 * x;
+    let sum = |offset: f64, first: f64| {
+        let mut term = first;
+        let mut sum = term;
+        let mut k = 0.0;
+        loop {
+            let a = k + offset + 0.5 * nu;
+            let b = k + offset - 0.5 * nu;
+            term *= -half * half / (a * b);
+            sum += term;
+            k += 1.0;
+            if k > 0.5 * nu.abs() && term.abs() <= f64::EPSILON * sum.abs() {
+                return sum;
+            }
+        }
+    };
+    let s1 = if s == 0.0 {
+        0.0
+    } else {
+        sum(1.0, rgamma(1.0 + 0.5 * nu) * rgamma(1.0 - 0.5 * nu))
+    };
+    let s2 = if c == 0.0 || x == 0.0 {
+        0.0
+    } else {
+        sum(1.5, half * rgamma(1.5 + 0.5 * nu) * rgamma(1.5 - 0.5 * nu))
+    };
+    (c * s1 + s * s2, s * s1 - c * s2)
+}
+
+/// 由双指数 (exp-sinh) 求积公式计算 A_ν(x) = (1/π) ∫_0^∞ e^(-νt - x sinh t) dt.
+/// 代换 u = sinh t 后被积函数 e^(-xu - ν asinh u) / √(1 + u^2) 只有指数衰减, 再取 u = e^((π/2) sinh s) / x.
+/// 要求 x >= 2|ν|, x > 0
+fn anger_weber_a(nu: f64, x: f64) -> f64 {
+    let sum = double_exponential_sum(DE_STEP, DE_RANGE, |y, dy| {
+        let u = y.exp() / x;
+        dy * u * (-x * u - nu * u.asinh()).exp() / u.hypot(1.0)
+    });
+    FRAC_1_PI * sum
+}
+
+/// 由定义 J_ν(x) = (1/π) ∫_0^π cos(νθ - x sin θ) dθ, E_ν(x) = (1/π) ∫_0^π sin(νθ - x sin θ) dθ
+/// 计算 (J_ν(x), E_ν(x)). 将 [0, π] 等分, 每段约含一个振荡周期, 在每段上使用 tanh-sinh 求积公式
+fn anger_weber_integral(nu: f64, x: f64) -> (f64, f64) {
+    let panels = (0.5 * (nu.abs() + x)).ceil().max(1.0);
+    let width = PI / panels;
+    // 实部与虚部分别为 J_ν 与 E_ν 的被积函数
+    let mut sum = Complex64::new(0.0, 0.0);
+    let mut i = 0.0;
+    while i < panels {
+        let mid = (i + 0.5) * width;
+        sum += double_exponential_sum(DE_STEP, DE_RANGE, |y, dy| {
+            let theta = mid + 0.5 * width * y.tanh();
+            let (sp, cp) = (nu * theta - x * theta.sin()).sin_cos();
+            Complex64::new(cp, sp) * (dy / y.cosh().powi(2))
+        });
+        i += 1.0;
+    }
+    let factor = 0.5 * width * FRAC_1_PI;
+    (factor * sum.re, factor * sum.im)
+}
+
+/// 计算 (J_ν(x), E_ν(x)), 要求 ν, x 有限且非 NaN
+fn anger_weber_general(nu: f64, x: f64) -> (f64, f64) {
+    if x < 0.0 {
+        // J_ν(-x) = J_(-ν)(x), E_ν(-x) = -E_(-ν)(x)
+        let (j, e) = anger_weber_general(-nu, -x);
+        return (j, -e);
+    }
+    let a = nu.abs();
+    if x <= ANGER_WEBER_SERIES_MAX_ARG || x <= 0.5 * a {
+        anger_weber_series(nu, x)
+    } else if x >= 2.0 * a {
+        // J_ν(x) = J_ν(x) + sin(νπ) A_ν(x), E_ν(x) = -Y_ν(x) - cos(νπ) A_ν(x) - A_(-ν)(x)
+        let (s, c) = sincospi(nu);
+        let a_pos = anger_weber_a(nu, x);
+        let j = bessel_jv(nu, x) + s * a_pos;
+        let e = -bessel_yv(nu, x) - c * a_pos - anger_weber_a(-nu, x);
+        (j, e)
+    } else {
+        anger_weber_integral(nu, x)
+    }
+}
+
+/// 计算 Anger 函数 J_ν(x) = (1/π) ∫_0^π cos(νθ - x sin θ) dθ
+///
+/// ν 为整数时即第一类 Bessel 函数 J_n(x). 否则 x <= 2 或 x <= |ν|/2 时使用幂级数,
+/// x >= 2|ν| 时由 J_ν(x) = J_ν(x) + sin(νπ) A_ν(x) 计算, 其余情形直接对定义式数值积分.
+/// x < 0 时由 J_ν(-x) = J_(-ν)(x) 计算, x 为 ±∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::anger_weber::anger_j;
+/// assert!((anger_j(0.5, 1.0) - 0.8551653096792622).abs() < 1.0e-15);
+/// ```
+pub fn anger_j(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu.is_infinite() {
+        return f64::NAN;
+    }
+    if nu == nu.floor() && nu.abs() <= i32::MAX as f64 {
+        return bessel_jn(nu as i32, x);
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    anger_weber_general(nu, x).0
+}
+
+/// 计算 Weber 函数 E_ν(x) = (1/π) ∫_0^π sin(νθ - x sin θ) dθ
+///
+/// 方法与 `anger_j` 相同, x >= 2|ν| 时由 E_ν(x) = -Y_ν(x) - cos(νπ) A_ν(x) - A_(-ν)(x) 计算.
+/// x < 0 时由 E_ν(-x) = -E_(-ν)(x) 计算, x 为 ±∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::anger_weber::weber_e;
+/// assert!((weber_e(0.5, 1.0) - 0.09950754264004317).abs() < 1.0e-15);
+/// ```
+pub fn weber_e(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu.is_infinite() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    anger_weber_general(nu, x).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_anger_j() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(anger_j(0.5, 1.0), 0.8551653096792622, tol));
+        assert!(approx_eq(anger_j(2.0, 3.0), bessel_jn(2, 3.0), tol));
+        assert!(approx_eq(anger_j(-2.5, 3.0), 0.10744776031851959, tol));
+        assert!(approx_eq(anger_j(1.7, 5.0), -0.1229558207523223, tol));
+        assert!(approx_eq(anger_j(10.5, 12.0), 0.3088320109467395, tol));
+        assert!(approx_eq(anger_j(30.5, 10.0), 0.007858321297656738, tol));
+        assert!(approx_eq(anger_j(0.25, 80.0), -0.0829046074407882, tol));
+        assert!(approx_eq(anger_j(0.5, -1.0), 0.09950754264004317, tol));
+        assert!(approx_eq(anger_j(2.5, 0.0), 0.12732395447351627, tol));
+        assert_eq!(anger_j(0.5, f64::INFINITY), 0.0);
+        assert!(anger_j(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_weber_e() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(weber_e(0.5, 1.0), 0.09950754264004317, tol));
+        assert!(approx_eq(weber_e(2.0, 3.0), -0.10576689730990184, tol));
+        assert!(approx_eq(weber_e(-2.5, 3.0), -0.4696568580693943, tol));
+        assert!(approx_eq(weber_e(1.7, 5.0), -0.47247024621462776, tol));
+        assert!(approx_eq(weber_e(10.5, 12.0), -0.01860435798876641, tol));
+        assert!(approx_eq(weber_e(30.5, 10.0), 0.01554559746429344, tol));
+        assert!(approx_eq(weber_e(0.25, 80.0), 0.017935852928739113, tol));
+        assert!(approx_eq(weber_e(0.5, -1.0), 0.8551653096792622, tol));
+        assert!(approx_eq(weber_e(2.5, 0.0), 0.12732395447351627, tol));
+        assert_eq!(weber_e(0.5, f64::NEG_INFINITY), 0.0);
+        assert!(weber_e(0.5, f64::NAN).is_nan());
+    }
+}
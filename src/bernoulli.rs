@@ -0,0 +1,685 @@
+use crate::triangle::{cospi, sinpi};
+use crate::zeta::{dirichlet_beta, zeta};
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+
+/// 偶数下标 Bernoulli 数表的长度, B_258 是可以用 f64 表示的最后一个非零 Bernoulli 数
+const BERNOULLI_TABLE_SIZE: usize = 130;
+
+/// B_0, B_2, B_4, ..., B_258 的值
+static BERNOULLI_EVEN: [f64; BERNOULLI_TABLE_SIZE] = [
+    1.0,
+    0.16666666666666666,
+    -0.03333333333333333,
+    0.023809523809523808,
+    -0.03333333333333333,
+    0.07575757575757576,
+    -0.2531135531135531,
+    1.1666666666666667,
+    -7.092156862745098,
+    54.971177944862156,
+    -529.1242424242424,
+    6192.123188405797,
+    -86580.25311355312,
+    1425517.1666666667,
+    -27298231.067816094,
+    601580873.9006424,
+    -15116315767.092157,
+    429614643061.1667,
+    -13711655205088.332,
+    488332318973593.2,
+    -1.9296579341940068e+16,
+    8.416930475736826e+17,
+    -4.0338071854059454e+19,
+    2.1150748638081993e+21,
+    -1.2086626522296526e+23,
+    7.500866746076964e+24,
+    -5.038778101481069e+26,
+    3.6528776484818122e+28,
+    -2.849876930245088e+30,
+    2.3865427499683627e+32,
+    -2.1399949257225335e+34,
+    2.0500975723478097e+36,
+    -2.093800591134638e+38,
+    2.2752696488463515e+40,
+    -2.6257710286239577e+42,
+    3.212508210271803e+44,
+    -4.159827816679471e+46,
+    5.692069548203528e+48,
+    -8.218362941978458e+50,
+    1.2502904327166994e+53,
+    -2.001558323324837e+55,
+    3.3674982915364376e+57,
+    -5.947097050313545e+59,
+    1.1011910323627977e+62,
+    -2.1355259545253502e+64,
+    4.3328896986641194e+66,
+    -9.188552824166933e+68,
+    2.0346896776329074e+71,
+    -4.700383395803573e+73,
+    1.131804344548425e+76,
+    -2.8382249570693707e+78,
+    7.406424897967885e+80,
+    -2.0096454802756605e+83,
+    5.665717005080594e+85,
+    -1.6584511154136216e+88,
+    5.036885995049238e+90,
+    -1.5861468237658186e+93,
+    5.1756743617545625e+95,
+    -1.7488921840217116e+98,
+    6.116051999495218e+100,
+    -2.2122776912707833e+103,
+    8.272277679877097e+105,
+    -3.195892511141571e+108,
+    1.2750082223387793e+111,
+    -5.250092308677413e+113,
+    2.2301817894241627e+116,
+    -9.76845219309552e+118,
+    4.409836197845295e+121,
+    -2.050857088646409e+124,
+    9.821443327979128e+126,
+    -4.841260079820888e+129,
+    2.4553088801480982e+132,
+    -1.2806926804084748e+135,
+    6.867616710466858e+137,
+    -3.7846468581969106e+140,
+    2.142610125066529e+143,
+    -1.2456727137183695e+146,
+    7.434578755100016e+148,
+    -4.5535795304641704e+151,
+    2.861211281685887e+154,
+    -1.843772355203387e+157,
+    1.2181154536221047e+160,
+    -8.248218718531412e+162,
+    5.722587793783294e+165,
+    -4.0668530525059105e+168,
+    2.9596092064642052e+171,
+    -2.2049522565189457e+174,
+    1.68125970728896e+177,
+    -1.3116736213556958e+180,
+    1.0467894009478039e+183,
+    -8.543289357883371e+185,
+    7.128782132248655e+188,
+    -6.08029314555359e+191,
+    5.299677642484992e+194,
+    -4.719425916874586e+197,
+    4.292841379140298e+200,
+    -3.9876744968232205e+203,
+    3.781978041935888e+206,
+    -3.661423368368119e+209,
+    3.617609027237286e+212,
+    -3.647077264519136e+215,
+    3.750875543645441e+218,
+    -3.934586729643903e+221,
+    4.208821114819008e+224,
+    -4.590229622061792e+227,
+    5.103172577262957e+230,
+    -5.782276230365695e+233,
+    6.676248216783588e+236,
+    -7.853530764445042e+239,
+    9.410689406705872e+242,
+    -1.1484933873465185e+246,
+    1.4272958742848785e+249,
+    -1.805955958690931e+252,
+    2.3261535307660807e+255,
+    -3.0495751715499594e+258,
+    4.068580607643398e+261,
+    -5.523103132197436e+264,
+    7.6277279396434395e+267,
+    -1.0715571119697886e+271,
+    1.5310200895969188e+274,
+    -2.2244891682179836e+277,
+    3.286267919069014e+280,
+    -4.935592895596035e+283,
+    7.534957120083251e+286,
+    -1.1691485154584178e+290,
+    1.843526146783894e+293,
+    -2.953682617296808e+296,
+    4.807932127750157e+299,
+    -7.950212504588525e+302,
+    1.3352784187354634e+306,
+];
+
+/// 计算 Bernoulli 数 B_n, 取 B_1 = -1/2 的约定
+///
+/// 偶数下标的值查表给出, 为精确值的正确舍入; n > 1 为奇数时 B_n = 0.
+/// n > 258 的偶数下标上溢, 按 B_n 的符号 (-1)^(n/2+1) 返回 ±∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::bernoulli::bernoulli;
+/// assert_eq!(bernoulli(12), -691.0 / 2730.0);
+/// assert_eq!(bernoulli(13), 0.0);
+/// ```
+pub fn bernoulli(n: u32) -> f64 {
+    if n == 1 {
+        return -0.5;
+    }
+    if !n.is_multiple_of(2) {
+        return 0.0;
+    }
+    let k = (n / 2) as usize;
+    if k < BERNOULLI_TABLE_SIZE {
+        BERNOULLI_EVEN[k]
+    } else if k.is_multiple_of(2) {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// n 不小于此值时在 [0, 1] 上使用 Fourier 级数, 一般至多约 40 项即收敛到机器精度
+const BERNOULLI_POLY_FOURIER_ORDER: u32 = 10;
+
+/// 在 x = 0 处展开的 B_n(x) = Σ_(k=0)^n C(n, k) B_k x^(n-k), 以秦九韶算法求值
+fn bernoulli_poly_origin(n: u32, x: f64) -> f64 {
+    let mut binomial = 1.0;
+    let mut sum = 0.0;
+    for k in 0..=n {
+        sum = sum * x + binomial * bernoulli(k);
+        binomial *= (n - k) as f64 / (k + 1) as f64;
+    }
+    sum
+}
+
+/// 在中点 x = 1/2 处展开的 B_n(x) = Σ_j C(n, 2j) B_(2j)(1/2) t^(n-2j), 其中 t = x - 1/2,
+/// B_(2j)(1/2) = (2^(1-2j) - 1) B_(2j), 奇数下标的项为零. 自最低次项 t^(n mod 2) 起按 t^2 的升幂求和
+fn bernoulli_poly_midpoint(n: u32, t: f64) -> f64 {
+    if n as f64 * t.abs().ln() > f64::MAX.ln() {
+        // |t| > n/4 时 B_n(x) 由 t^n 主导, 直接返回上溢的结果以免各项相减得到 NaN
+        return t.powi(n as i32);
+    }
+    let t2 = t * t;
+    let mut j = n / 2;
+    let (mut binomial, mut power) = if n.is_multiple_of(2) {
+        (1.0, 1.0)
+    } else {
+        (n as f64, t)
+    };
+    let mut sum = 0.0;
+    loop {
+        let half = 2.0f64.powi(1 - 2 * j as i32) - 1.0;
+        sum += binomial * half * bernoulli(2 * j) * power;
+        if j == 0 {
+            return sum;
+        }
+        let m = (n - 2 * j) as f64;
+        binomial *= 2.0 * j as f64 * (2.0 * j as f64 - 1.0) / ((m + 1.0) * (m + 2.0));
+        power *= t2;
+        j -= 1;
+    }
+}
+
+/// n >= 2 时 B_n(x - ⌊x⌋) 的 Fourier 级数 -2 n!/(2π)^n Σ_(k>=1) cos(2πkx - nπ/2)/k^n.
+/// 取 r = x - round(x) 后以 `cospi`, `sinpi` 求三角函数; 系数 2 n!/(2π)^n 由 |B_n|/ζ(n) 或 n |B_(n-1)|/(2π ζ(n-1)) 精确给出
+fn bernoulli_poly_fourier(n: u32, x: f64) -> f64 {
+    let r = x - x.round();
+    let even = n.is_multiple_of(2);
+    let mut sum = 0.0;
+    let mut k = 1.0f64;
+    loop {
+        let weight = k.powi(-(n as i32));
+        sum += weight
+            * if even {
+                cospi(2.0 * k * r)
+            } else {
+                sinpi(2.0 * k * r)
+            };
+        // 首项可能为零 (如 x = 1/4), 故相对部分和判断收敛; 和为零时 (如 n 为奇数, x 为整数) 在 k^(-n) <= ε^2 时停止
+        if weight <= 0.5 * f64::EPSILON * sum.abs() || weight <= f64::EPSILON * f64::EPSILON {
+            break;
+        }
+        k += 1.0;
+    }
+    if even {
+        // B_n = -2 n!/(2π)^n cos(nπ/2) ζ(n)
+        bernoulli(n) / zeta(n as f64) * sum
+    } else {
+        let amplitude = bernoulli(n - 1).abs() / zeta((n - 1) as f64) * (n as f64 / (2.0 * PI));
+        let sign = if ((n - 1) / 2).is_multiple_of(2) {
+            -1.0
+        } else {
+            1.0
+        };
+        sign * amplitude * sum
+    }
+}
+
+/// 计算 Bernoulli 多项式 B_n(x) = Σ_(k=0)^n C(n, k) B_k x^(n-k)
+///
+/// n < 10 时在 0, 1/2, 1 中离 x 最近的点处展开, 在 x = 1 处利用 B_n(1 - x) = (-1)^n B_n(x), 零点 0, 1/2, 1 附近保持相对精度.
+/// n >= 10 时 B_n(x) 在 [0, 1] 上约为 2 n!/(2π)^n 倍的余弦, 多项式的各项相消严重, 改用 Fourier 级数
+/// 计算 B_n(x - ⌊x⌋), 再由 B_n(x + 1) = B_n(x) + n x^(n-1) 平移到 x; |x| 超过约 n/4 时各项单调衰减,
+/// 直接在中点 1/2 处展开. x = 0 与 x = 1 时由 B_n(0) = B_n, B_n(1) = (-1)^n B_n 精确给出; x 为无穷时返回 x^n.
+/// n > 259 时展开的系数上溢, 除 x = 0, 1 外返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::bernoulli::bernoulli_poly;
+/// // B_2(x) = x^2 - x + 1/6
+/// assert!((bernoulli_poly(2, 0.3) - (0.09 - 0.3 + 1.0 / 6.0)).abs() < 1.0e-15);
+/// ```
+pub fn bernoulli_poly(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if n == 0 {
+        return 1.0;
+    }
+    if x.is_infinite() {
+        return x.powi(n as i32);
+    }
+    let reflect = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+    if x == 0.0 {
+        return bernoulli(n);
+    }
+    if x == 1.0 {
+        return reflect * bernoulli(n);
+    }
+    if n > 2 * BERNOULLI_TABLE_SIZE as u32 - 1 {
+        return f64::NAN;
+    }
+    if n < BERNOULLI_POLY_FOURIER_ORDER {
+        return if x < 0.25 {
+            bernoulli_poly_origin(n, x)
+        } else if x <= 0.75 {
+            bernoulli_poly_midpoint(n, x - 0.5)
+        } else {
+            reflect * bernoulli_poly_origin(n, 1.0 - x)
+        };
+    }
+    let floor = x.floor();
+    if floor.abs() > 0.25 * n as f64 + 1.0 {
+        return bernoulli_poly_midpoint(n, x - 0.5);
+    }
+    let mut value = bernoulli_poly_fourier(n, x);
+    let nf = n as f64;
+    let mut j = 0.0;
+    if floor > 0.0 {
+        // B_n(x) = B_n(x - m) + n Σ_(j=1)^m (x - j)^(n-1)
+        while j < floor {
+            j += 1.0;
+            value += nf * (x - j).powi(n as i32 - 1);
+        }
+    } else {
+        // B_n(x) = B_n(x + m) - n Σ_(j=0)^(m-1) (x + j)^(n-1)
+        while j < -floor {
+            value -= nf * (x + j).powi(n as i32 - 1);
+            j += 1.0;
+        }
+    }
+    value
+}
+
+/// 偶数下标 Euler 数表的长度, E_186 是可以用 f64 表示的最后一个非零 Euler 数
+const EULER_TABLE_SIZE: usize = 94;
+
+/// n 不小于此值时在 [0, 1] 上使用 Fourier 级数
+const EULER_POLY_FOURIER_ORDER: u32 = 10;
+
+/// E_0, E_2, E_4, ..., E_186, 在第一次使用时生成
+fn euler_table() -> &'static [f64; EULER_TABLE_SIZE] {
+    static TABLE: OnceLock<[f64; EULER_TABLE_SIZE]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        // Seidel 三角形: 第 k 行由上一行自右向左累加得到, 末项为 zigzag 数 A_k, 且 E_(2j) = (-1)^j A_(2j).
+        // 只有正数相加, 没有相消
+        let mut table = [0.0; EULER_TABLE_SIZE];
+        table[0] = 1.0;
+        let mut row = vec![1.0];
+        let mut next = Vec::with_capacity(2 * EULER_TABLE_SIZE);
+        for k in 1..2 * EULER_TABLE_SIZE - 1 {
+            next.clear();
+            let mut partial = 0.0;
+            next.push(partial);
+            for value in row.iter().rev() {
+                partial += value;
+                next.push(partial);
+            }
+            std::mem::swap(&mut row, &mut next);
+            if k.is_multiple_of(2) {
+                let j = k / 2;
+                table[j] = if j.is_multiple_of(2) {
+                    partial
+                } else {
+                    -partial
+                };
+            }
+        }
+        table
+    })
+}
+
+/// 计算 Euler 数 E_n, 即 sech x = Σ_(n>=0) E_n x^n / n! 的系数, 奇数下标的 Euler 数为零
+///
+/// 数表由 Seidel 三角形的递推生成并缓存, 递推中只有正数相加, 相对误差约为 n 个机器精度.
+/// n > 186 时上溢为 ±∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::bernoulli::euler_number;
+/// assert_eq!(euler_number(10), -50521.0);
+/// assert_eq!(euler_number(11), 0.0);
+/// ```
+pub fn euler_number(n: u32) -> f64 {
+    if !n.is_multiple_of(2) {
+        return 0.0;
+    }
+    let k = (n / 2) as usize;
+    if k < EULER_TABLE_SIZE {
+        euler_table()[k]
+    } else if k.is_multiple_of(2) {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// E_n(0) = -2 (2^(n+1) - 1) B_(n+1) / (n + 1), n 为正偶数时为零
+fn euler_poly_zero(n: u32) -> f64 {
+    if n == 0 {
+        return 1.0;
+    }
+    -2.0 * (2.0f64.powi(n as i32 + 1) - 1.0) * bernoulli(n + 1) / (n + 1) as f64
+}
+
+/// 在 x = 0 处展开的 E_n(x) = Σ_(k=0)^n C(n, k) E_k(0) x^(n-k), 以秦九韶算法求值
+fn euler_poly_origin(n: u32, x: f64) -> f64 {
+    let mut binomial = 1.0;
+    let mut sum = 0.0;
+    for k in 0..=n {
+        sum = sum * x + binomial * euler_poly_zero(k);
+        binomial *= (n - k) as f64 / (k + 1) as f64;
+    }
+    sum
+}
+
+/// 在中点 x = 1/2 处展开的 E_n(x) = Σ_j C(n, 2j) E_(2j) 2^(-2j) t^(n-2j), 其中 t = x - 1/2.
+/// 自最低次项 t^(n mod 2) 起按 t^2 的升幂求和
+fn euler_poly_midpoint(n: u32, t: f64) -> f64 {
+    if n as f64 * t.abs().ln() > f64::MAX.ln() {
+        // |t| 远大于 n 时 E_n(x) 由 t^n 主导, 直接返回上溢的结果以免各项相减得到 NaN
+        return t.powi(n as i32);
+    }
+    let t2 = t * t;
+    let mut j = n / 2;
+    let (mut binomial, mut power) = if n.is_multiple_of(2) {
+        (1.0, 1.0)
+    } else {
+        (n as f64, t)
+    };
+    let mut sum = 0.0;
+    loop {
+        sum += binomial * euler_number(2 * j) * 2.0f64.powi(-2 * j as i32) * power;
+        if j == 0 {
+            return sum;
+        }
+        let m = (n - 2 * j) as f64;
+        binomial *= 2.0 * j as f64 * (2.0 * j as f64 - 1.0) / ((m + 1.0) * (m + 2.0));
+        power *= t2;
+        j -= 1;
+    }
+}
+
+/// 0 <= x <= 1 时 E_n(x) 的 Fourier 级数 4 n!/π^(n+1) Σ_(k>=0) sin((2k+1)πx - nπ/2)/(2k+1)^(n+1),
+/// 级数在整个实轴上给出 E_n 以 1 为反周期的延拓. 取 r = x - round(x) 后以 `sinpi`, `cospi` 求三角函数;
+/// 系数 4 n!/π^(n+1) 由 |E_n|/(2^n β(n+1)) 或 n |E_(n-1)|/(2^(n-1) π β(n)) 精确给出
+fn euler_poly_fourier(n: u32, x: f64) -> f64 {
+    let round = x.round();
+    let r = x - round;
+    let even = n.is_multiple_of(2);
+    let mut sum = 0.0;
+    let mut k = 1.0f64;
+    loop {
+        let weight = k.powi(-(n as i32 + 1));
+        sum += weight * if even { sinpi(k * r) } else { cospi(k * r) };
+        // 首项在 r = 0 或 n 为奇数且 r = ±1/2 时为零, 故相对部分和判断收敛
+        if weight <= 0.5 * f64::EPSILON * sum.abs() || weight <= f64::EPSILON * f64::EPSILON {
+            break;
+        }
+        k += 2.0;
+    }
+    let (amplitude, sign) = if even {
+        let amplitude =
+            euler_number(n).abs() / dirichlet_beta(n as f64 + 1.0) * 2.0f64.powi(-(n as i32));
+        (
+            amplitude,
+            if (n / 2).is_multiple_of(2) { 1.0 } else { -1.0 },
+        )
+    } else {
+        let amplitude = euler_number(n - 1).abs() / dirichlet_beta(n as f64)
+            * 2.0f64.powi(1 - n as i32)
+            * (n as f64 / PI);
+        (
+            amplitude,
+            if ((n - 1) / 2).is_multiple_of(2) {
+                -1.0
+            } else {
+                1.0
+            },
+        )
+    };
+    let parity = if (round % 2.0) == 0.0 { 1.0 } else { -1.0 };
+    parity * sign * amplitude * sum
+}
+
+/// 计算 Euler 多项式 E_n(x), 即 2e^(xt)/(e^t + 1) = Σ_(n>=0) E_n(x) t^n / n! 的系数
+///
+/// n < 10 时在 0, 1/2, 1 中离 x 最近的点处展开, 在 x = 1 处利用 E_n(1 - x) = (-1)^n E_n(x).
+/// n >= 10 时与 `bernoulli_poly` 相同, 改用 Fourier 级数计算 E_n 在 [0, 1] 上的值, 再由 E_n(x + 1) = 2x^n - E_n(x)
+/// 平移到 x; |x| 超过约 n/2 时在中点 1/2 处展开. x = 0, 1/2, 1 时由 Euler 数与 Bernoulli 数精确给出;
+/// x 为无穷时返回 x^n. n > 187 时展开的系数上溢, 返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::bernoulli::euler_poly;
+/// // E_3(x) = x^3 - 3x^2/2 + 1/4
+/// assert!((euler_poly(3, 0.2) - (0.008 - 0.06 + 0.25)).abs() < 1.0e-15);
+/// ```
+pub fn euler_poly(n: u32, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if n == 0 {
+        return 1.0;
+    }
+    if x.is_infinite() {
+        return x.powi(n as i32);
+    }
+    if n > 2 * EULER_TABLE_SIZE as u32 - 1 {
+        return f64::NAN;
+    }
+    let reflect = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+    if x == 0.0 {
+        return euler_poly_zero(n);
+    }
+    if x == 1.0 {
+        return reflect * euler_poly_zero(n);
+    }
+    if x == 0.5 {
+        return euler_number(n) * 2.0f64.powi(-(n as i32));
+    }
+    if n < EULER_POLY_FOURIER_ORDER {
+        return if x < 0.25 {
+            euler_poly_origin(n, x)
+        } else if x <= 0.75 {
+            euler_poly_midpoint(n, x - 0.5)
+        } else {
+            reflect * euler_poly_origin(n, 1.0 - x)
+        };
+    }
+    let floor = x.floor();
+    // 平移时的交错和在结果上溢时会得到 NaN, 同样交给中点处的展开
+    if floor.abs() > 0.5 * n as f64 + 1.0 || n as f64 * x.abs().ln() > f64::MAX.ln() {
+        return euler_poly_midpoint(n, x - 0.5);
+    }
+    let mut value = euler_poly_fourier(n, x);
+    let mut j = 0.0;
+    let mut sign = 2.0;
+    if floor > 0.0 {
+        // E_n(x) = (-1)^m E_n(x - m) + 2 Σ_(j=1)^m (-1)^(j-1) (x - j)^n
+        while j < floor {
+            j += 1.0;
+            value += sign * (x - j).powi(n as i32);
+            sign = -sign;
+        }
+    } else {
+        // E_n(x) = (-1)^m E_n(x + m) + 2 Σ_(j=0)^(m-1) (-1)^j (x + j)^n
+        while j < -floor {
+            value += sign * (x + j).powi(n as i32);
+            sign = -sign;
+            j += 1.0;
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_bernoulli() {
+        assert_eq!(bernoulli(0), 1.0);
+        assert_eq!(bernoulli(1), -0.5);
+        assert_eq!(bernoulli(2), 1.0 / 6.0);
+        assert_eq!(bernoulli(3), 0.0);
+        assert_eq!(bernoulli(101), 0.0);
+        assert!(approx_eq(bernoulli(20), -529.1242424242424, 1.0e-12));
+        assert!(approx_eq(
+            bernoulli(60) / -2.1399949257225335e34,
+            1.0,
+            1.0e-15
+        ));
+        assert!(approx_eq(
+            bernoulli(258) / 1.3352784187354634e306,
+            1.0,
+            1.0e-15
+        ));
+        assert_eq!(bernoulli(260), f64::NEG_INFINITY);
+        assert_eq!(bernoulli(262), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bernoulli_poly() {
+        let tol = 1.0e-15;
+        assert_eq!(bernoulli_poly(0, 0.3), 1.0);
+        assert_eq!(bernoulli_poly(3, 0.5), 0.0);
+        assert!(approx_eq(bernoulli_poly(5, 0.2), -0.02368, tol));
+        assert!(approx_eq(bernoulli_poly(7, 0.9), -0.0155316, tol));
+        assert!(approx_eq(bernoulli_poly(12, 0.3), 0.07824673840744686, tol));
+        assert!(approx_eq(
+            bernoulli_poly(30, 0.7) / -185898713.80636057,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(25, 2.5) / 420852.80490219593,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(10, -1.5) / 384.37751538825756,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(50, 40.0) / 6.334388962184587e79,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(15, -30.0) / -1.821433659702191e22,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(100, 0.1) / -2.2961722241282265e78,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            bernoulli_poly(200, 0.37) / 2.4965961865248925e215,
+            1.0,
+            tol
+        ));
+        assert_eq!(bernoulli_poly(20, 1.0), bernoulli(20));
+        assert!(bernoulli_poly(2, f64::NAN).is_nan());
+        assert_eq!(bernoulli_poly(3, f64::NEG_INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_euler_number() {
+        assert_eq!(euler_number(0), 1.0);
+        assert_eq!(euler_number(2), -1.0);
+        assert_eq!(euler_number(4), 5.0);
+        assert_eq!(euler_number(7), 0.0);
+        assert_eq!(euler_number(20), 370371188237525.0);
+        assert!(approx_eq(
+            euler_number(100) / 2.9035283466610976e138,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            euler_number(186) / -3.245547458389247e306,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(euler_number(188), f64::INFINITY);
+        assert_eq!(euler_number(190), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_euler_poly() {
+        let tol = 1.0e-15;
+        assert_eq!(euler_poly(0, 0.3), 1.0);
+        assert_eq!(euler_poly(13, 0.5), 0.0);
+        assert_eq!(euler_poly(10, 0.0), 0.0);
+        assert!(approx_eq(euler_poly(4, 0.3), 0.2541, tol));
+        assert!(approx_eq(euler_poly(5, 0.9), 0.47524, tol));
+        assert!(approx_eq(euler_poly(7, -0.6), -0.7122896, tol));
+        assert!(approx_eq(euler_poly(12, 0.2) / 387.853519241216, 1.0, tol));
+        assert!(approx_eq(
+            euler_poly(20, 0.75) / 249759653.86290106,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(21, 2.3) / -1387794731.0803828,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(30, -4.5) / 7.93289240689434e19,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(50, 0.01) / -1.6887672110680616e38,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(100, 30.0) / 3.37272389934873e146,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(101, -70.0) / -3.66738204427276e186,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            euler_poly(187, 0.4) / 6.086713617095985e251,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(euler_poly(20, 0.5), euler_number(20) / 1048576.0);
+        assert!(euler_poly(188, 0.3).is_nan());
+        assert!(euler_poly(2, f64::NAN).is_nan());
+    }
+}
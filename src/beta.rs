@@ -0,0 +1,142 @@
+use crate::gamma::{
+    gamma, lanczos_sum, ln_gamma, ln_gamma_diff_positive, ln_gamma_sign, GAMMA_MAX_ARG,
+    LANCZOS_G_HALF,
+};
+
+/// 判断是否为 Gamma 函数的极点 (非正整数)
+fn is_gamma_pole(x: f64) -> bool {
+    x <= 0.0 && x == x.floor()
+}
+
+/// 使用 Lanczos 近似计算 B(a, b), 要求 0 < a <= b 且均有限
+///
+/// B(a, b) = L(a) L(b) / L(c) e^(-g + 1/2) (agh / cgh)^(a - 1/2) (bgh / cgh)^(b - 1/2) / √cgh,
+/// 其中 c = a + b, zgh = z + g - 1/2. 由于 bgh / cgh = 1 - a / cgh, 最后一个幂用 ln(1 + x)
+/// 计算, c 的舍入误差只会以 a 倍而不是 b 倍被放大
+fn beta_lanczos(a: f64, b: f64) -> f64 {
+    let c = a + b;
+    let agh = a + LANCZOS_G_HALF;
+    let cgh = c + LANCZOS_G_HALF;
+    let sum = lanczos_sum(a) * (lanczos_sum(b) / lanczos_sum(c));
+    let power_b = ((b - 0.5) * (-a / cgh).ln_1p()).exp();
+    let power_a = (agh / cgh).powf(a - 0.5);
+    sum * (-LANCZOS_G_HALF).exp() * power_a * power_b / cgh.sqrt()
+}
+
+/// 计算 Beta 函数 B(a, b) = Γ(a) Γ(b) / Γ(a + b)
+///
+/// a, b > 0 时使用 Lanczos 近似直接组合各项, 参数很大时结果下溢为 0 而不会在中间上溢;
+/// 一般实参数时借助 Gamma 函数计算, a 或 b 为非正整数时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::beta::beta;
+/// assert!((beta(2.0, 3.0) - 1.0 / 12.0).abs() < 1.0e-16);
+/// ```
+pub fn beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || is_gamma_pole(a) || is_gamma_pole(b) {
+        return f64::NAN;
+    }
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    if a > 0.0 {
+        return if b.is_infinite() {
+            0.0
+        } else {
+            beta_lanczos(a, b)
+        };
+    }
+    let c = a + b;
+    if is_gamma_pole(c) {
+        return 0.0;
+    }
+    if a.abs() < GAMMA_MAX_ARG && b.abs() < GAMMA_MAX_ARG && c.abs() < GAMMA_MAX_ARG {
+        let value = gamma(a) * gamma(b) / gamma(c);
+        if value.is_normal() {
+            return value;
+        }
+    }
+    let (ln_a, sign_a) = ln_gamma_sign(a);
+    let (ln_b, sign_b) = ln_gamma_sign(b);
+    let (ln_c, sign_c) = ln_gamma_sign(c);
+    let sign = (sign_a * sign_b * sign_c) as f64;
+    sign * (ln_a + ln_b - ln_c).exp()
+}
+
+/// 计算 Beta 函数的对数 ln |B(a, b)|
+///
+/// a, b > 0 且 B(a, b) 超出浮点数范围时, 记 a <= b, 使用
+/// ln B(a, b) = ln Γ(a) - [ln Γ(a + b) - ln Γ(b)], 方括号中的差值由 `ln_gamma_diff`
+/// 的算法计算, 因此 b 很大时不会因相消而损失精度
+///
+/// # Example
+///
+/// ```
+/// use special_functions::beta::ln_beta;
+/// assert!((ln_beta(1.0e10, 1.0e10) + 13862943621.44632).abs() < 1.0e-4);
+/// ```
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || is_gamma_pole(a) || is_gamma_pole(b) {
+        return f64::NAN;
+    }
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    if a > 0.0 {
+        if b.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let value = beta_lanczos(a, b);
+        if value.is_normal() {
+            return value.ln();
+        }
+        return ln_gamma(a) - ln_gamma_diff_positive(b, a);
+    }
+    let c = a + b;
+    if is_gamma_pole(c) {
+        return f64::NEG_INFINITY;
+    }
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_beta() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(beta(2.0, 3.0), 1.0 / 12.0, tol));
+        assert!(approx_eq(beta(0.5, 0.5), std::f64::consts::PI, tol));
+        assert!(approx_eq(beta(0.25, 1.0e10), 0.011465185217362756, tol));
+        let r = beta(1271.0666797182312, 234.23192272188356) / 4.284519965784143e-284;
+        assert!(approx_eq(r, 1.0, 1.0e-13));
+        assert!(approx_eq(
+            beta(0.5, 1.0e300) / 1.772453850905516e-150,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(beta(1.0e-300, 1.0) / 1.0e300, 1.0, tol));
+        assert!(approx_eq(beta(-2.5, 1.25), -0.21850479619101, tol));
+        assert!(approx_eq(beta(3.5, -7.25), 0.00658051743839801, tol));
+        assert_eq!(beta(1.0e10, 1.0e10), 0.0);
+        assert_eq!(beta(2.5, f64::INFINITY), 0.0);
+        assert_eq!(beta(-1.5, -0.5), 0.0);
+        assert!(beta(-2.0, 1.5).is_nan());
+    }
+
+    #[test]
+    fn test_ln_beta() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(ln_beta(2.0, 3.0), -(12.0f64.ln()), tol));
+        let r = ln_beta(1271.0666797182312, 234.23192272188356) / -652.4791578911895;
+        assert!(approx_eq(r, 1.0, tol));
+        assert!(approx_eq(
+            ln_beta(1.0e10, 1.0e10) / -13862943621.44632,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(ln_beta(1.0e-300, 1.0), 690.7755278982137, tol));
+        assert!(approx_eq(ln_beta(-2.5, 1.25), -1.5209473141564633, tol));
+        assert_eq!(ln_beta(-1.5, -0.5), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::bessel::bessel_k0;
+use crate::beta::beta;
+
+/// 梯形公式步长 h = BICKLEY_STEP_SCALE / √(max(x + n, 16))
+const BICKLEY_STEP_SCALE: f64 = 0.5;
+
+/// 计算 Bickley–Naylor 函数 Ki_n(x) = ∫_0^∞ e^(-x cosh t) / cosh^n t dt, 要求 x >= 0 有限
+///
+/// 被积函数在实轴上解析且为偶函数, 在 (-∞, ∞) 上使用梯形公式可达指数收敛.
+/// 被积函数在 t = 0 附近的宽度约为 1/√(x + n), 因此步长取为 0.5/√(x + n), x + n 较小时取 1/8 以控制 t = ±iπ/2 处极点带来的误差
+fn bickley_integral(n: u32, x: f64) -> f64 {
+    let n = n as f64;
+    let h = BICKLEY_STEP_SCALE / (x + n).max(16.0).sqrt();
+    // 被积函数写成 e^(-x cosh t - n ln cosh t), 并提出因子 e^(-x)
+    let integrand = |t: f64| {
+        // cosh t - 1 = 2 sinh^2(t/2), 避免 t 较小时的相消
+        let s = (0.5 * t).sinh();
+        (-2.0 * x * s * s - n * t.cosh().ln()).exp()
+    };
+    let mut sum = 0.5;
+    let mut k = 1.0;
+    loop {
+        let term = integrand(k * h);
+        sum += term;
+        if term <= f64::EPSILON * sum {
+            break;
+        }
+        k += 1.0;
+    }
+    (-x).exp() * sum * h
+}
+
+/// 计算 Bickley–Naylor 函数 Ki_n(x) = ∫_0^∞ e^(-x cosh t) / cosh^n t dt
+///
+/// Ki_0(x) = K_0(x), Ki_n(x) = ∫_x^∞ Ki_(n-1)(t) dt 为 K_0 的 n 重积分.
+/// n >= 1 时对积分表示使用梯形公式, Ki_n(0) = B(n/2, 1/2)/2. x < 0 时返回 NaN, Ki_0(0) = +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::bickley::bickley_ki;
+/// assert!((bickley_ki(1, 1.0) - 0.32828647817111835).abs() < 1.0e-15);
+/// ```
+pub fn bickley_ki(n: u32, x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if n == 0 {
+        return bessel_k0(x);
+    }
+    if x == 0.0 {
+        // Ki_n(0) = ∫_0^∞ sech^n t dt = B(n/2, 1/2) / 2
+        return 0.5 * beta(0.5 * n as f64, 0.5);
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    bickley_integral(n, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn test_bickley_ki() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(bickley_ki(0, 1.0), bessel_k0(1.0), tol));
+        assert!(approx_eq(bickley_ki(1, 0.0), FRAC_PI_2, tol));
+        assert!(approx_eq(bickley_ki(2, 0.0), 1.0, tol));
+        assert!(approx_eq(bickley_ki(3, 0.0), FRAC_PI_4, tol));
+        assert!(approx_eq(bickley_ki(1, 1.0), 0.32828647817111835, tol));
+        assert!(approx_eq(bickley_ki(2, 0.1), 0.8625212897833684, tol));
+        assert!(approx_eq(bickley_ki(3, 1.0), 0.23784508219285524, tol));
+        assert!(approx_eq(bickley_ki(10, 5.0), 0.002197267845978932, tol));
+        assert!(approx_eq(bickley_ki(100, 2.0), 0.016834625339337602, tol));
+        assert!(approx_eq(
+            bickley_ki(3, 700.0) / 4.659815938642473e-306,
+            1.0,
+            tol
+        ));
+        assert_eq!(bickley_ki(1, f64::INFINITY), 0.0);
+        assert!(bickley_ki(0, 0.0).is_infinite());
+        assert!(bickley_ki(1, -1.0).is_nan());
+    }
+}
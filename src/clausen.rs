@@ -0,0 +1,218 @@
+use crate::elliptic::PI_LO;
+use crate::utils::eval_chebyshev;
+use crate::zeta::{dirichlet_eta, zeta};
+use std::f64::consts::{FRAC_PI_2, LN_2, PI};
+
+/// 0 <= θ <= π/2 时 Cl_2(θ) = θ - θ ln θ + θ^3 f(t) 中 f 的 Chebyshev 展开系数, t = 8θ^2/π^2 - 1
+const CLAUSEN2_SMALL: [f64; 9] = [
+    0.013976415986787831,
+    8.815443719490578e-05,
+    6.331370730578264e-07,
+    5.858590906719619e-09,
+    6.150320644168734e-11,
+    6.976364485006456e-13,
+    8.345388081428368e-15,
+    1.0380456575727165e-16,
+    1.3304599153029028e-18,
+];
+
+/// 0 <= x <= π/2 时 Cl_2(π - x) = x ln 2 + x^3 g(t) 中 g 的 Chebyshev 展开系数, t = 8x^2/π^2 - 1
+const CLAUSEN2_NEAR_PI: [f64; 13] = [
+    -0.043081106191851626,
+    -0.0014604958929756943,
+    -4.7937574236500824e-05,
+    -1.9681290161419234e-06,
+    -9.126869353951092e-08,
+    -4.5757181817007244e-09,
+    -2.422258325113257e-10,
+    -1.3347638610780105e-11,
+    -7.585200620523744e-13,
+    -4.416968818565178e-14,
+    -2.6234832107624644e-15,
+    -1.58396946444844e-16,
+    -9.696169437409447e-18,
+];
+
+/// n 不小于此值时 Fourier 级数至多约 10 项即收敛到机器精度, 直接求和
+const CLAUSEN_FOURIER_ORDER: u32 = 16;
+
+/// 0 < θ <= π/2 时的 Cl_2(θ)
+fn clausen2_small(theta: f64) -> f64 {
+    let t = 8.0 * (theta / PI).powi(2) - 1.0;
+    let theta3 = theta * theta * theta;
+    theta3.mul_add(
+        eval_chebyshev(t, &CLAUSEN2_SMALL),
+        theta - theta * theta.ln(),
+    )
+}
+
+/// |x| <= π/2 时的 Cl_2(π - x), 零点 x = 0 附近保持相对精度
+fn clausen2_near_pi(x: f64) -> f64 {
+    let t = 8.0 * (x / PI).powi(2) - 1.0;
+    x * (x * x).mul_add(eval_chebyshev(t, &CLAUSEN2_NEAR_PI), LN_2)
+}
+
+/// 直接对 Fourier 级数求和, `flip` 为真时计算 Cl_n(π - x) = Σ (-1)^(k+1) sin(kx)/k^n 或 Σ (-1)^k cos(kx)/k^n
+fn clausen_fourier(n: u32, x: f64, flip: bool) -> f64 {
+    let even = n.is_multiple_of(2);
+    let mut sum = 0.0;
+    let mut k = 1.0f64;
+    loop {
+        let weight = k.powi(-(n as i32));
+        let mut term = weight * if even { (k * x).sin() } else { (k * x).cos() };
+        if flip && (even == (k % 2.0 == 0.0)) {
+            term = -term;
+        }
+        sum += term;
+        if weight <= 0.5 * f64::EPSILON {
+            return sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// 0 < θ <= π/2 时 Li_n(e^(iθ)) 的对数级数取实部或虚部:
+/// Cl_n(θ) = Σ_(k ≡ n-1 mod 2) (-1)^⌊k/2⌋ c_k θ^k / k!, 其中 c_(n-1) = H_(n-1) - ln θ, 其余 c_k = ζ(n - k).
+/// 项按 (θ/(2π))^2 <= 1/16 衰减
+fn clausen_series(n: u32, theta: f64) -> f64 {
+    let harmonic: f64 = (1..n).map(|j| 1.0 / j as f64).sum();
+    let theta2 = theta * theta;
+    let mut k = (n - 1) % 2;
+    let mut power = if k == 0 { 1.0 } else { theta };
+    let mut sign = 1.0;
+    let mut sum = 0.0;
+    loop {
+        let c = if k == n - 1 {
+            harmonic - theta.ln()
+        } else {
+            zeta(n as f64 - k as f64)
+        };
+        let term = sign * c * power;
+        sum += term;
+        if k > n - 1 && term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        power *= theta2 / ((k + 1) * (k + 2)) as f64;
+        sign = -sign;
+        k += 2;
+    }
+}
+
+/// |x| <= π/2 时 Cl_n(π - x) 由 Li_n(-e^(ix)) = -Σ_(k>=0) η(n - k) (ix)^k / k! 给出:
+/// Cl_n(π - x) = ±Σ_(k ≡ n-1 mod 2) (-1)^⌊k/2⌋ η(n - k) x^k / k!, n 为偶数时取正号, 奇数时取负号.
+/// 项按 (x/π)^2 <= 1/4 衰减
+fn clausen_series_near_pi(n: u32, x: f64) -> f64 {
+    let x2 = x * x;
+    let mut k = (n - 1) % 2;
+    let mut power = if k == 0 { 1.0 } else { x };
+    let mut sign = 1.0;
+    let mut sum = 0.0;
+    loop {
+        let term = sign * dirichlet_eta(n as f64 - k as f64) * power;
+        sum += term;
+        if term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            break;
+        }
+        power *= x2 / ((k + 1) * (k + 2)) as f64;
+        sign = -sign;
+        k += 2;
+    }
+    if n.is_multiple_of(2) {
+        sum
+    } else {
+        -sum
+    }
+}
+
+/// 计算 n 阶 Clausen 函数, n 为偶数时 Cl_n(θ) = Σ_(k>=1) sin(kθ)/k^n, n 为奇数时 Cl_n(θ) = Σ_(k>=1) cos(kθ)/k^n
+///
+/// 先将 θ 约化到 [-π, π], |θ| > π/2 时改写为 θ = mπ - x (m 为奇数) 并直接由 θ 求出 x.
+/// Cl_1(θ) = -ln|2 sin(θ/2)|; Cl_2 在 θ = 0 与 θ = π 附近分别使用 Chebyshev 展开;
+/// 3 <= n < 16 时使用 Li_n(e^(iθ)) 在 θ = 0 与 θ = π 处的级数展开, 系数分别为 ζ(n - k) 与 η(n - k);
+/// n >= 16 时直接对 Fourier 级数求和.
+/// n = 0 或 θ 为无穷时返回 NaN, Cl_1(0) = +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::clausen::clausen;
+/// // Catalan 常数 G = Cl_2(π/2)
+/// assert!((clausen(2, std::f64::consts::FRAC_PI_2) - 0.915965594177219).abs() < 1.0e-15);
+/// ```
+pub fn clausen(n: u32, theta: f64) -> f64 {
+    if n == 0 || !theta.is_finite() {
+        return f64::NAN;
+    }
+    // θ = 2jπ + r, |r| <= π
+    let j = (0.5 * theta / PI).round();
+    let r = (-2.0 * j).mul_add(PI, theta) - 2.0 * j * PI_LO;
+    if r.abs() > FRAC_PI_2 {
+        // θ = mπ - x, m 为奇数, 直接由 θ 求 x 以免在 r 处损失精度
+        let m = 2.0 * (0.5 * theta / PI).floor() + 1.0;
+        let x = m.mul_add(PI, -theta) + m * PI_LO;
+        return match n {
+            1 => -(2.0 * (0.5 * x).cos()).ln(),
+            2 => clausen2_near_pi(x),
+            _ if n >= CLAUSEN_FOURIER_ORDER => clausen_fourier(n, x, true),
+            _ => clausen_series_near_pi(n, x),
+        };
+    }
+    let a = r.abs();
+    let value = match n {
+        1 => -(2.0 * (0.5 * a).sin()).ln(),
+        _ if a == 0.0 => {
+            if n.is_multiple_of(2) {
+                0.0
+            } else {
+                zeta(n as f64)
+            }
+        }
+        2 => clausen2_small(a),
+        _ if n >= CLAUSEN_FOURIER_ORDER => clausen_fourier(n, a, false),
+        _ => clausen_series(n, a),
+    };
+    if n.is_multiple_of(2) && r < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_clausen() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(clausen(2, FRAC_PI_2), 0.915965594177219, tol));
+        assert!(approx_eq(clausen(2, PI / 3.0), 1.0149416064096536, tol));
+        assert!(approx_eq(clausen(2, 0.1), 0.3302723988828167, tol));
+        assert!(approx_eq(clausen(2, 3.0), 0.09802620939130142, tol));
+        assert!(approx_eq(clausen(2, -1.0), -1.0139591323607685, tol));
+        assert!(approx_eq(clausen(2, 100.0), -0.869179200335718, 1.0e-14));
+        assert!(approx_eq(
+            clausen(2, 1.0e-10) / 2.402585092994046e-9,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(clausen(2, PI) / 8.488604760107495e-17, 1.0, tol));
+        assert!(approx_eq(clausen(1, 1.0), 0.04201950582536896, tol));
+        assert!(approx_eq(clausen(1, 3.0), -0.6906390243683489, tol));
+        assert!(approx_eq(clausen(3, 1.0), 0.4485730072800174, tol));
+        assert!(approx_eq(clausen(3, 3.0), -0.8945985921231673, tol));
+        assert!(approx_eq(clausen(4, 2.0), 0.8614259169344444, tol));
+        assert!(approx_eq(clausen(5, 0.5), 0.8939028695108385, tol));
+        assert!(approx_eq(clausen(6, -3.1), -0.04042223010450979, tol));
+        assert!(approx_eq(clausen(7, 10.0), -0.835843939626951, tol));
+        assert!(approx_eq(clausen(20, 1.0), 0.8414718520212728, tol));
+        assert!(approx_eq(clausen(60, 1.0), 0.8414709848078965, tol));
+        assert!(approx_eq(clausen(3, 0.0), zeta(3.0), tol));
+        assert_eq!(clausen(2, 0.0), 0.0);
+        assert_eq!(clausen(1, 0.0), f64::INFINITY);
+        assert!(clausen(0, 1.0).is_nan());
+        assert!(clausen(2, f64::NAN).is_nan());
+        assert!(clausen(2, f64::INFINITY).is_nan());
+    }
+}
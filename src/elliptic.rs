@@ -0,0 +1,1113 @@
+use crate::utils::eval_poly;
+use std::f64::consts::{FRAC_PI_2, LN_2, PI};
+
+/// π 的低位部分, PI + PI_LO 比 PI 更接近 π, 用于辐角约化
+pub(crate) const PI_LO: f64 = 1.2246467991473532e-16;
+
+/// R_J 的倍加过程与 R_G 的组合公式含自变量的三次乘积, 自变量的最大绝对值超出
+/// [1/CARLSON_SCALE_MAX, CARLSON_SCALE_MAX] 时先按齐次性缩放
+const CARLSON_SCALE_MAX: f64 = 1.0e90;
+
+/// 算术-几何平均的最多迭代次数. 即使 a/b 达到 f64 的整个动态范围, 10 余步即可收敛
+const AGM_MAX_ITER: usize = 64;
+
+/// 1 - m 不超过此值时 E(m) 使用 m → 1 处的对数型级数, 否则使用算术-几何平均
+const ELLIP_E_SERIES_MAX_COMPLEMENT: f64 = 0.1;
+
+/// 对 p > 0 有限计算算术-几何平均 a_0 = 1, b_0 = √p, a_(n+1) = (a_n + b_n)/2, b_(n+1) = √(a_n b_n),
+/// 返回 (AGM(1, √p), Σ_(n>=1) 2^(n-1) c_n^2), 其中 c_n = (a_(n-1) - b_(n-1))/2. 收敛是二次的
+fn ellip_agm(p: f64) -> (f64, f64) {
+    let mut a = 1.0;
+    let mut b = p.sqrt();
+    let mut sum = 0.0;
+    let mut weight = 1.0;
+    while (a - b).abs() > f64::EPSILON * a {
+        let c = 0.5 * (a - b);
+        sum += weight * c * c;
+        weight *= 2.0;
+        let a_next = 0.5 * (a + b);
+        b = (a * b).sqrt();
+        a = a_next;
+    }
+    (a, sum)
+}
+
+/// 以 p = 1 - m ∈ (0, 1] 为参数计算 K(m) = π / (2 AGM(1, √p)).
+/// 直接以 p 为自变量, m 接近 1 时不会因 1 - m 的舍入损失精度
+fn ellip_k_complement(p: f64) -> f64 {
+    FRAC_PI_2 / agm(1.0, p.sqrt())
+}
+
+/// 以 p = 1 - m ∈ (0, 1] 为参数计算 E(m)
+///
+/// p > 0.1 时由 E(m) = K(m) ((1 + p)/2 - Σ 2^(n-1) c_n^2) 计算 (见 `ellip_agm`); p 较小时该式有相消, 改用
+/// E(m) = 1 + (1/2) Σ_(j>=0) ((1/2)_j (3/2)_j / ((2)_j j!)) p^(j+1) (ln(1/√p) + d_j - 1/((2j + 1)(2j + 2))),
+/// 其中 d_j = ψ(j + 1) - ψ(j + 1/2), 由 d_0 = 2 ln 2, d_(j+1) = d_j - 2/((2j + 1)(2j + 2)) 递推
+fn ellip_e_complement(p: f64) -> f64 {
+    if p > ELLIP_E_SERIES_MAX_COMPLEMENT {
+        let (a, sum) = ellip_agm(p);
+        return FRAC_PI_2 / a * (0.5 * (1.0 + p) - sum);
+    }
+    let log_term = -0.5 * p.ln();
+    let mut coeff = 1.0;
+    let mut d = 2.0 * LN_2;
+    let mut power = p;
+    let mut sum = 0.0;
+    let mut j = 0.0;
+    loop {
+        let r = 1.0 / ((2.0 * j + 1.0) * (2.0 * j + 2.0));
+        let term = coeff * power * (log_term + d - r);
+        sum += term;
+        if term.abs() <= f64::EPSILON {
+            break;
+        }
+        coeff *= (j + 0.5) * (j + 1.5) / ((j + 2.0) * (j + 1.0));
+        d -= 2.0 * r;
+        power *= p;
+        j += 1.0;
+    }
+    1.0 + 0.5 * sum
+}
+
+/// 以 Carlson 的倍加算法计算 R_F(x, y, z) = (1/2) ∫_0^∞ dt / √((t + x)(t + y)(t + z)), 要求 x, y, z >= 0
+/// 且至多一个为零
+///
+/// 反复以 λ = √x√y + √y√z + √z√x, (x, y, z) → ((x + λ)/4, (y + λ)/4, (z + λ)/4) 使三者趋于均值 A,
+/// 当 4^(-n) Q < |A| 时 (Q = (3ε)^(-1/6) max |A_0 - x_0|) 以相对偏差 X, Y, Z 的五阶 Taylor 展开结束
+fn carlson_rf(x0: f64, y0: f64, z0: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x0, y0, z0);
+    let a0 = (x0 + y0 + z0) / 3.0;
+    let mut a = a0;
+    let q = (3.0 * f64::EPSILON).powf(-1.0 / 6.0)
+        * (a0 - x0).abs().max((a0 - y0).abs()).max((a0 - z0).abs());
+    let mut scale = 1.0;
+    while scale * q >= a.abs() {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+        a = 0.25 * (a + lambda);
+        scale *= 0.25;
+    }
+    let dx = (a0 - x0) * scale / a;
+    let dy = (a0 - y0) * scale / a;
+    let dz = -dx - dy;
+    let e2 = dx * dy - dz * dz;
+    let e3 = dx * dy * dz;
+    (1.0 - e2 / 10.0 + e3 / 14.0 + e2 * e2 / 24.0 - 3.0 * e2 * e3 / 44.0) / a.sqrt()
+}
+
+/// 以 Carlson 的倍加算法计算 R_D(x, y, z) = (3/2) ∫_0^∞ dt / (√((t + x)(t + y)) (t + z)^(3/2)),
+/// 要求 x, y >= 0 至多一个为零, z > 0
+///
+/// 倍加步骤与 `carlson_rf` 相同, 每步累加 4^(-n) / (√z_n (z_n + λ_n)), 终止条件中 Q = (ε/4)^(-1/6) max |A_0 - x_0|,
+/// 其中 A_0 = (x + y + 3z)/5
+fn carlson_rd(x0: f64, y0: f64, z0: f64) -> f64 {
+    let (mut x, mut y, mut z) = (x0, y0, z0);
+    let a0 = (x0 + y0 + 3.0 * z0) / 5.0;
+    let mut a = a0;
+    let q = (0.25 * f64::EPSILON).powf(-1.0 / 6.0)
+        * (a0 - x0).abs().max((a0 - y0).abs()).max((a0 - z0).abs());
+    let mut scale = 1.0;
+    let mut sum = 0.0;
+    while scale * q >= a.abs() {
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        sum += scale / (sz * (z + lambda));
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+        a = 0.25 * (a + lambda);
+        scale *= 0.25;
+    }
+    let dx = (a0 - x0) * scale / a;
+    let dy = (a0 - y0) * scale / a;
+    let dz = -(dx + dy) / 3.0;
+    let xy = dx * dy;
+    let zz = dz * dz;
+    let e2 = xy - 6.0 * zz;
+    let e3 = (3.0 * xy - 8.0 * zz) * dz;
+    let e4 = 3.0 * (xy - zz) * zz;
+    let e5 = xy * zz * dz;
+    let series = 1.0 - 3.0 * e2 / 14.0 + e3 / 6.0 + 9.0 * e2 * e2 / 88.0
+        - 3.0 * e4 / 22.0
+        - 9.0 * e2 * e3 / 52.0
+        + 3.0 * e5 / 26.0;
+    scale * series / (a * a.sqrt()) + 3.0 * sum
+}
+
+/// 以 Carlson 的倍加算法计算 R_C(x, y) = (1/2) ∫_0^∞ dt / (√(t + x) (t + y)), 要求 x >= 0, y != 0
+///
+/// y < 0 时取 Cauchy 主值, 由 R_C(x, y) = √(x / (x - y)) R_C(x - y, -y) 化为 y > 0 的情形.
+/// 倍加步骤为 λ = 2√x√y + y, 终止条件中 Q = (3ε)^(-1/8) |A_0 - x_0|, A_0 = (x + 2y)/3
+fn carlson_rc(x0: f64, y0: f64) -> f64 {
+    if y0 < 0.0 {
+        return (x0 / (x0 - y0)).sqrt() * carlson_rc(x0 - y0, -y0);
+    }
+    let (mut x, mut y) = (x0, y0);
+    let a0 = (x0 + 2.0 * y0) / 3.0;
+    let mut a = a0;
+    let q = (3.0 * f64::EPSILON).powf(-1.0 / 8.0) * (a0 - x0).abs();
+    let mut scale = 1.0;
+    while scale * q >= a.abs() {
+        let lambda = 2.0 * x.sqrt() * y.sqrt() + y;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        a = 0.25 * (a + lambda);
+        scale *= 0.25;
+    }
+    let t = (y0 - a0) * scale / a;
+    let series = eval_poly(
+        t,
+        &[
+            9.0 / 8.0,
+            159.0 / 208.0,
+            9.0 / 22.0,
+            3.0 / 8.0,
+            1.0 / 7.0,
+            3.0 / 10.0,
+            0.0,
+            1.0,
+        ],
+    );
+    series / a.sqrt()
+}
+
+/// 以 Carlson 的倍加算法计算 R_J(x, y, z, p) = (3/2) ∫_0^∞ dt / (√((t + x)(t + y)(t + z)) (t + p)),
+/// 要求 x, y, z >= 0 至多一个为零, p != 0
+///
+/// p > 0 时每步累加 6 · 4^(-n) R_C(1, 1 + e_n) / d_n, 其中 d_n = (√p_n + √x_n)(√p_n + √y_n)(√p_n + √z_n),
+/// e_n = 4^(-3n) (p - x)(p - y)(p - z) / d_n^2, 终止后以 X, Y, Z, P = -(X + Y + Z)/2 的五阶展开结束.
+/// p < 0 时取 Cauchy 主值: 将 x <= y <= z 排序后, 记 b = (z - y)(y - x)/(y - p), q = y + b, 则
+/// (y - p) R_J(x, y, z, p) = b R_J(x, y, z, q) - 3 R_F(x, y, z) + 3 R_C(xz/y, pq/y)
+fn carlson_rj(x0: f64, y0: f64, z0: f64, p0: f64) -> f64 {
+    if p0 < 0.0 {
+        let mut v = [x0, y0, z0];
+        v.sort_by(f64::total_cmp);
+        let [x, y, z] = v;
+        let b = (z - y) * (y - x) / (y - p0);
+        let q = y + b;
+        let rc = carlson_rc(x * z / y, p0 * q / y);
+        return (b * carlson_rj(x, y, z, q) - 3.0 * carlson_rf(x, y, z) + 3.0 * rc) / (y - p0);
+    }
+    let (mut x, mut y, mut z, mut p) = (x0, y0, z0, p0);
+    let a0 = (x0 + y0 + z0 + 2.0 * p0) / 5.0;
+    let mut a = a0;
+    let delta = (p0 - x0) * (p0 - y0) * (p0 - z0);
+    let q = (0.25 * f64::EPSILON).powf(-1.0 / 6.0)
+        * (a0 - x0)
+            .abs()
+            .max((a0 - y0).abs())
+            .max((a0 - z0).abs())
+            .max((a0 - p0).abs());
+    let mut scale = 1.0;
+    let mut cube = 1.0;
+    let mut sum = 0.0;
+    while scale * q >= a.abs() {
+        let (sx, sy, sz, sp) = (x.sqrt(), y.sqrt(), z.sqrt(), p.sqrt());
+        let lambda = sx * sy + sy * sz + sz * sx;
+        let d = (sp + sx) * (sp + sy) * (sp + sz);
+        let e = cube * delta / (d * d);
+        sum += scale / d * carlson_rc(1.0, 1.0 + e);
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+        p = 0.25 * (p + lambda);
+        a = 0.25 * (a + lambda);
+        scale *= 0.25;
+        cube *= 1.0 / 64.0;
+    }
+    let dx = (a0 - x0) * scale / a;
+    let dy = (a0 - y0) * scale / a;
+    let dz = (a0 - z0) * scale / a;
+    let dp = -0.5 * (dx + dy + dz);
+    let xyz = dx * dy * dz;
+    let pp = dp * dp;
+    let e2 = dx * dy + dx * dz + dy * dz - 3.0 * pp;
+    let e3 = xyz + 2.0 * e2 * dp + 4.0 * pp * dp;
+    let e4 = (2.0 * xyz + e2 * dp + 3.0 * pp * dp) * dp;
+    let e5 = xyz * pp;
+    let series = 1.0 - 3.0 * e2 / 14.0 + e3 / 6.0 + 9.0 * e2 * e2 / 88.0
+        - 3.0 * e4 / 22.0
+        - 9.0 * e2 * e3 / 52.0
+        + 3.0 * e5 / 26.0;
+    scale * series / (a * a.sqrt()) + 6.0 * sum
+}
+
+/// 计算算术-几何平均 AGM(a, b), 即 a_0 = a, b_0 = b, a_(n+1) = (a_n + b_n)/2, b_(n+1) = √(a_n b_n) 的共同极限
+///
+/// 迭代二次收敛, 通常 5 ~ 6 步即达到机器精度; 几何平均按 √a_n √b_n 计算, 参数接近上溢或下溢界时也不会溢出.
+/// a, b 中有零时返回 0, 两者均为负数时返回 -AGM(-a, -b), 异号时返回 NaN. 有一个为 +∞ 而另一个为正数时返回 +∞,
+/// 一个为 ∞ 另一个为零时结果不确定, 返回 NaN. 例如 K(m) = π / (2 AGM(1, √(1 - m)))
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::agm;
+/// assert!((agm(1.0, 2.0f64.sqrt()) - 1.1981402347355923).abs() < 1.0e-15);
+/// ```
+pub fn agm(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if a < 0.0 && b < 0.0 {
+        return -agm(-a, -b);
+    }
+    if a < 0.0 || b < 0.0 || (a.is_infinite() && b == 0.0) || (a == 0.0 && b.is_infinite()) {
+        return f64::NAN;
+    }
+    if a == 0.0 || b == 0.0 {
+        return 0.0;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return f64::INFINITY;
+    }
+    let (mut a, mut b) = (a, b);
+    for _ in 0..AGM_MAX_ITER {
+        if (a - b).abs() <= f64::EPSILON * a.min(b) {
+            break;
+        }
+        let a_next = 0.5 * a + 0.5 * b;
+        b = a.sqrt() * b.sqrt();
+        a = a_next;
+    }
+    0.5 * a + 0.5 * b
+}
+
+/// 计算 Carlson 第一类对称椭圆积分 R_F(x, y, z) = (1/2) ∫_0^∞ dt / √((t + x)(t + y)(t + z))
+///
+/// 使用 Carlson 的倍加算法, 迭代至五阶 Taylor 展开的截断误差低于 ε 为止, 相对误差在数个 ε 以内.
+/// 要求 x, y, z >= 0, 至少两个为零时返回 +∞, 有一个为 +∞ 时返回 0, 有负数时返回 NaN.
+/// 例如 F(φ, m) = sin φ R_F(cos^2 φ, 1 - m sin^2 φ, 1), K(m) = R_F(0, 1 - m, 1)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_rf;
+/// assert!((ellip_rf(1.0, 2.0, 3.0) - 0.7269459354689082).abs() < 1.0e-15);
+/// ```
+pub fn ellip_rf(x: f64, y: f64, z: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0.0 || y < 0.0 || z < 0.0 {
+        return f64::NAN;
+    }
+    if (x == 0.0) as u8 + (y == 0.0) as u8 + (z == 0.0) as u8 >= 2 {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.0;
+    }
+    carlson_rf(x, y, z)
+}
+
+/// 计算 Carlson 退化椭圆积分 R_C(x, y) = R_F(x, y, y) = (1/2) ∫_0^∞ dt / (√(t + x) (t + y))
+///
+/// 这是初等函数: 0 <= x < y 时 R_C(x, y) = arccos(√(x/y)) / √(y - x), x > y > 0 时为 artanh 型.
+/// 使用倍加算法以避免 x 接近 y 时的相消, 相对误差在数个 ε 以内. 要求 x >= 0, y < 0 时返回 Cauchy 主值,
+/// y = 0 时返回 +∞, x 或 y 为无穷大时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_rc;
+/// assert!((ellip_rc(0.0, 0.25) - std::f64::consts::PI).abs() < 1.0e-15);
+/// ```
+pub fn ellip_rc(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if y == 0.0 {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() || y.is_infinite() {
+        return 0.0;
+    }
+    carlson_rc(x, y)
+}
+
+/// 计算 Carlson 第二类对称椭圆积分 R_D(x, y, z) = R_J(x, y, z, z) = (3/2) ∫_0^∞ dt / (√((t + x)(t + y)) (t + z)^(3/2))
+///
+/// 使用 Carlson 的倍加算法, 相对误差在数个 ε 以内. 要求 x, y >= 0, z > 0,
+/// x = y = 0 或 z = 0 时返回 +∞, 有一个为 +∞ 时返回 0, 有负数时返回 NaN.
+/// 例如 E(m) = R_F(0, 1 - m, 1) - (m/3) R_D(0, 1 - m, 1)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_rd;
+/// assert!((ellip_rd(1.0, 2.0, 3.0) - 0.29046028102899063).abs() < 1.0e-15);
+/// ```
+pub fn ellip_rd(x: f64, y: f64, z: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0.0 || y < 0.0 || z < 0.0 {
+        return f64::NAN;
+    }
+    if z == 0.0 || (x == 0.0 && y == 0.0) {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return 0.0;
+    }
+    carlson_rd(x, y, z)
+}
+
+/// 计算 Carlson 第三类对称椭圆积分 R_J(x, y, z, p) = (3/2) ∫_0^∞ dt / (√((t + x)(t + y)(t + z)) (t + p))
+///
+/// 使用 Carlson 的倍加算法, 每步所需的 R_C 同样由倍加算法计算, p > 0 时相对误差在 10 ε 以内.
+/// 要求 x, y, z >= 0; p < 0 时返回 Cauchy 主值, 它由 p > 0 的 R_J 与 R_F, R_C 组合得到, 结果接近零时有相消.
+/// 至少两个 x, y, z 为零或 p = 0 时返回 +∞, 有一个为无穷大时返回 0, x, y, z 有负数时返回 NaN.
+/// 例如 Π(n, m) = R_F(0, 1 - m, 1) + (n/3) R_J(0, 1 - m, 1, 1 - n)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_rj;
+/// assert!((ellip_rj(1.0, 2.0, 3.0, 4.0) - 0.23984809974956775).abs() < 1.0e-15);
+/// ```
+pub fn ellip_rj(x: f64, y: f64, z: f64, p: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || p.is_nan() || x < 0.0 || y < 0.0 || z < 0.0 {
+        return f64::NAN;
+    }
+    if p == 0.0 || (x == 0.0) as u8 + (y == 0.0) as u8 + (z == 0.0) as u8 >= 2 {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() || p.is_infinite() {
+        return 0.0;
+    }
+    let max = x.max(y).max(z).max(p.abs());
+    if (1.0 / CARLSON_SCALE_MAX..=CARLSON_SCALE_MAX).contains(&max) {
+        return carlson_rj(x, y, z, p);
+    }
+    // R_J(x, y, z, p) = 2^(-3h) R_J(2^(-2h) x, 2^(-2h) y, 2^(-2h) z, 2^(-2h) p), 缩放是精确的
+    let h = (0.5 * max.log2()).floor().max(-500.0) as i32;
+    let t = 2f64.powi(-2 * h);
+    let (x, y, z) = (t * x, t * y, t * z);
+    if (x == 0.0) as u8 + (y == 0.0) as u8 + (z == 0.0) as u8 >= 2 {
+        // 缩放后有两个自变量下溢为零, 倍加算法无法进行, 按 R_J(0, 0, z, p) = +∞ 处理
+        return f64::INFINITY;
+    }
+    let s = 2f64.powi(-h);
+    carlson_rj(x, y, z, t * p) * s * s * s
+}
+
+/// 计算 Carlson 完全对称椭圆积分 R_G(x, y, z) = (1/4π) ∫_0^2π ∫_0^π √(x sin^2 θ cos^2 φ + y sin^2 θ sin^2 φ + z cos^2 θ) sin θ dθ dφ
+///
+/// 将自变量排序为 a <= b <= c 后由 2 R_G(a, b, c) = b R_F(a, b, c) + (c - b)(b - a) R_D(a, c, b) / 3 + √(ac/b) 计算,
+/// 三项均非负, 没有相消, 相对误差在数个 ε 以内. 要求 x, y, z >= 0, 有一个为 +∞ 时返回 +∞, 有负数时返回 NaN.
+/// 例如 E(m) = 2 R_G(0, 1 - m, 1)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_rg;
+/// assert!((ellip_rg(1.0, 2.0, 3.0) - 1.4018470999908952).abs() < 1.0e-15);
+/// ```
+pub fn ellip_rg(x: f64, y: f64, z: f64) -> f64 {
+    if x.is_nan() || y.is_nan() || z.is_nan() || x < 0.0 || y < 0.0 || z < 0.0 {
+        return f64::NAN;
+    }
+    if x.is_infinite() || y.is_infinite() || z.is_infinite() {
+        return f64::INFINITY;
+    }
+    let mut v = [x, y, z];
+    v.sort_by(f64::total_cmp);
+    let mut factor = 0.5;
+    if v[2] > 0.0 && !(1.0 / CARLSON_SCALE_MAX..=CARLSON_SCALE_MAX).contains(&v[2]) {
+        // R_G(x, y, z) = 2^h R_G(2^(-2h) x, 2^(-2h) y, 2^(-2h) z)
+        let h = (0.5 * v[2].log2()).floor().max(-500.0) as i32;
+        let t = 2f64.powi(-2 * h);
+        v = v.map(|u| t * u);
+        factor *= 2f64.powi(h);
+    }
+    let [a, b, c] = v;
+    if b == 0.0 {
+        // R_G(0, 0, z) = √z / 2
+        return factor * c.sqrt();
+    }
+    factor
+        * (b * carlson_rf(a, b, c)
+            + (c - b) * (b - a) / 3.0 * carlson_rd(a, c, b)
+            + (a * c / b).sqrt())
+}
+
+/// 计算第一类完全椭圆积分 K(m) = ∫_0^(π/2) dθ / √(1 - m sin^2 θ), 要求参数 m <= 1
+///
+/// 0 <= m < 1 时由算术-几何平均 K(m) = π / (2 AGM(1, √(1 - m))) 计算, m → 1 时 K(m) ~ ln(4/√(1 - m))
+/// 的对数发散由 √(1 - m) 精确体现; m < 0 时由虚模变换 K(m) = K(-m/(1 - m)) / √(1 - m) 化为 [0, 1) 上的情形.
+/// K(1) = +∞, K(-∞) = 0, m > 1 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_k;
+/// assert!((ellip_k(0.5) - 1.8540746773013719).abs() < 1.0e-15);
+/// ```
+pub fn ellip_k(m: f64) -> f64 {
+    if m.is_nan() || m > 1.0 {
+        return f64::NAN;
+    }
+    if m == 1.0 {
+        return f64::INFINITY;
+    }
+    if m == f64::NEG_INFINITY {
+        return 0.0;
+    }
+    if m < 0.0 {
+        // 1 - (-m/(1 - m)) = 1/(1 - m)
+        let p = 1.0 / (1.0 - m);
+        return p.sqrt() * ellip_k_complement(p);
+    }
+    ellip_k_complement(1.0 - m)
+}
+
+/// 计算第二类完全椭圆积分 E(m) = ∫_0^(π/2) √(1 - m sin^2 θ) dθ, 要求参数 m <= 1
+///
+/// 0 <= m < 1 时由算术-几何平均计算, m 接近 1 时改用关于 1 - m 的对数型级数以避免相消;
+/// m < 0 时由虚模变换 E(m) = √(1 - m) E(-m/(1 - m)) 计算. E(1) = 1, E(-∞) = +∞, m > 1 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_e;
+/// assert!((ellip_e(0.5) - 1.3506438810476755).abs() < 1.0e-15);
+/// ```
+pub fn ellip_e(m: f64) -> f64 {
+    if m.is_nan() || m > 1.0 {
+        return f64::NAN;
+    }
+    if m == 1.0 {
+        return 1.0;
+    }
+    if m == f64::NEG_INFINITY {
+        return f64::INFINITY;
+    }
+    if m < 0.0 {
+        let p = 1.0 / (1.0 - m);
+        return ellip_e_complement(p) / p.sqrt();
+    }
+    ellip_e_complement(1.0 - m)
+}
+
+/// 将 φ 约化为 φ = jπ + ψ, |ψ| <= π/2, 返回 (j, ψ). φ/π 的舍入值恰为半整数时 (如 φ = fl(π/2))
+/// 按 φ 与该半整数倍 π 的真实大小关系取整; 舍入误差仍可能使 ψ 略超出 [-π/2, π/2] 而 cos ψ < 0, 因此再将 ψ 限制在该区间内
+pub(crate) fn ellip_reduce(phi: f64) -> (f64, f64) {
+    let ratio = phi / PI;
+    let j = if ratio.fract().abs() == 0.5 {
+        let d = (-ratio).mul_add(PI, phi) - ratio * PI_LO;
+        if d > 0.0 {
+            ratio.ceil()
+        } else {
+            ratio.floor()
+        }
+    } else {
+        ratio.round()
+    };
+    let psi = (-j).mul_add(PI, phi) - j * PI_LO;
+    (j, psi.clamp(-FRAC_PI_2, FRAC_PI_2))
+}
+
+/// 计算 F(φ, m) (second 为假) 或 E(φ, m) (second 为真), 要求 φ, m 有限
+///
+/// 记 φ = jπ + ψ, |ψ| <= π/2, s = sin ψ, c = cos ψ, 则
+/// F(φ, m) = 2j K(m) + s R_F(c^2, 1 - m s^2, 1), E(φ, m) = 2j E(m) + s R_F(c^2, 1 - m s^2, 1) - (m/3) s^3 R_D(c^2, 1 - m s^2, 1).
+/// 0 <= m <= 1 时 E(φ, m) 改用 s [(1 - m) R_F(c^2, Δ^2, 1) + (m(1 - m)/3) s^2 R_D(c^2, 1, Δ^2) + m c/Δ],
+/// 其中 Δ^2 = 1 - m s^2 按 c^2 + (1 - m) s^2 计算, 避免 m → 1, ψ → ±π/2 时的相消.
+/// m > 1 时被积函数仅在 |sin θ| <= 1/√m 上为实数, 超出该范围时返回 NaN
+fn ellip_incomplete(phi: f64, m: f64, second: bool) -> f64 {
+    let (j, psi) = ellip_reduce(phi);
+    let (s, c) = psi.sin_cos();
+    let cc = c * c;
+    let y = cc + (1.0 - m) * s * s;
+    if y < 0.0 || (m > 1.0 && j != 0.0) {
+        return f64::NAN;
+    }
+    let mut value = s * ellip_rf(cc, y, 1.0);
+    if second {
+        let s3 = s * s * s;
+        if (0.0..=1.0).contains(&m) {
+            // 上式两项在 m → 1, ψ → ±π/2 时均对数发散而相互抵消, 改用各项同号的等价形式
+            let mc = 1.0 - m;
+            value = mc * value + m * mc / 3.0 * s3 * ellip_rd(cc, 1.0, y) + m * s * c / y.sqrt();
+        } else {
+            value -= m / 3.0 * s3 * ellip_rd(cc, y, 1.0);
+        }
+    }
+    if j != 0.0 {
+        let complete = if second { ellip_e(m) } else { ellip_k(m) };
+        value += 2.0 * j * complete;
+    }
+    value
+}
+
+/// 计算第一类不完全椭圆积分 F(φ, m) = ∫_0^φ dθ / √(1 - m sin^2 θ)
+///
+/// |φ| <= π/2 时由 Carlson 对称形式 F(φ, m) = sin φ R_F(cos^2 φ, 1 - m sin^2 φ, 1) 计算, 其余 φ 由
+/// F(φ + jπ, m) = F(φ, m) + 2j K(m) 延拓. m > 1 时仅在 |sin φ| <= 1/√m 且 |φ| <= π/2 时有定义, 否则返回 NaN.
+/// m = 1 时 F(φ, 1) = artanh(sin φ), 在 φ = ±π/2 处发散. φ = ±∞ 且 m <= 1 时返回 ±∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_f;
+/// assert!((ellip_f(1.0, 0.5) - 1.0832167728451687).abs() < 1.0e-15);
+/// ```
+pub fn ellip_f(phi: f64, m: f64) -> f64 {
+    if phi.is_nan() || m.is_nan() || m.is_infinite() {
+        return f64::NAN;
+    }
+    if phi.is_infinite() {
+        return if m <= 1.0 { phi } else { f64::NAN };
+    }
+    ellip_incomplete(phi, m, false)
+}
+
+/// 计算第二类不完全椭圆积分 E(φ, m) = ∫_0^φ √(1 - m sin^2 θ) dθ
+///
+/// |φ| <= π/2 时由 E(φ, m) = sin φ R_F(cos^2 φ, 1 - m sin^2 φ, 1) - (m/3) sin^3 φ R_D(cos^2 φ, 1 - m sin^2 φ, 1) 计算,
+/// 其余 φ 由 E(φ + jπ, m) = E(φ, m) + 2j E(m) 延拓. 定义域与 `ellip_f` 相同
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_e_inc;
+/// assert!((ellip_e_inc(1.0, 0.5) - 0.92732988362444).abs() < 1.0e-15);
+/// ```
+pub fn ellip_e_inc(phi: f64, m: f64) -> f64 {
+    if phi.is_nan() || m.is_nan() || m.is_infinite() {
+        return f64::NAN;
+    }
+    if phi.is_infinite() {
+        return if m <= 1.0 { phi } else { f64::NAN };
+    }
+    ellip_incomplete(phi, m, true)
+}
+
+/// 计算第三类完全椭圆积分 Π(n, m) = ∫_0^(π/2) dθ / ((1 - n sin^2 θ) √(1 - m sin^2 θ)), 要求 m <= 1
+///
+/// 由 Carlson 对称形式 Π(n, m) = R_F(0, 1 - m, 1) + (n/3) R_J(0, 1 - m, 1, 1 - n) 计算.
+/// n > 1 时被积函数在 (0, π/2) 内有极点, 此时返回 Cauchy 主值, 满足 Π(n, m) = K(m) - Π(m/n, m).
+/// n = 1 或 m = 1 时积分发散, 返回 ±∞ (n > 1, m = 1 时为 -∞); m > 1 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_pi;
+/// assert!((ellip_pi(0.3, 0.5) - 2.250376821943947).abs() < 1.0e-15);
+/// ```
+pub fn ellip_pi(n: f64, m: f64) -> f64 {
+    if n.is_nan() || m.is_nan() || m > 1.0 || n.is_infinite() || m.is_infinite() {
+        return f64::NAN;
+    }
+    if m == 1.0 {
+        return if n > 1.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    if n == 1.0 {
+        return f64::INFINITY;
+    }
+    let y = 1.0 - m;
+    ellip_rf(0.0, y, 1.0) + n / 3.0 * ellip_rj(0.0, y, 1.0, 1.0 - n)
+}
+
+/// 计算第三类不完全椭圆积分 Π(n; φ, m) = ∫_0^φ dθ / ((1 - n sin^2 θ) √(1 - m sin^2 θ))
+///
+/// 记 φ = jπ + ψ, |ψ| <= π/2, s = sin ψ, c = cos ψ, 则
+/// Π(n; φ, m) = 2j Π(n, m) + s R_F(c^2, 1 - m s^2, 1) + (n/3) s^3 R_J(c^2, 1 - m s^2, 1, 1 - n s^2),
+/// 其中 1 - n s^2 按 c^2 + (1 - n) s^2 计算. 积分区间内含极点 sin^2 θ = 1/n 时返回 Cauchy 主值.
+/// m 的定义域与 `ellip_f` 相同, 超出时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::ellip_pi_inc;
+/// assert!((ellip_pi_inc(0.3, 1.0, 0.5) - 1.1923254369345582).abs() < 1.0e-15);
+/// ```
+pub fn ellip_pi_inc(n: f64, phi: f64, m: f64) -> f64 {
+    if n.is_nan() || phi.is_nan() || m.is_nan() || n.is_infinite() || m.is_infinite() {
+        return f64::NAN;
+    }
+    if phi.is_infinite() {
+        return if m <= 1.0 && n < 1.0 { phi } else { f64::NAN };
+    }
+    let (j, psi) = ellip_reduce(phi);
+    let (s, c) = psi.sin_cos();
+    let cc = c * c;
+    let ss = s * s;
+    let y = cc + (1.0 - m) * ss;
+    if y < 0.0 || (m > 1.0 && j != 0.0) {
+        return f64::NAN;
+    }
+    let p = cc + (1.0 - n) * ss;
+    let mut value = s * ellip_rf(cc, y, 1.0) + n / 3.0 * s * ss * ellip_rj(cc, y, 1.0, p);
+    if j != 0.0 {
+        value += 2.0 * j * ellip_pi(n, m);
+    }
+    value
+}
+
+/// 计算 Jacobi zeta 函数 Z(φ, m) = E(φ, m) - (E(m)/K(m)) F(φ, m), 要求 m <= 1
+///
+/// 直接按定义计算在 E(φ, m) 与 (E/K) F(φ, m) 接近时有相消, 改用 Carlson 形式
+/// Z(φ, m) = (m / (3K(m))) sin φ cos φ √(1 - m sin^2 φ) R_J(0, 1 - m, 1, 1 - m sin^2 φ), 各因子同号, 没有相消.
+/// Z 是以 π 为周期的奇函数. m = 1 时取极限 Z(φ, 1) = sin ψ, 其中 ψ ∈ [-π/2, π/2] 为 φ 模 π 约化后的值,
+/// m > 1 或 φ 为无穷大时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::jacobi_zeta;
+/// assert!((jacobi_zeta(0.7, 0.5) - 0.1422994719057059).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_zeta(phi: f64, m: f64) -> f64 {
+    if phi.is_nan() || m.is_nan() || phi.is_infinite() || m > 1.0 {
+        return f64::NAN;
+    }
+    if m == 1.0 {
+        return ellip_reduce(phi).1.sin();
+    }
+    // s c 与 s^2 在 φ → φ + π 下不变, 因而无需约化 φ, 避免在 cos φ 的零点附近损失精度
+    let (s, c) = phi.sin_cos();
+    // 1 - m sin^2 φ = cos^2 φ + (1 - m) sin^2 φ, 后者在 m → 1, φ → π/2 时没有相消
+    let mc = 1.0 - m;
+    let delta2 = c * c + mc * s * s;
+    m / (3.0 * ellip_k(m)) * s * c * delta2.sqrt() * ellip_rj(0.0, mc, 1.0, delta2)
+}
+
+/// 计算 Heuman lambda 函数 Λ_0(φ, m) = F(φ, 1 - m)/K(1 - m) + (2/π) K(m) Z(φ, 1 - m), 要求 0 <= m <= 1
+///
+/// φ ∈ [0, π/2] 时两项均非负, 没有相消; Z 由 `jacobi_zeta` 的 Carlson 形式计算. 由 F 的拟周期性, Λ_0(φ + π, m) = Λ_0(φ, m) + 2,
+/// Λ_0(π/2, m) = 1. 端点取极限 Λ_0(φ, 0) = sin φ, Λ_0(φ, 1) = 2φ/π (均连同上述的平移关系).
+/// m 超出 [0, 1] 或 φ 为无穷大时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::elliptic::heuman_lambda;
+/// assert!((heuman_lambda(0.7, 0.5) - 0.5610260039668115).abs() < 1.0e-15);
+/// ```
+pub fn heuman_lambda(phi: f64, m: f64) -> f64 {
+    if phi.is_nan() || m.is_nan() || phi.is_infinite() || !(0.0..=1.0).contains(&m) {
+        return f64::NAN;
+    }
+    if m == 1.0 {
+        return phi / FRAC_PI_2;
+    }
+    if m == 0.0 {
+        let (j, psi) = ellip_reduce(phi);
+        return psi.sin() + 2.0 * j;
+    }
+    let mc = 1.0 - m;
+    ellip_f(phi, mc) / ellip_k(mc) + ellip_k(m) / FRAC_PI_2 * jacobi_zeta(phi, mc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_agm() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(agm(1.0, 2.0f64.sqrt()), 1.1981402347355923, tol));
+        assert!(approx_eq(agm(24.0, 6.0) / 13.458171481725616, 1.0, tol));
+        assert!(approx_eq(agm(0.5, 7.0), 2.7289578129195022, tol));
+        assert!(approx_eq(agm(-0.5, -7.0), -2.7289578129195022, tol));
+        assert_eq!(agm(3.0, 3.0), 3.0);
+        assert!(approx_eq(
+            agm(1.0e300, 1.0e-300) / 1.1358405546107696e297,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            agm(1.0e-310, 1.0e-300) / 6.434487047601331e-302,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(agm(1.0e308, 1.0e308), 1.0e308);
+        assert_eq!(agm(0.0, 5.0), 0.0);
+        assert_eq!(agm(f64::INFINITY, 5.0), f64::INFINITY);
+        assert!(agm(-1.0, 2.0).is_nan());
+        assert!(agm(f64::INFINITY, 0.0).is_nan());
+        assert!(agm(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_rf() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_rf(1.0, 2.0, 3.0), 0.7269459354689082, tol));
+        assert!(approx_eq(ellip_rf(0.0, 1.0, 2.0), 1.3110287771460598, tol));
+        assert!(approx_eq(ellip_rf(2.0, 3.0, 4.0), 0.5840828416771517, tol));
+        assert!(approx_eq(
+            ellip_rf(0.5, 1.0e-10, 1.0),
+            1.854060535300811,
+            tol
+        ));
+        assert!(approx_eq(ellip_rf(1.0, 1.0, 1.0), 1.0, tol));
+        assert!(approx_eq(ellip_rf(0.0, 0.5, 1.0), ellip_k(0.5), tol));
+        assert!(approx_eq(
+            ellip_rf(1.0e300, 2.0e300, 3.0e300) / 7.269459354689082e-151,
+            1.0,
+            tol
+        ));
+        assert_eq!(ellip_rf(0.0, 0.0, 1.0), f64::INFINITY);
+        assert_eq!(ellip_rf(1.0, 2.0, f64::INFINITY), 0.0);
+        assert!(ellip_rf(-1.0, 2.0, 3.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_rc() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_rc(0.0, 0.25), PI, tol));
+        assert!(approx_eq(ellip_rc(2.0, 1.0), 0.881373587019543, tol));
+        assert!(approx_eq(ellip_rc(0.25, -2.0), 0.23104906018664845, tol));
+        assert!(approx_eq(
+            ellip_rc(1.0, 1.0 + 1.0e-10),
+            0.9999999999666667,
+            tol
+        ));
+        assert!(approx_eq(ellip_rc(1.0, 1.0), 1.0, tol));
+        assert!(approx_eq(
+            ellip_rc(1.0e-300, 1.0e300) / 1.5707963267948966e-150,
+            1.0,
+            tol
+        ));
+        assert_eq!(ellip_rc(0.0, -1.0), 0.0);
+        assert_eq!(ellip_rc(1.0, 0.0), f64::INFINITY);
+        assert_eq!(ellip_rc(f64::INFINITY, 1.0), 0.0);
+        assert!(ellip_rc(-1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_rd() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_rd(1.0, 2.0, 3.0), 0.29046028102899063, tol));
+        assert!(approx_eq(ellip_rd(0.0, 2.0, 1.0), 1.7972103521033884, tol));
+        assert!(approx_eq(ellip_rd(2.0, 3.0, 4.0), 0.16510527294261054, tol));
+        assert!(approx_eq(
+            ellip_rd(0.5, 1.0e-10, 1.0),
+            3.020542351671523,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rd(1.0, 2.0, 3.0),
+            ellip_rj(1.0, 2.0, 3.0, 3.0),
+            tol
+        ));
+        assert_eq!(ellip_rd(1.0, 2.0, 0.0), f64::INFINITY);
+        assert_eq!(ellip_rd(0.0, 0.0, 1.0), f64::INFINITY);
+        assert_eq!(ellip_rd(f64::INFINITY, 2.0, 3.0), 0.0);
+        assert!(ellip_rd(1.0, f64::NAN, 3.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_rj() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            ellip_rj(1.0, 2.0, 3.0, 4.0),
+            0.23984809974956775,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(0.0, 1.0, 2.0, 3.0),
+            0.7768862377858233,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(2.0, 3.0, 4.0, 5.0),
+            0.14297579667156754,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(2.0, 3.0, 4.0, -0.5),
+            0.24723819703051564,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(0.5, 1.0e-10, 1.0, 2.0),
+            1.7428207186977975,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(1.0e200, 2.0e200, 3.0e200, 4.0e200) / 2.3984809974956776e-301,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_rj(1.0e-120, 2.0e-120, 3.0e-120, 4.0e-120) / 2.3984809974956776e179,
+            1.0,
+            tol
+        ));
+        assert_eq!(ellip_rj(1.0, 2.0, 3.0, 0.0), f64::INFINITY);
+        assert_eq!(ellip_rj(0.0, 0.0, 3.0, 1.0), f64::INFINITY);
+        assert_eq!(ellip_rj(1.0, 2.0, 3.0, f64::NEG_INFINITY), 0.0);
+        assert!(ellip_rj(1.0, -2.0, 3.0, 4.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_rg() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_rg(1.0, 2.0, 3.0), 1.4018470999908952, tol));
+        assert!(approx_eq(ellip_rg(0.0, 16.0, 16.0), PI, tol));
+        assert!(approx_eq(ellip_rg(2.0, 3.0, 4.0), 1.7255030280692278, tol));
+        assert!(approx_eq(
+            ellip_rg(0.0, 0.0796, 4.0),
+            1.028475809028804,
+            tol
+        ));
+        assert!(approx_eq(ellip_rg(0.0, 0.5, 1.0), 0.5 * ellip_e(0.5), tol));
+        assert!(approx_eq(
+            ellip_rg(1.0e300, 2.0e300, 3.0e300) / 1.4018470999908951e150,
+            1.0,
+            tol
+        ));
+        assert_eq!(ellip_rg(0.0, 0.0, 4.0), 1.0);
+        assert_eq!(ellip_rg(0.0, 0.0, 0.0), 0.0);
+        assert_eq!(ellip_rg(1.0, 2.0, f64::INFINITY), f64::INFINITY);
+        assert!(ellip_rg(1.0, 2.0, -3.0).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_k() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_k(0.0), FRAC_PI_2, tol));
+        assert!(approx_eq(ellip_k(1.0e-20), FRAC_PI_2, tol));
+        assert!(approx_eq(ellip_k(0.1), 1.6124413487202194, tol));
+        assert!(approx_eq(ellip_k(0.5), 1.8540746773013719, tol));
+        assert!(approx_eq(ellip_k(0.9), 2.5780921133481733, tol));
+        assert!(approx_eq(ellip_k(0.99), 3.6956373629898747, tol));
+        assert!(approx_eq(
+            ellip_k(0.9999999999) / 12.899219785017415,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(ellip_k(-0.5), 1.4157372084259563, tol));
+        assert!(approx_eq(ellip_k(-1.0), 1.3110287771460598, tol));
+        assert!(approx_eq(
+            ellip_k(-1.0e10) / 0.00012899219825792638,
+            1.0,
+            tol
+        ));
+        assert_eq!(ellip_k(1.0), f64::INFINITY);
+        assert_eq!(ellip_k(f64::NEG_INFINITY), 0.0);
+        assert!(ellip_k(1.5).is_nan());
+        assert!(ellip_k(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_e() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_e(0.0), FRAC_PI_2, tol));
+        assert!(approx_eq(ellip_e(1.0e-20), FRAC_PI_2, tol));
+        assert!(approx_eq(ellip_e(0.1), 1.5307576368977631, tol));
+        assert!(approx_eq(ellip_e(0.5), 1.3506438810476755, tol));
+        assert!(approx_eq(ellip_e(0.89), 1.112855607039631, tol));
+        assert!(approx_eq(ellip_e(0.9), 1.1047747327040733, tol));
+        assert!(approx_eq(ellip_e(0.91), 1.0964775173922272, tol));
+        assert!(approx_eq(ellip_e(0.99), 1.015993545025224, tol));
+        assert!(approx_eq(ellip_e(0.9999999999), 1.000000000619961, tol));
+        assert!(approx_eq(ellip_e(-0.5), 1.7517712756948178, tol));
+        assert!(approx_eq(ellip_e(-1.0), 1.910098894513856, tol));
+        assert!(approx_eq(ellip_e(-1.0e10) / 100000.0000669961, 1.0, tol));
+        assert_eq!(ellip_e(1.0), 1.0);
+        assert_eq!(ellip_e(f64::NEG_INFINITY), f64::INFINITY);
+        assert!(ellip_e(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_f() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_f(1.0, 0.5), 1.0832167728451687, tol));
+        assert!(approx_eq(ellip_f(0.5, 0.3), 0.5061402119623553, tol));
+        assert!(approx_eq(ellip_f(-1.2, 0.9), -1.5648981345066715, tol));
+        assert!(approx_eq(ellip_f(FRAC_PI_2, 0.5), ellip_k(0.5), tol));
+        assert!(approx_eq(ellip_f(4.0, 0.5), 4.619520616257107, tol));
+        assert!(approx_eq(ellip_f(-7.0, 0.3), -7.59007171719006, tol));
+        assert!(approx_eq(ellip_f(2.0, -3.0), 1.2979278392804405, tol));
+        assert!(approx_eq(ellip_f(10.0, 0.99), 22.783267219475515, 1.0e-14));
+        assert!(approx_eq(ellip_f(100.0, -50.0), 29.85613657542345, 1.0e-14));
+        assert!(approx_eq(ellip_f(0.3, 2.0), 0.3096205756256344, tol));
+        assert!(approx_eq(ellip_f(1.5, 1.0), 3.340677542798311, tol));
+        assert!(approx_eq(ellip_f(1.0e-10, 0.5) / 1.0e-10, 1.0, tol));
+        assert_eq!(ellip_f(4.0, 1.0), f64::INFINITY);
+        // fl(π/2) < π/2, fl(3π/2) < 3π/2
+        assert!(approx_eq(
+            ellip_f(FRAC_PI_2, 1.0) / 38.02500337382921,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            ellip_f(-FRAC_PI_2, 1.0) / -38.02500337382921,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(ellip_f(3.0 * FRAC_PI_2, 1.0), f64::INFINITY);
+        assert_eq!(ellip_f(f64::NEG_INFINITY, 0.5), f64::NEG_INFINITY);
+        assert!(ellip_f(1.0, 2.0).is_nan());
+        assert!(ellip_f(4.0, 1.5).is_nan());
+        assert!(ellip_f(f64::NAN, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_e_inc() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_e_inc(1.0, 0.5), 0.92732988362444, tol));
+        assert!(approx_eq(ellip_e_inc(0.5, 0.3), 0.49399114472896843, tol));
+        assert!(approx_eq(ellip_e_inc(-1.2, 0.9), -0.967037660288675, tol));
+        assert!(approx_eq(ellip_e_inc(FRAC_PI_2, 0.5), ellip_e(0.5), tol));
+        assert!(approx_eq(ellip_e_inc(4.0, 0.5), 3.511927740482793, tol));
+        assert!(approx_eq(ellip_e_inc(-7.0, 0.3), -6.481303624022843, tol));
+        assert!(approx_eq(ellip_e_inc(2.0, -3.0), 3.261074564114756, tol));
+        assert!(approx_eq(ellip_e_inc(10.0, 0.99), 6.640311327957426, tol));
+        assert!(approx_eq(
+            ellip_e_inc(100.0, -50.0) / 468.75399044805084,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(ellip_e_inc(0.3, 2.0), 0.2909118734264599, tol));
+        assert!(approx_eq(ellip_e_inc(1.5, 1.0), 0.9974949866040544, tol));
+        assert!(approx_eq(ellip_e_inc(4.0, 1.0), 2.7568024953079284, tol));
+        assert!(approx_eq(ellip_e_inc(FRAC_PI_2, 1.0), 1.0, tol));
+        assert!(approx_eq(ellip_e_inc(3.0 * FRAC_PI_2, 1.0), 3.0, tol));
+        assert!(approx_eq(ellip_e_inc(-3.0 * FRAC_PI_2, 1.0), -3.0, tol));
+        assert_eq!(ellip_e_inc(f64::INFINITY, 0.5), f64::INFINITY);
+        assert!(ellip_e_inc(1.0, 2.0).is_nan());
+        assert!(ellip_e_inc(0.5, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_pi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ellip_pi(0.3, 0.5), 2.250376821943947, tol));
+        assert!(approx_eq(ellip_pi(0.0, 0.5), ellip_k(0.5), tol));
+        assert!(approx_eq(ellip_pi(-2.0, 0.5), 1.0226975086065924, tol));
+        assert!(approx_eq(ellip_pi(0.5, -3.0), 1.4400343186575506, tol));
+        assert!(approx_eq(ellip_pi(0.9, 0.99), 20.32600549644431, tol));
+        assert!(approx_eq(ellip_pi(3.0, 0.5), -0.19298820849013934, tol));
+        assert!(approx_eq(
+            ellip_pi(3.0, 0.5),
+            ellip_k(0.5) - ellip_pi(0.5 / 3.0, 0.5),
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi(-1.0e3, 0.5),
+            0.05013895255200541,
+            1.0e-14
+        ));
+        assert_eq!(ellip_pi(1.0, 0.5), f64::INFINITY);
+        assert_eq!(ellip_pi(0.5, 1.0), f64::INFINITY);
+        assert_eq!(ellip_pi(2.0, 1.0), f64::NEG_INFINITY);
+        assert!(ellip_pi(0.5, 1.5).is_nan());
+    }
+
+    #[test]
+    fn test_ellip_pi_inc() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            ellip_pi_inc(0.3, 1.0, 0.5),
+            1.1923254369345582,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(0.0, 1.0, 0.5),
+            ellip_f(1.0, 0.5),
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(0.3, FRAC_PI_2, 0.5),
+            ellip_pi(0.3, 0.5),
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(1.0, 1.0, 0.5),
+            1.731991542023527,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(3.0, 1.0, 0.5),
+            0.2715305188238305,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(-2.0, 4.0, 0.7),
+            2.904118755189584,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(0.5, -7.0, 0.3),
+            -10.64657164199776,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(0.8, 0.3, 2.0),
+            0.31743983385241975,
+            tol
+        ));
+        assert!(approx_eq(
+            ellip_pi_inc(5.0, 10.0, 0.2),
+            0.2989886458300093,
+            1.0e-14
+        ));
+        assert!(ellip_pi_inc(0.5, 1.0, 2.0).is_nan());
+        assert!(ellip_pi_inc(f64::NAN, 1.0, 0.5).is_nan());
+    }
+    #[test]
+    fn test_jacobi_zeta() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_zeta(0.7, 0.5), 0.1422994719057059, tol));
+        assert!(approx_eq(jacobi_zeta(1.4, 0.99), 0.33626224444082575, tol));
+        assert!(approx_eq(jacobi_zeta(0.3, -3.0), -0.334393470830622, tol));
+        assert!(approx_eq(jacobi_zeta(5.0, 0.3), -0.04621865419674823, tol));
+        assert!(approx_eq(
+            jacobi_zeta(1.0e-8, 0.5) / 2.715267094777682e-09,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            jacobi_zeta(1.5, 1.0 - 1.0e-12),
+            0.777739823988236,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_zeta(-1.5708555210528434, 0.10383968136494803) / 3.2020497425705e-06,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(jacobi_zeta(1.0, 0.0), 0.0);
+        assert!(approx_eq(jacobi_zeta(4.0, 1.0), (4.0 - PI).sin(), tol));
+        // m = 1 时 Z 在 φ = π/2 + jπ 处跳变; fl(π/2) < π/2, fl(3π/2) < 3π/2
+        assert_eq!(jacobi_zeta(FRAC_PI_2, 1.0), 1.0);
+        assert_eq!(jacobi_zeta(-FRAC_PI_2, 1.0), -1.0);
+        assert_eq!(jacobi_zeta(3.0 * FRAC_PI_2, 1.0), 1.0);
+        assert!(jacobi_zeta(1.0, 1.5).is_nan());
+        assert!(jacobi_zeta(f64::INFINITY, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_heuman_lambda() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(heuman_lambda(0.7, 0.5), 0.5610260039668115, tol));
+        assert!(approx_eq(heuman_lambda(1.4, 0.99), 0.892976912237191, tol));
+        assert!(approx_eq(heuman_lambda(5.0, 0.3), 3.0900326690900655, tol));
+        assert!(approx_eq(
+            heuman_lambda(1.0e-8, 0.5) / 8.598466001022379e-09,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            heuman_lambda(1.5, 1.0e-12),
+            0.9974949866038051,
+            tol
+        ));
+        assert!(approx_eq(
+            heuman_lambda(0.2, 1.0 - 1.0e-12),
+            0.12732395447442743,
+            tol
+        ));
+        assert!(approx_eq(heuman_lambda(FRAC_PI_2, 0.3), 1.0, tol));
+        assert!(approx_eq(heuman_lambda(0.7, 0.0), 0.7f64.sin(), tol));
+        assert!(approx_eq(heuman_lambda(0.7, 1.0), 0.7 / FRAC_PI_2, tol));
+        assert!(heuman_lambda(0.7, -0.5).is_nan());
+    }
+}
@@ -0,0 +1,800 @@
+use crate::expint::expint_ei_scaled;
+use crate::gamma::EULER;
+use crate::utils::eval_poly;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI, LN_2};
+
+/// √π
+const SQRT_PI: f64 = 1.772453850905516;
+
+/// x 小于此值时 Goodwin–Staton 积分使用 G(x) ≈ -ln x - γ/2 + √π x, 截断误差为 O(x^2 ln x)
+const GOODWIN_STATON_SMALL: f64 = 1.0e-8;
+
+/// 在 |x| <= 0.5 上以 erf(x) / x = P(x^2) 的多项式逼近计算 erf(x) / x
+fn erf_small(x: f64) -> f64 {
+    eval_poly(
+        x * x,
+        &[
+            -1.462091340945175e-07,
+            1.637123442577e-06,
+            -1.4923003368152099e-05,
+            0.00012055286202005714,
+            -0.0008548326510724796,
+            0.0052239776220169365,
+            -0.02686617064499972,
+            0.1128379167095487,
+            -0.3761263890318375,
+            FRAC_2_SQRT_PI,
+        ],
+    )
+}
+
+/// 在 0.5 <= x < 6 上计算 erfc(x) e^(x^2), 分为三段, 每段以 x 与区间中点之差的多项式逼近
+fn erfc_scaled_mid(x: f64) -> f64 {
+    if x < 1.5 {
+        eval_poly(
+            x - 1.0,
+            &[
+                -3.914617199714194e-08,
+                1.3784633743013745e-07,
+                -4.299143543295316e-07,
+                1.4340410570175926e-06,
+                -4.675888436513413e-06,
+                1.4754609192515983e-05,
+                -4.5143861219947744e-05,
+                0.0001336627622717746,
+                -0.00038195453301478085,
+                0.0010502694181821256,
+                -0.002769064775600966,
+                0.0069701423740626415,
+                -0.0166618690904203,
+                0.03757229621531269,
+                -0.07922696894132669,
+                0.15437156137190824,
+                -0.27321201478389856,
+                0.427583576155807,
+            ],
+        )
+    } else if x < 3.0 {
+        eval_poly(
+            x - 2.25,
+            &[
+                -1.400353262582745e-10,
+                6.055280430567816e-10,
+                -2.216644094827292e-09,
+                9.178735686798168e-09,
+                -3.7612103166965675e-08,
+                1.4948034958257032e-07,
+                -5.806298566444485e-07,
+                2.203006148039637e-06,
+                -8.150283936965526e-06,
+                2.9353250462341522e-05,
+                -0.0001027210810813699,
+                0.00034853542170552886,
+                -0.0011437284836579198,
+                0.0036189953543767483,
+                -0.011002060756439959,
+                0.03199262741070587,
+                -0.08848650280874916,
+                0.23108725873039188,
+            ],
+        )
+    } else {
+        eval_poly(
+            x - 4.5,
+            &[
+                2.796256096079432e-16,
+                -1.739235249034333e-15,
+                7.406191554852865e-15,
+                -4.471845887377254e-14,
+                2.8373594679567976e-13,
+                -1.6801265535064187e-12,
+                9.786792686590515e-12,
+                -5.6583604363407236e-11,
+                3.2320747696199433e-10,
+                -1.822314079557745e-09,
+                1.0139579714474986e-08,
+                -5.565075559266143e-08,
+                3.011263511055057e-07,
+                -1.605497025334082e-06,
+                8.429241997445513e-06,
+                -4.3550828562693365e-05,
+                0.00022126645452907306,
+                -0.0011045761167899032,
+                0.005413125434612245,
+                -0.02601592863093981,
+                0.12248480427384142,
+            ],
+        )
+    }
+}
+
+/// 在 x >= 6 上计算 erfc(x) e^(x^2), 以 √π x erfc(x) e^(x^2) = P(1 / x^2) 的多项式逼近,
+/// P 在 0 处的展开即为渐近级数 1 - 1 / (2x^2) + 3 / (4x^4) - ...
+fn erfc_scaled_tail(x: f64) -> f64 {
+    let u = 1.0 / (x * x);
+    eval_poly(
+        u,
+        &[
+            163198.90105722388,
+            -44322.389845306614,
+            7186.220337647256,
+            -1040.273582206813,
+            162.2046560799878,
+            -29.529253063162052,
+            6.562488415851431,
+            -1.8749999604706094,
+            0.7499999999303896,
+            -0.4999999999999519,
+            1.0,
+        ],
+    ) / SQRT_PI
+        / x
+}
+
+/// 计算 e^(s x^2), 其中 s 为 ±1 或 ±1/2. 将 x 拆分为高位 hi 与低位 lo, 使 s hi^2 可精确表示,
+/// 再由 e^(s x^2) = e^(s hi^2) e^(s lo (x + hi)) 避免 x^2 的舍入误差被指数函数放大
+pub(crate) fn exp_square(x: f64, s: f64) -> f64 {
+    if x.is_infinite() {
+        return (s * x * x).exp();
+    }
+    let hi = f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000);
+    let lo = x - hi;
+    (s * hi * hi).exp() * (s * lo * (x + hi)).exp()
+}
+
+/// 计算 erfc(x) e^(x^2), 要求 x >= 0.5
+fn erfc_scaled_positive(x: f64) -> f64 {
+    if x < 6.0 {
+        erfc_scaled_mid(x)
+    } else {
+        erfc_scaled_tail(x)
+    }
+}
+
+/// 计算 erfc(x), 要求 x >= 0.5
+fn erfc_positive(x: f64) -> f64 {
+    // erfc(x) 在 x >= 27.3 时下溢为 0
+    if x >= 27.3 {
+        return 0.0;
+    }
+    exp_square(x, -1.0) * erfc_scaled_positive(x)
+}
+
+/// 计算误差函数 erf(x) = 2 / √π ∫_0^x e^(-t^2) dt
+///
+/// |x| <= 0.5 时使用 erf(x) / x 的多项式逼近; 0.5 < |x| < 6 时计算 1 - erfc(|x|),
+/// 其中 erfc(x) e^(x^2) 在各区间上分别由多项式逼近, 此时 erfc(|x|) < 0.48, 相减不损失精度;
+/// |x| >= 6 时 erf(x) 在双精度下等于 ±1
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erf;
+/// assert!((erf(1.0) - 0.8427007929497149).abs() < 1.0e-16);
+/// ```
+pub fn erf(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let ax = x.abs();
+    if ax <= 0.5 {
+        return x * erf_small(x);
+    }
+    let value = if ax < 6.0 {
+        1.0 - erfc_positive(ax)
+    } else {
+        1.0
+    };
+    value.copysign(x)
+}
+
+/// 计算互补误差函数 erfc(x) = 1 - erf(x) = 2 / √π ∫_x^∞ e^(-t^2) dt
+///
+/// x >= 0.5 时直接由 erfc(x) e^(x^2) 的分段多项式逼近计算而非使用 1 - erf(x), 其中 e^(-x^2)
+/// 通过拆分 x 精确计算, 因此直到 x ≈ 27 处下溢之前都保持相对精度, 适用于计算尾概率;
+/// x < 0.5 时使用 1 - erf(x) 或 2 - erfc(-x)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erfc;
+/// assert!((erfc(10.0) / 2.088487583762545e-45 - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.abs() <= 0.5 {
+        return 1.0 - x * erf_small(x);
+    }
+    if x > 0.0 {
+        erfc_positive(x)
+    } else if x > -6.0 {
+        2.0 - erfc_positive(-x)
+    } else {
+        2.0
+    }
+}
+
+/// 计算缩放的互补误差函数 erfcx(x) = e^(x^2) erfc(x)
+///
+/// x >= 0.5 时直接使用 erfc(x) 与 `erfc` 相同的缩放逼近, 大 x 时 erfcx(x) ≈ 1 / (x √π),
+/// 不会下溢; x < -0.5 时使用 erfcx(x) = 2 e^(x^2) - erfcx(-x), 其中 e^(x^2) 精确计算,
+/// 直到 x ≈ -26.6 处 e^(x^2) 上溢之前均有限
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erfcx;
+/// assert!((erfcx(1.0e3) / 5.641893014533876e-4 - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn erfcx(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x >= 0.5 {
+        return erfc_scaled_positive(x);
+    }
+    if x >= -0.5 {
+        return (x * x).exp() * (1.0 - x * erf_small(x));
+    }
+    2.0 * exp_square(x, 1.0) - erfc_scaled_positive(-x)
+}
+
+/// 计算互补误差函数的对数 ln erfc(x)
+///
+/// x >= 0.5 时使用 ln erfc(x) = ln erfcx(x) - x^2, erfcx(x) 不会下溢, 因此在 erfc(x) 下溢为 0
+/// 的远尾 (如 x = 1e8) 处仍保持相对精度; |x| <= 0.5 时以 ln(1 + t) 计算 ln(1 - erf(x)),
+/// 避免 x 接近 0 时的精度损失; x < -0.5 时 erfc(x) ∈ (1.5, 2), 直接取对数
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::ln_erfc;
+/// assert!((ln_erfc(30.0) / -903.9741171106439 - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn ln_erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x >= 0.5 {
+        return erfc_scaled_positive(x).ln() - x * x;
+    }
+    if x >= -0.5 {
+        return (-x * erf_small(x)).ln_1p();
+    }
+    erfc(x).ln()
+}
+
+/// 标准正态分布分位数在中心区间上的 Acklam 有理逼近, 其中 t = p - 1/2, 要求 |t| <= 0.47575,
+/// 相对误差约为 1.15e-9
+fn normal_quantile_central(t: f64) -> f64 {
+    let r = t * t;
+    let num = eval_poly(
+        r,
+        &[
+            -39.69683028665376,
+            220.9460984245205,
+            -275.9285104469687,
+            138.357751867269,
+            -30.66479806614716,
+            2.506628277459239,
+        ],
+    );
+    let den = eval_poly(
+        r,
+        &[
+            -54.47609879822406,
+            161.5858368580409,
+            -155.6989798598866,
+            66.80131188771972,
+            -13.28068155288572,
+            1.0,
+        ],
+    );
+    t * num / den
+}
+
+/// 标准正态分布分位数在下尾 p < 0.02425 上的 Acklam 有理逼近, 以 √(-2 ln p) 为变量,
+/// 参数直接取 ln p, 因此 p 接近下溢时仍然适用
+fn normal_quantile_tail(ln_p: f64) -> f64 {
+    let q = (-2.0 * ln_p).sqrt();
+    let num = eval_poly(
+        q,
+        &[
+            -0.007784894002430293,
+            -0.3223964580411365,
+            -2.400758277161838,
+            -2.549732539343734,
+            4.374664141464968,
+            2.938163982698783,
+        ],
+    );
+    let den = eval_poly(
+        q,
+        &[
+            0.007784695709041462,
+            0.3224671290700398,
+            2.445134137142996,
+            3.754408661907416,
+            1.0,
+        ],
+    );
+    num / den
+}
+
+/// 对 erf(x) = y 的近似解 x 作一步 Halley 迭代, 其中 err = erf(x) - y
+///
+/// 由 erf''(x) / erf'(x) = -2x, Halley 迭代为 x - u / (1 + x u), 其中 u = f / f',
+/// 即带二阶修正的 Newton 步, 对 erfc 同样成立
+fn erf_halley_step(x: f64, err: f64) -> f64 {
+    let u = err / (FRAC_2_SQRT_PI * exp_square(x, -1.0));
+    if !u.is_finite() {
+        // q 为次正规数时导数下溢, 保留初始近似
+        return x;
+    }
+    x - u / (1.0 + x * u)
+}
+
+/// 计算误差函数的反函数 erf^(-1)(y), 即求解 erf(x) = y
+///
+/// 利用 erf(x / √2) = 2Φ(x) - 1 将问题转化为正态分布分位数, 在中心区间与尾部分别使用
+/// Acklam 有理逼近, 再作一步 Halley 迭代; |y| > 0.9515 时改为求解 erfc(|x|) = 1 - |y|.
+/// 当 |y| > 1 时返回 NaN, y = ±1 时返回 ±∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erf_inv;
+/// assert!((erf_inv(0.5) - 0.4769362762044699).abs() < 1.0e-16);
+/// ```
+pub fn erf_inv(y: f64) -> f64 {
+    if y.is_nan() || y.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if y.abs() == 1.0 {
+        return f64::INFINITY.copysign(y);
+    }
+    if y.abs() > 0.9515 {
+        return erfc_inv(1.0 - y.abs()).copysign(y);
+    }
+    let x = normal_quantile_central(0.5 * y) * FRAC_1_SQRT_2;
+    if x == 0.0 {
+        return x;
+    }
+    erf_halley_step(x, erf(x) - y)
+}
+
+/// 计算互补误差函数的反函数 erfc^(-1)(q), 即求解 erfc(x) = q
+///
+/// 算法同 [erf_inv], 迭代时直接使用 `erfc`, 因此 q 很小 (直到 1e-300 附近) 时仍保持相对精度;
+/// q >= 0.5 时转化为 erf^(-1)(1 - q), q > 1 时利用 erfc^(-1)(q) = -erfc^(-1)(2 - q).
+/// 当 q 不在 [0, 2] 内时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erfc_inv;
+/// assert!((erfc_inv(1.0e-300) - 26.209469960516124).abs() < 1.0e-13);
+/// ```
+pub fn erfc_inv(q: f64) -> f64 {
+    if q.is_nan() || !(0.0..=2.0).contains(&q) {
+        return f64::NAN;
+    }
+    if q > 1.0 {
+        return -erfc_inv(2.0 - q);
+    }
+    if q == 0.0 {
+        return f64::INFINITY;
+    }
+    if q >= 0.5 {
+        // 此时 1 - q 没有舍入误差
+        return erf_inv(1.0 - q);
+    }
+    let x = if q < 0.0485 {
+        -normal_quantile_tail(q.ln() - LN_2) * FRAC_1_SQRT_2
+    } else {
+        normal_quantile_central(0.5 * (1.0 - q)) * FRAC_1_SQRT_2
+    };
+    // erfc'(x) = -erf'(x)
+    erf_halley_step(x, q - erfc(x))
+}
+
+/// 在 |x| <= 0.5 上以 D(x) / x = P(x^2) 的多项式逼近计算 D(x) / x
+fn dawson_small(x: f64) -> f64 {
+    eval_poly(
+        x * x,
+        &[
+            -6.944810957012878e-07,
+            7.383487309727073e-06,
+            -6.313347207175447e-05,
+            0.00047359813122810265,
+            -0.0030784028217140775,
+            0.01693121691407088,
+            -0.07619047618981782,
+            0.26666666666665384,
+            -0.6666666666666665,
+            1.0,
+        ],
+    )
+}
+
+/// 在 0.5 <= x < 6 上计算 D(x), 分为三段, 每段以 x 与区间中点之差的多项式逼近
+fn dawson_mid(x: f64) -> f64 {
+    if x < 1.5 {
+        eval_poly(
+            x - 1.0,
+            &[
+                -4.2430784876041853e-07,
+                3.48565664900575e-07,
+                4.1634592616328585e-06,
+                -7.2150051316851536e-06,
+                -2.840378589484425e-05,
+                8.60832535689178e-05,
+                0.00012700489720943796,
+                -0.0007295771791846203,
+                -9.59639019780175e-05,
+                0.0044734253696673025,
+                -0.003945623042378139,
+                -0.01842150365768052,
+                0.03617680730048836,
+                0.0375092073222326,
+                -0.16412803287248978,
+                0.051600410906024755,
+                0.358719671275179,
+                -0.46192049308723154,
+                -0.07615901382553684,
+                0.5380795069127684,
+            ],
+        )
+    } else if x < 3.0 {
+        eval_poly(
+            x - 2.25,
+            &[
+                -1.2914231377923813e-09,
+                -7.296962989185709e-10,
+                1.9872687150607072e-08,
+                -2.630063350632261e-08,
+                -1.4499767988161806e-07,
+                5.624004872763972e-07,
+                4.3773542148596345e-08,
+                -4.869051897182651e-06,
+                1.0603152741853852e-05,
+                1.265641620875387e-05,
+                -0.00010269836912873345,
+                0.00014880587323649842,
+                0.00028137686913404524,
+                -0.0014515304860283672,
+                0.0018590592651330407,
+                0.002349003866376581,
+                -0.012721495761263848,
+                0.020401851928827326,
+                -0.007739679556008331,
+                -0.03359035082099251,
+                0.09105764845924853,
+                -0.1544941828018212,
+                0.25655426284484917,
+            ],
+        )
+    } else {
+        eval_poly(
+            x - 4.5,
+            &[
+                4.605327260792046e-15,
+                -1.5369651867449985e-14,
+                -5.791722980866575e-14,
+                3.767613698422559e-13,
+                -5.651232705555313e-13,
+                -1.313165867104744e-12,
+                1.0477213835448141e-11,
+                -3.509750163339256e-11,
+                5.721447122836715e-11,
+                8.004620782653073e-11,
+                -8.818951999984442e-10,
+                3.2816638471194192e-09,
+                -7.704509644099706e-09,
+                1.0064842510958694e-08,
+                8.633507305238512e-09,
+                -1.0427747438680808e-07,
+                4.1745102333146604e-07,
+                -1.3050009070110944e-06,
+                3.7852477244939766e-06,
+                -1.1161111502135264e-05,
+                3.508401114051799e-05,
+                -0.00011881415971925872,
+                0.0004294116852798737,
+                -0.001635317184476532,
+                0.006500103959586714,
+                -0.026797492041424828,
+                0.11408861022682498,
+            ],
+        )
+    }
+}
+
+/// 在 x >= 6 上以 2x D(x) = P(1 / x^2) 的多项式逼近计算 D(x),
+/// P 在 0 处的展开即为渐近级数 1 + 1 / (2x^2) + 3 / (4x^4) + ...
+fn dawson_tail(x: f64) -> f64 {
+    let u = 1.0 / (x * x);
+    eval_poly(
+        u,
+        &[
+            1550044451770.0867,
+            -242552226456.46173,
+            17824399949.699196,
+            -766918291.465284,
+            22917406.55028037,
+            -376355.1170755052,
+            14148.50461461515,
+            993.8951892260219,
+            162.85065461171462,
+            29.529229876811343,
+            6.562506167295126,
+            1.8749999887290936,
+            0.7500000000107312,
+            0.499999999999996,
+            1.0,
+        ],
+    ) * (0.5 / x)
+}
+
+/// 计算 Dawson 函数 D(x) = e^(-x^2) ∫_0^x e^(t^2) dt
+///
+/// D(x) 是奇函数, 在 x ≈ 0.924 处取得最大值, 大 x 时 D(x) ≈ 1 / (2x).
+/// 按 |x| 分段使用多项式逼近: 小参数时逼近 D(x) / x, 大参数时逼近 2x D(x) 关于 1 / x^2 的函数,
+/// 因此对所有实数 x 都保持相对精度
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::dawson;
+/// assert!((dawson(1.0) - 0.5380795069127684).abs() < 1.0e-16);
+/// ```
+pub fn dawson(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let ax = x.abs();
+    if ax <= 0.5 {
+        return x * dawson_small(x);
+    }
+    let value = if ax < 6.0 {
+        dawson_mid(ax)
+    } else {
+        dawson_tail(ax)
+    };
+    value.copysign(x)
+}
+
+/// 计算缩放的虚误差函数 e^(-x^2) erfi(x) = 2 / √π D(x), 其中 D 为 Dawson 函数
+///
+/// 该值对所有实数 x 有界, 大 |x| 时约为 1 / (x √π), 不会发生上溢
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erfi_scaled;
+/// assert!((erfi_scaled(10.0) - 0.0567053942328876).abs() < 1.0e-16);
+/// ```
+pub fn erfi_scaled(x: f64) -> f64 {
+    FRAC_2_SQRT_PI * dawson(x)
+}
+
+/// 计算虚误差函数 erfi(x) = -i erf(ix) = 2 / √π ∫_0^x e^(t^2) dt
+///
+/// 使用 erfi(x) = 2 / √π D(x) e^(x^2), 其中 e^(x^2) 拆为两个由 `exp_square` 精确计算的
+/// e^(x^2 / 2) 因子分别相乘, 因此结果直到 |x| ≈ 26.7 处真正上溢之前均有限, 而不会因 e^(x^2)
+/// 先行上溢而提前变为无穷
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::erfi;
+/// assert!((erfi(-1.0) + 1.6504257587975428).abs() < 1.0e-15);
+/// ```
+pub fn erfi(x: f64) -> f64 {
+    if x.is_infinite() {
+        return x;
+    }
+    let half = exp_square(x, 0.5);
+    erfi_scaled(x) * half * half
+}
+
+/// 计算 Goodwin–Staton 积分 G(x) = ∫_0^∞ e^(-t^2) / (t + x) dt, 要求 x > 0
+///
+/// 使用 G(x) = √π D(x) - e^(-x^2) Ei(x^2)/2, 其中 D 为 Dawson 函数, e^(-x^2) Ei(x^2) 由 `expint_ei_scaled` 计算;
+/// 两项对所有 x 有界, 相减至多损失一位. x 接近 0 时 G(x) ≈ -ln x - γ/2, 大 x 时 G(x) ≈ √π/(2x).
+/// G(0) = +∞, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::erf::goodwin_staton;
+/// assert!((goodwin_staton(1.0) - 0.6051336525033446).abs() < 1.0e-15);
+/// ```
+pub fn goodwin_staton(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x < GOODWIN_STATON_SMALL {
+        return SQRT_PI.mul_add(x, -x.ln() - 0.5 * EULER);
+    }
+    SQRT_PI * dawson(x) - 0.5 * expint_ei_scaled(x * x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_erf() {
+        let tol = 1.0e-16;
+        assert!(approx_eq(erf(0.1), 0.1124629160182849, tol));
+        assert!(approx_eq(erf(0.5), 0.5204998778130465, tol));
+        assert!(approx_eq(erf(-2.0), -0.9953222650189527, tol));
+        assert!(approx_eq(erf(3.5), 0.9999992569016276, tol));
+        assert!(approx_eq(erf(5.5), 0.9999999999999927, tol));
+        assert!(approx_eq(erf(1.0e-20) / 1.1283791670955125e-20, 1.0, tol));
+        assert_eq!(erf(0.0), 0.0);
+        assert_eq!(erf(6.0), 1.0);
+        assert_eq!(erf(f64::NEG_INFINITY), -1.0);
+        assert!(erf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfc() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erfc(0.3), 0.6713732405408726, tol));
+        assert!(approx_eq(erfc(-0.2), 1.2227025892104784, tol));
+        assert!(approx_eq(erfc(-1.5), 1.9661051464753108, tol));
+        assert!(approx_eq(erfc(2.0) / 0.004677734981047266, 1.0, tol));
+        assert!(approx_eq(erfc(5.0) / 1.537459794428035e-12, 1.0, tol));
+        assert!(approx_eq(erfc(8.0) / 1.1224297172982926e-29, 1.0, tol));
+        assert!(approx_eq(erfc(20.0) / 5.395865611607901e-176, 1.0, tol));
+        assert!(approx_eq(erfc(26.5) / 2.2109076642637343e-307, 1.0, tol));
+        assert_eq!(erfc(0.0), 1.0);
+        assert_eq!(erfc(-6.0), 2.0);
+        assert_eq!(erfc(27.3), 0.0);
+        assert_eq!(erfc(f64::INFINITY), 0.0);
+        assert!(erfc(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfcx() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erfcx(0.3), 0.7345993345676551, tol));
+        assert!(approx_eq(erfcx(-0.3), 1.4537492328427655, tol));
+        assert!(approx_eq(erfcx(2.0), 0.25539567631050575, tol));
+        assert!(approx_eq(erfcx(-3.0) / 16205.988853999586, 1.0, tol));
+        assert!(approx_eq(erfcx(10.0), 0.05614099274382259, tol));
+        assert!(approx_eq(erfcx(50.0), 0.011281536265323773, tol));
+        assert!(approx_eq(erfcx(-26.0) / 7.657724931490568e293, 1.0, tol));
+        assert_eq!(erfcx(0.0), 1.0);
+        assert_eq!(erfcx(f64::INFINITY), 0.0);
+        assert_eq!(erfcx(-27.0), f64::INFINITY);
+        assert!(erfcx(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ln_erfc() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            ln_erfc(1.0e-10) / -1.1283791671591746e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(ln_erfc(0.3), -0.3984300514400853, tol));
+        assert!(approx_eq(ln_erfc(-0.3), 0.28414589714140825, tol));
+        assert!(approx_eq(ln_erfc(-3.0), 0.6931361352504468, tol));
+        assert!(approx_eq(ln_erfc(1.0), -1.8496055099332482, tol));
+        assert!(approx_eq(ln_erfc(5.0) / -27.200889545537436, 1.0, tol));
+        assert!(approx_eq(ln_erfc(1000.0) / -1000007.4801207219, 1.0, tol));
+        assert!(approx_eq(ln_erfc(1.0e8) / -1.0000000000000018e16, 1.0, tol));
+        assert_eq!(ln_erfc(0.0), 0.0);
+        assert_eq!(ln_erfc(f64::INFINITY), f64::NEG_INFINITY);
+        assert_eq!(ln_erfc(f64::NEG_INFINITY), std::f64::consts::LN_2);
+        assert!(ln_erfc(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erf_inv() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erf_inv(0.1), 0.08885599049425769, tol));
+        assert!(approx_eq(erf_inv(-0.7), -0.7328690779592167, tol));
+        assert!(approx_eq(erf_inv(0.99), 1.8213863677184494, tol));
+        assert!(approx_eq(
+            erf_inv(0.9999999999990905),
+            5.05125408524939,
+            tol
+        ));
+        assert!(approx_eq(
+            erf_inv(1.0e-300) / 8.86226925452758e-301,
+            1.0,
+            tol
+        ));
+        assert_eq!(erf_inv(0.0), 0.0);
+        assert_eq!(erf_inv(1.0), f64::INFINITY);
+        assert_eq!(erf_inv(-1.0), f64::NEG_INFINITY);
+        assert!(erf_inv(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_erfc_inv() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erfc_inv(0.3), 0.7328690779592169, tol));
+        assert!(approx_eq(erfc_inv(1.5), -0.4769362762044699, tol));
+        assert!(approx_eq(erfc_inv(1.0e-10) / 4.572824967389486, 1.0, tol));
+        assert!(approx_eq(erfc_inv(1.0e-100) / 15.065574702592645, 1.0, tol));
+        assert!(approx_eq(erfc_inv(1.0e-300) / 26.209469960516124, 1.0, tol));
+        assert!(erfc_inv(5.0e-324).is_finite());
+        assert_eq!(erfc_inv(1.0), 0.0);
+        assert_eq!(erfc_inv(0.0), f64::INFINITY);
+        assert_eq!(erfc_inv(2.0), f64::NEG_INFINITY);
+        assert!(erfc_inv(-0.5).is_nan());
+    }
+
+    #[test]
+    fn test_goodwin_staton() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            goodwin_staton(1.0e-10) / 22.737243097666937,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(goodwin_staton(0.1), 2.1650436618898743, tol));
+        assert!(approx_eq(goodwin_staton(0.6103), 0.8492689204932815, tol));
+        assert!(approx_eq(goodwin_staton(1.0), 0.6051336525033446, tol));
+        assert!(approx_eq(goodwin_staton(2.0), 0.35433592884953063, tol));
+        assert!(approx_eq(goodwin_staton(10.0), 0.08402159370660217, tol));
+        assert!(approx_eq(
+            goodwin_staton(1000.0) / 0.0008857273680668844,
+            1.0,
+            tol
+        ));
+        assert_eq!(goodwin_staton(0.0), f64::INFINITY);
+        assert_eq!(goodwin_staton(f64::INFINITY), 0.0);
+        assert!(goodwin_staton(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_dawson() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(dawson(0.2), 0.19475103336802807, tol));
+        assert!(approx_eq(dawson(1.0), 0.5380795069127684, tol));
+        assert!(approx_eq(dawson(-0.924), -0.5410442141998663, tol));
+        assert!(approx_eq(dawson(2.0), 0.30134038892379195, tol));
+        assert!(approx_eq(dawson(5.0), 0.10213407442427684, tol));
+        assert!(approx_eq(dawson(10.0), 0.05025384718759853, tol));
+        assert!(approx_eq(dawson(1.0e10) / 5.0e-11, 1.0, tol));
+        assert_eq!(dawson(0.0), 0.0);
+        assert_eq!(dawson(f64::INFINITY), 0.0);
+        assert!(dawson(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erfi(1.0e-20) / 1.1283791670955125e-20, 1.0, tol));
+        assert!(approx_eq(erfi(0.3), 0.3489493387589362, tol));
+        assert!(approx_eq(erfi(-1.0), -1.6504257587975428, tol));
+        assert!(approx_eq(erfi(2.5) / 130.39575501324694, 1.0, tol));
+        assert!(approx_eq(erfi(10.0) / 1.5243074227086696e42, 1.0, tol));
+        assert!(approx_eq(erfi(26.7) / 8.499867261268985e307, 1.0, tol));
+        assert_eq!(erfi(0.0), 0.0);
+        assert_eq!(erfi(26.8), f64::INFINITY);
+        assert_eq!(erfi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(erfi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfi_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(erfi_scaled(0.3), 0.31891568277156584, tol));
+        assert!(approx_eq(erfi_scaled(-1.0), -0.6071577058413937, tol));
+        assert!(approx_eq(erfi_scaled(2.5), 0.2517230246118576, tol));
+        assert!(approx_eq(erfi_scaled(26.8), 0.021066536009945774, tol));
+        assert_eq!(erfi_scaled(f64::INFINITY), 0.0);
+        assert!(erfi_scaled(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,774 @@
+use crate::gamma::EULER;
+use crate::harmonic::harmonic;
+use crate::incomplete_gamma::upper_continued_fraction;
+use std::f64::consts::LN_2;
+
+/// Ei(x) 正零点 x_0 的高位部分
+const EI_ROOT_HI: f64 = 0.3725074107813666;
+
+/// Ei(x) 正零点 x_0 的低位部分, x_0 = EI_ROOT_HI + EI_ROOT_LO
+const EI_ROOT_LO: f64 = 1.3140183414386028e-17;
+
+/// li(x) 的零点 μ = e^(x_0) (Ramanujan–Soldner 常数) 的高位部分
+const LI_ROOT_HI: f64 = 1.451369234883381;
+
+/// li(x) 的零点 μ 的低位部分, μ = LI_ROOT_HI + LI_ROOT_LO
+const LI_ROOT_LO: f64 = -4.82713642696033e-17;
+
+/// li(2), 即偏移对数积分 Li(x) = li(x) - li(2) 的偏移量
+const LI_2: f64 = 1.045163780117493;
+
+/// |ln(x/2)| 不超过此值时 Li(x) 使用以 x = 2 为中心的级数, 项按 |ln(x/2)|/ln 2 衰减
+const LI_OFFSET_SERIES_RADIUS: f64 = 0.25;
+
+/// Chi(x) 正零点 x_c 的高位部分
+const CHI_ROOT_HI: f64 = 0.5238225713898644;
+
+/// Chi(x) 正零点 x_c 的低位部分, x_c = CHI_ROOT_HI + CHI_ROOT_LO
+const CHI_ROOT_LO: f64 = -2.6671382575502846e-17;
+
+/// x 超过此值时 Ei(x) 使用渐近展开, 最优截断处的相对误差约为 e^(-x)
+const EI_ASYMPTOTIC: f64 = 40.0;
+
+/// x 不超过此值时 E_n(x) 使用幂级数, 否则使用连分式
+const EN_SERIES_MAX: f64 = 1.0;
+
+/// n 不小于此值时幂级数需要约 n 项, 而连分式的收敛速度随 x + n 增大, 总是使用连分式
+const EN_SERIES_ORDER_MAX: u32 = 64;
+
+/// 0 < x <= 1 时的幂级数 E_n(x) = (-x)^(n-1)/(n-1)! (ψ(n) - ln x) - Σ_(k>=0, k≠n-1) (-x)^k / ((k - n + 1) k!),
+/// 其中 ψ(n) = H_(n-1) - γ. n = 1 时即 E_1(x) = -γ - ln x - Σ_(k>=1) (-x)^k / (k k!)
+fn en_series(n: u32, x: f64) -> f64 {
+    let m = n as f64 - 1.0;
+    let mut sum = 0.0;
+    let mut power = 1.0;
+    let mut k = 0.0;
+    loop {
+        let delta = if k == m {
+            power * (harmonic(n as u64 - 1) - EULER - x.ln())
+        } else {
+            -power / (k - m)
+        };
+        sum += delta;
+        if k > m && delta.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        k += 1.0;
+        power *= -x / k;
+    }
+}
+
+/// 0 < x <= 40 时以零点 x_0 为基准的幂级数: 由 Ei(x_0) = 0 得
+/// Ei(x) = ln(x/x_0) + Σ_(k>=1) (x^k - x_0^k) / (k k!), 所有项都与 x - x_0 成正比, 零点附近保持相对精度.
+/// e_k = (x^k - x_0^k)/k! 按 e_k = (x e_(k-1) + δ x_0^(k-1)/(k-1)!)/k 递推, δ = x - x_0 由调用者精确给出
+fn ei_series(x: f64, delta: f64) -> f64 {
+    let log = if delta.abs() < 0.5 * EI_ROOT_HI {
+        (delta / EI_ROOT_HI).ln_1p()
+    } else {
+        x.ln() - EI_ROOT_HI.ln()
+    };
+    let mut sum = 0.0;
+    let mut e = 0.0;
+    let mut u = 1.0;
+    let mut k = 1.0;
+    loop {
+        e = x.mul_add(e, delta * u) / k;
+        u *= EI_ROOT_HI / k;
+        let term = e / k;
+        sum += term;
+        if k > x && term.abs() <= 0.5 * f64::EPSILON * (log + sum).abs() {
+            return log + sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// x > 40 时的渐近展开 e^(-x) Ei(x) ~ 1/x Σ_(k>=0) k!/x^k, 在最小项处截断
+fn ei_asymptotic_scaled(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    loop {
+        let next = term * k / x;
+        if next >= term || next <= 0.5 * f64::EPSILON * sum {
+            break;
+        }
+        term = next;
+        sum += term;
+        k += 1.0;
+    }
+    sum / x
+}
+
+/// 计算指数积分 Ei(x) = -P.V. ∫_(-x)^∞ e^(-t)/t dt = P.V. ∫_(-∞)^x e^t/t dt
+///
+/// x < 0 时 Ei(x) = -E_1(-x).
+/// 0 < x <= 40 时使用以正零点 x_0 ≈ 0.3725 为基准的幂级数, 零点附近保持相对精度; x > 40 时使用渐近展开,
+/// 并分两次乘以 e^(x/2), 使结果直到 x ≈ 716 处真正上溢之前均有限.
+/// Ei(0) = -∞, Ei(+∞) = +∞, Ei(-∞) = 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_ei;
+/// assert!((expint_ei(1.0) - 1.8951178163559368).abs() < 1.0e-15);
+/// assert!((expint_ei(-1.0) + 0.21938393439552029).abs() < 1.0e-15);
+/// ```
+pub fn expint_ei(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+    if x < 0.0 {
+        return -expint_e1(-x);
+    }
+    if x <= EI_ASYMPTOTIC {
+        ei_series(x, (x - EI_ROOT_HI) - EI_ROOT_LO)
+    } else {
+        let half = (0.5 * x).exp();
+        half * (half * ei_asymptotic_scaled(x))
+    }
+}
+
+/// 计算缩放的指数积分 e^(-x) Ei(x)
+///
+/// x < 0 时等于 -e^(-x) E_1(-x), x > 40 时直接使用渐近展开, 大 |x| 时约为 1/x, 不会发生上溢或下溢.
+/// x = 0 时返回 -∞, x = ±∞ 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_ei_scaled;
+/// assert!((expint_ei_scaled(1000.0) - 0.0010010020060241206).abs() < 1.0e-18);
+/// ```
+pub fn expint_ei_scaled(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if x < 0.0 {
+        return -expint_e1_scaled(-x);
+    }
+    if x <= EI_ASYMPTOTIC {
+        ei_series(x, (x - EI_ROOT_HI) - EI_ROOT_LO) * (-x).exp()
+    } else {
+        ei_asymptotic_scaled(x)
+    }
+}
+
+/// 计算指数积分 E_1(x) = ∫_x^∞ e^(-t)/t dt = Γ(0, x), 要求 x >= 0
+///
+/// 即 `expint_en(1, x)`. E_1(0) = +∞, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_e1;
+/// assert!((expint_e1(1.0) - 0.21938393439552029).abs() < 1.0e-15);
+/// ```
+pub fn expint_e1(x: f64) -> f64 {
+    expint_en(1, x)
+}
+
+/// 计算广义指数积分 E_n(x) = ∫_1^∞ e^(-xt)/t^n dt = x^(n-1) Γ(1 - n, x), 要求 x >= 0
+///
+/// E_0(x) = e^(-x)/x. x <= 1 且 n < 64 时使用幂级数, 否则使用 Γ(1 - n, x) 的连分式
+/// E_n(x) = e^(-x) / (x + n - 1·n/(x + n + 2 - 2(n + 1)/(x + n + 4 - ...))).
+/// 各阶之间满足递推关系 n E_(n+1)(x) = e^(-x) - x E_n(x).
+/// E_n(0) = 1/(n - 1) (n >= 2), E_0(0) = E_1(0) = +∞, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_en;
+/// // E_2(0) = 1
+/// assert_eq!(expint_en(2, 0.0), 1.0);
+/// assert!((expint_en(3, 2.0) - 0.03013337979781589).abs() < 1.0e-16);
+/// ```
+pub fn expint_en(n: u32, x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return if n <= 1 {
+            f64::INFINITY
+        } else {
+            1.0 / (n as f64 - 1.0)
+        };
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    if n == 0 {
+        return (-x).exp() / x;
+    }
+    if x <= EN_SERIES_MAX && n < EN_SERIES_ORDER_MAX {
+        en_series(n, x)
+    } else {
+        (-x).exp() * upper_continued_fraction(1.0 - n as f64, x)
+    }
+}
+
+/// 0 <= x <= 40 时的幂级数 Shi(x) = Σ_(k>=0) x^(2k+1) / ((2k + 1) (2k + 1)!), 各项均为正
+fn shi_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut power = x;
+    let mut sum = x;
+    let mut k = 1.0;
+    loop {
+        power *= x2 / ((2.0 * k) * (2.0 * k + 1.0));
+        let term = power / (2.0 * k + 1.0);
+        sum += term;
+        if term <= 0.5 * f64::EPSILON * sum {
+            return sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// 0 < x <= 40 时以零点 x_c 为基准的幂级数: 由 Chi(x_c) = 0 得
+/// Chi(x) = ln(x/x_c) + Σ_(k>=1) (x^(2k) - x_c^(2k)) / (2k (2k)!), 所有项都与 x - x_c 成正比, 零点附近保持相对精度.
+/// e_k = (x^(2k) - x_c^(2k))/(2k)! 按 e_k = (x^2 e_(k-1) + D x_c^(2k-2)/(2k-2)!)/((2k - 1) 2k) 递推, D = x^2 - x_c^2
+fn chi_series(x: f64) -> f64 {
+    let delta = (x - CHI_ROOT_HI) - CHI_ROOT_LO;
+    let log = if delta.abs() < 0.5 * CHI_ROOT_HI {
+        (delta / CHI_ROOT_HI).ln_1p()
+    } else {
+        x.ln() - CHI_ROOT_HI.ln()
+    };
+    let x2 = x * x;
+    let root2 = CHI_ROOT_HI * CHI_ROOT_HI;
+    let d = delta * (x + CHI_ROOT_HI);
+    let mut sum = 0.0;
+    let mut e = 0.0;
+    let mut u = 1.0;
+    let mut k = 1.0;
+    loop {
+        let q = (2.0 * k - 1.0) * (2.0 * k);
+        e = x2.mul_add(e, d * u) / q;
+        u *= root2 / q;
+        let term = e / (2.0 * k);
+        sum += term;
+        if k > x && term.abs() <= 0.5 * f64::EPSILON * (log + sum).abs() {
+            return log + sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// 计算双曲正弦积分 Shi(x) = ∫_0^x sinh t / t dt
+///
+/// |x| <= 40 时使用各项均为正的幂级数; 否则使用 Shi(x) = (Ei(x) + E_1(x))/2.
+/// Shi 为奇函数, 结果直到 |x| ≈ 717 处真正上溢之前均有限
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::shi;
+/// assert!((shi(1.0) - 1.0572508753757286).abs() < 1.0e-15);
+/// ```
+pub fn shi(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let a = x.abs();
+    let value = if a <= EI_ASYMPTOTIC {
+        shi_series(a)
+    } else if a == f64::INFINITY {
+        a
+    } else {
+        0.5 * (expint_ei(a) + expint_e1(a))
+    };
+    value.copysign(x)
+}
+
+/// 计算双曲余弦积分 Chi(x) = γ + ln x + ∫_0^x (cosh t - 1)/t dt, 要求 x >= 0
+///
+/// x <= 40 时使用以正零点 x_c ≈ 0.5238 为基准的幂级数, 零点附近保持相对精度; 否则使用 Chi(x) = (Ei(x) - E_1(x))/2.
+/// Chi(0) = -∞, Chi(+∞) = +∞, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::chi;
+/// assert!((chi(1.0) - 0.8378669409802082).abs() < 1.0e-15);
+/// ```
+pub fn chi(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x <= EI_ASYMPTOTIC {
+        chi_series(x)
+    } else if x == f64::INFINITY {
+        x
+    } else {
+        0.5 * (expint_ei(x) - expint_e1(x))
+    }
+}
+
+/// 计算缩放的双曲正弦积分 e^(-|x|) Shi(x)
+///
+/// |x| > 40 时使用 (e^(-x) Ei(x) + e^(-2x) e^x E_1(x))/2, 大 |x| 时约为 1/(2x), 不会发生上溢. Shi(±∞) 缩放后为 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::shi_scaled;
+/// assert!((shi_scaled(1000.0) - 0.0005005010030120603).abs() < 1.0e-18);
+/// ```
+pub fn shi_scaled(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let a = x.abs();
+    let value = if a <= EI_ASYMPTOTIC {
+        shi_series(a) * (-a).exp()
+    } else if a == f64::INFINITY {
+        0.0
+    } else {
+        0.5 * (-2.0 * a)
+            .exp()
+            .mul_add(expint_e1_scaled(a), expint_ei_scaled(a))
+    };
+    value.copysign(x)
+}
+
+/// 计算缩放的双曲余弦积分 e^(-x) Chi(x), 要求 x >= 0
+///
+/// x > 40 时使用 (e^(-x) Ei(x) - e^(-2x) e^x E_1(x))/2, 大 x 时约为 1/(2x), 不会发生上溢.
+/// x = 0 时返回 -∞, x = +∞ 时返回 0, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::chi_scaled;
+/// assert!((chi_scaled(1000.0) - 0.0005005010030120603).abs() < 1.0e-18);
+/// ```
+pub fn chi_scaled(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x <= EI_ASYMPTOTIC {
+        chi_series(x) * (-x).exp()
+    } else if x == f64::INFINITY {
+        0.0
+    } else {
+        0.5 * (-(-2.0 * x).exp()).mul_add(expint_e1_scaled(x), expint_ei_scaled(x))
+    }
+}
+
+/// 计算对数积分 li(x) = P.V. ∫_0^x dt/ln t = Ei(ln x), 要求 x >= 0
+///
+/// 奇点 x = 1 附近 ln x 的相对误差不超过 ε, 而 Ei(t) ≈ γ + ln|t| 对 t 的相对误差不敏感, 直接得到 li(x) ≈ γ + ln|ln x|.
+/// 零点 μ ≈ 1.4514 附近, t - x_0 = ln(x/μ) 由 x - μ 精确求出, 再传给 Ei 以零点为基准的幂级数, 保持相对精度.
+/// li(0) = 0, li(1) = -∞, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::li;
+/// assert!((li(2.0) - 1.045163780117493).abs() < 1.0e-15);
+/// assert!((li(10.0) - 6.165599504787298).abs() < 1.0e-14);
+/// ```
+pub fn li(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return 0.0;
+    }
+    if x == 1.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+    let t = x.ln();
+    if t > EI_ASYMPTOTIC {
+        // e^t = x, 不必再计算 e^t
+        return x * ei_asymptotic_scaled(t);
+    }
+    if t > 0.0 {
+        let delta = (((x - LI_ROOT_HI) - LI_ROOT_LO) / LI_ROOT_HI).ln_1p();
+        return ei_series(t, delta);
+    }
+    if t < -EN_SERIES_MAX {
+        return -x * expint_e1_scaled(-t);
+    }
+    expint_ei(t)
+}
+
+/// 计算偏移对数积分 Li(x) = ∫_2^x dt/ln t = li(x) - li(2), 要求 x >= 0
+///
+/// Li(x) 是素数计数函数 π(x) 的经典近似. 零点 x = 2 附近令 h = ln(x/2), a = ln 2, 使用
+/// Li(x) = 2 ∫_0^h e^u/(a + u) du = (2/a) Σ_(k>=0) c_k h^(k+1)/(k + 1), 其中 c_k = Σ_(j=0)^k (-1/a)^(k-j)/j!,
+/// 保持相对精度; 其余情形使用 li(x) - li(2). x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::li_offset;
+/// assert!((li_offset(1.0e6) / 78626.50399568207 - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn li_offset(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    let h = (0.5 * x).ln();
+    if h.abs() > LI_OFFSET_SERIES_RADIUS {
+        return li(x) - LI_2;
+    }
+    let mut sum = 0.0;
+    let mut c = 0.0;
+    let mut reciprocal_factorial = 1.0;
+    let mut power = h;
+    let mut k = 0.0;
+    loop {
+        c = reciprocal_factorial - c / LN_2;
+        let term = c * power / (k + 1.0);
+        sum += term;
+        if term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return 2.0 / LN_2 * sum;
+        }
+        k += 1.0;
+        reciprocal_factorial /= k;
+        power *= h;
+    }
+}
+
+/// 计算缩放的指数积分 e^x E_1(x), 要求 x >= 0
+///
+/// 即 `expint_en_scaled(1, x)`, 大 x 时约为 1/x, 不会发生下溢
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_e1_scaled;
+/// assert!((expint_e1_scaled(1000.0) - 0.0009990019940238808).abs() < 1.0e-18);
+/// ```
+pub fn expint_e1_scaled(x: f64) -> f64 {
+    expint_en_scaled(1, x)
+}
+
+/// 计算缩放的广义指数积分 e^x E_n(x), 要求 x >= 0
+///
+/// x > 1 或 n >= 64 时直接返回连分式的值, 不再乘以 e^(-x); 其余情形由 `expint_en` 的幂级数乘以 e^x 得到.
+/// 大 x 时约为 1/(x + n), 不会发生下溢. x = +∞ 时返回 0, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::expint::expint_en_scaled;
+/// assert!((expint_en_scaled(3, 800.0) - 0.0012453357921047398).abs() < 1.0e-18);
+/// ```
+pub fn expint_en_scaled(n: u32, x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return expint_en(n, 0.0);
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    if n == 0 {
+        return 1.0 / x;
+    }
+    if x <= EN_SERIES_MAX && n < EN_SERIES_ORDER_MAX {
+        en_series(n, x) * x.exp()
+    } else {
+        upper_continued_fraction(1.0 - n as f64, x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_expint_e1() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_e1(0.5), 0.5597735947761608, tol));
+        assert!(approx_eq(expint_e1(2.0), 0.04890051070806112, tol));
+        assert!(approx_eq(
+            expint_e1(10.0) / 4.156968929685325e-6,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(expint_e1(1.0e-10), 22.448635265138925, 1.0e-14));
+        assert!(approx_eq(
+            expint_e1(700.0) / 1.406518766234033e-307,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(expint_e1(0.0), f64::INFINITY);
+        assert_eq!(expint_e1(f64::INFINITY), 0.0);
+        assert!(expint_e1(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expint_en() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_en(0, 2.0), 0.06766764161830635, tol));
+        assert!(approx_eq(expint_en(2, 0.5), 0.326643862324553, tol));
+        assert!(approx_eq(expint_en(2, 1.0e-8), 0.9999998115653491, tol));
+        assert!(approx_eq(expint_en(3, 2.0), 0.03013337979781589, tol));
+        assert!(approx_eq(expint_en(5, 0.1), 0.21901595224028045, tol));
+        assert!(approx_eq(expint_en(10, 1.5), 0.020946140395652534, tol));
+        assert!(approx_eq(
+            expint_en(10, 20.0) / 6.944390555412313e-11,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(expint_en(100, 0.5), 0.006095474587318909, tol));
+        assert!(approx_eq(
+            expint_en(100, 50.0) / 1.2915478777847485e-24,
+            1.0,
+            tol
+        ));
+        // n E_(n+1)(x) = e^(-x) - x E_n(x)
+        let x = 0.7;
+        for n in 1..10 {
+            let lhs = n as f64 * expint_en(n + 1, x);
+            assert!(approx_eq(lhs, (-x).exp() - x * expint_en(n, x), tol));
+        }
+        assert_eq!(expint_en(1, 0.0), f64::INFINITY);
+        assert_eq!(expint_en(5, 0.0), 0.25);
+        assert!(expint_en(2, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_shi() {
+        let tol = 1.0e-15;
+        assert_eq!(shi(0.0), 0.0);
+        assert!(approx_eq(shi(1.0e-10) / 1.0e-10, 1.0, tol));
+        assert!(approx_eq(shi(0.5), 0.5069967498196672, tol));
+        assert!(approx_eq(shi(1.0), 1.0572508753757286, tol));
+        assert!(approx_eq(shi(-2.0), -2.5015674333549756, tol));
+        assert!(approx_eq(shi(10.0) / 1246.1144901994232, 1.0, tol));
+        assert!(approx_eq(shi(50.0) / 5.292818448565845e19, 1.0, tol));
+        assert!(approx_eq(shi(700.0) / 7.254893680262804e300, 1.0, 1.0e-14));
+        assert_eq!(shi(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(shi(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_chi() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(chi(1.0e-10), -22.448635265038924, 1.0e-14));
+        assert!(approx_eq(chi(0.5), -0.05277684495649362, tol));
+        assert!(approx_eq(chi(0.52) / -0.008344582694969477, 1.0, 1.0e-14));
+        assert!(approx_eq(chi(1.0), 0.8378669409802082, tol));
+        assert!(approx_eq(chi(2.0), 2.4526669226469147, tol));
+        assert!(approx_eq(chi(10.0) / 1246.1144860424545, 1.0, tol));
+        assert!(approx_eq(chi(100.0) / 1.35777637242694e41, 1.0, tol));
+        assert_eq!(chi(0.0), f64::NEG_INFINITY);
+        assert_eq!(chi(f64::INFINITY), f64::INFINITY);
+        assert!(chi(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_shi_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(shi_scaled(0.5), 0.3075090731402837, tol));
+        assert!(approx_eq(shi_scaled(-1.0), -0.3889408612112412, tol));
+        assert!(approx_eq(shi_scaled(10.0), 0.05657351033106844, tol));
+        assert!(approx_eq(shi_scaled(50.0), 0.010208522777971994, tol));
+        assert!(approx_eq(shi_scaled(700.0), 0.0007153090504675817, tol));
+        assert!(approx_eq(shi_scaled(1.0e5) / 5.00005000100003e-6, 1.0, tol));
+        assert_eq!(shi_scaled(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_chi_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(chi_scaled(0.5), -0.03201077458901344, tol));
+        assert!(approx_eq(chi_scaled(2.0), 0.3319323726614914, tol));
+        assert!(approx_eq(chi_scaled(10.0), 0.05657351014234234, tol));
+        assert!(approx_eq(chi_scaled(30.0), 0.017263560896180922, tol));
+        assert!(approx_eq(chi_scaled(100.0), 0.005051031263874179, tol));
+        assert_eq!(chi_scaled(0.0), f64::NEG_INFINITY);
+        assert!(chi_scaled(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_li() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(li(0.5), -0.37867104306108795, tol));
+        assert!(approx_eq(li(0.3), -0.15741490289468946, tol));
+        assert!(approx_eq(li(1.5), 0.12506498631529636, tol));
+        assert!(approx_eq(li(1.0000000001), -22.448635182248555, 1.0e-14));
+        assert!(approx_eq(li(0.9999999999), -22.448635182348557, 1.0e-14));
+        assert!(approx_eq(
+            li(LI_ROOT_HI) / 1.2958497702998693e-16,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(li(1.0e6) / 78627.54915946219, 1.0, tol));
+        assert!(approx_eq(li(1.0e20) / 2.2208196027836634e18, 1.0, tol));
+        assert!(approx_eq(li(1.0e300) / 1.4497500526693365e297, 1.0, tol));
+        assert!(approx_eq(li(1.0e-300) / -1.4455586289196509e-303, 1.0, tol));
+        assert_eq!(li(0.0), 0.0);
+        assert_eq!(li(1.0), f64::NEG_INFINITY);
+        assert_eq!(li(f64::INFINITY), f64::INFINITY);
+        assert!(li(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_li_offset() {
+        let tol = 1.0e-15;
+        assert_eq!(li_offset(2.0), 0.0);
+        assert!(approx_eq(
+            li_offset(2.000001) / 1.442694520748712e-6,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(li_offset(1.9), -0.1498371565880709, tol));
+        assert!(approx_eq(li_offset(3.0), 1.118424814549699, tol));
+        assert!(approx_eq(li_offset(100.0) / 29.08097780396214, 1.0, tol));
+        assert!(approx_eq(li_offset(1.0e10) / 455055613.5414593, 1.0, tol));
+        assert_eq!(li_offset(1.0), f64::NEG_INFINITY);
+        assert!(li_offset(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expint_e1_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_e1_scaled(0.5), 0.9229106324837305, tol));
+        assert!(approx_eq(expint_e1_scaled(5.0), 0.1704221762847322, tol));
+        assert!(approx_eq(
+            expint_e1_scaled(1.0e-10),
+            22.44863526738379,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            expint_e1_scaled(1000.0),
+            0.0009990019940238808,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_e1_scaled(1.0e10) / 9.999999999e-11,
+            1.0,
+            tol
+        ));
+        assert_eq!(expint_e1_scaled(0.0), f64::INFINITY);
+        assert_eq!(expint_e1_scaled(f64::INFINITY), 0.0);
+        assert!(expint_e1_scaled(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expint_en_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_en_scaled(0, 3.0), 1.0 / 3.0, tol));
+        assert!(approx_eq(expint_en_scaled(2, 0.3), 0.6332393184758244, tol));
+        assert!(approx_eq(
+            expint_en_scaled(5, 2.0),
+            0.15755241125881506,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_en_scaled(10, 100.0),
+            0.009098306861960373,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_en_scaled(100, 0.5),
+            0.010049738607124771,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_en_scaled(3, 800.0),
+            0.0012453357921047398,
+            tol
+        ));
+        assert_eq!(expint_en_scaled(3, 0.0), 0.5);
+        assert!(expint_en_scaled(2, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_expint_ei_scaled() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_ei_scaled(0.5), 0.2754982985512703, tol));
+        assert!(approx_eq(expint_ei_scaled(10.0), 0.11314702047341078, tol));
+        assert!(approx_eq(expint_ei_scaled(50.0), 0.020417045555943987, tol));
+        assert!(approx_eq(
+            expint_ei_scaled(800.0),
+            0.001251566420972141,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_ei_scaled(1.0e5) / 1.000010000200006e-5,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_ei_scaled(0.3725) / -1.989459089789628e-5,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(expint_ei_scaled(-0.5), -0.9229106324837305, tol));
+        assert!(approx_eq(expint_ei_scaled(-3.0), -0.2620837402553185, tol));
+        assert!(approx_eq(
+            expint_ei_scaled(-50.0),
+            -0.01961510993011487,
+            tol
+        ));
+        assert!(approx_eq(
+            expint_ei_scaled(-1.0e5) / -9.99990000199994e-6,
+            1.0,
+            tol
+        ));
+        assert_eq!(expint_ei_scaled(0.0), f64::NEG_INFINITY);
+        assert_eq!(expint_ei_scaled(f64::INFINITY), 0.0);
+        assert!(expint_ei_scaled(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expint_ei() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(expint_ei(1.0), 1.8951178163559368, tol));
+        assert!(approx_eq(expint_ei(0.5), 0.4542199048631736, tol));
+        assert!(approx_eq(expint_ei(2.0) / 4.95423435600189, 1.0, tol));
+        assert!(approx_eq(expint_ei(10.0) / 2492.2289762418777, 1.0, tol));
+        assert!(approx_eq(expint_ei(50.0) / 1.058563689713169e20, 1.0, tol));
+        assert!(approx_eq(
+            expint_ei(700.0) / 1.4509787360525608e301,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            expint_ei(0.3725) / -2.8874183188745963e-5,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(expint_ei(-0.5), -0.5597735947761608, tol));
+        assert!(approx_eq(expint_ei(-1.0), -0.21938393439552029, tol));
+        assert!(approx_eq(expint_ei(-3.0), -0.013048381094197037, tol));
+        assert!(approx_eq(
+            expint_ei(-10.0) / -4.156968929685325e-6,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(expint_ei(0.0), f64::NEG_INFINITY);
+        assert_eq!(expint_ei(f64::INFINITY), f64::INFINITY);
+        assert_eq!(expint_ei(-800.0), 0.0);
+        assert!(expint_ei(f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,1238 @@
+use crate::bernoulli::bernoulli;
+use crate::gamma::{gamma, ln_gamma, ln_gamma_diff, ln_gamma_diff_positive, polygamma};
+use crate::harmonic::{harmonic, harmonic_generalized};
+use std::f64::consts::{LN_2, PI};
+use std::sync::OnceLock;
+
+/// 阶乘表的长度, 170! 是可以用 f64 表示的最大阶乘
+const FACTORIAL_TABLE_SIZE: usize = 171;
+
+/// 对数阶乘表的长度
+const LN_FACTORIAL_TABLE_SIZE: usize = 2048;
+
+/// 0! 到 170! 的值
+static FACTORIALS: [f64; FACTORIAL_TABLE_SIZE] = [
+    1.0,
+    1.0,
+    2.0,
+    6.0,
+    24.0,
+    120.0,
+    720.0,
+    5040.0,
+    40320.0,
+    362880.0,
+    3628800.0,
+    39916800.0,
+    479001600.0,
+    6227020800.0,
+    87178291200.0,
+    1307674368000.0,
+    20922789888000.0,
+    355687428096000.0,
+    6402373705728000.0,
+    1.21645100408832e17,
+    2.43290200817664e18,
+    5.109094217170944e19,
+    1.1240007277776077e21,
+    2.585201673888498e22,
+    6.204484017332394e23,
+    1.5511210043330986e25,
+    4.0329146112660565e26,
+    1.0888869450418352e28,
+    3.0488834461171387e29,
+    8.841761993739702e30,
+    2.6525285981219107e32,
+    8.222838654177922e33,
+    2.631308369336935e35,
+    8.683317618811886e36,
+    2.9523279903960416e38,
+    1.0333147966386145e40,
+    3.7199332678990125e41,
+    1.3763753091226346e43,
+    5.230226174666011e44,
+    2.0397882081197444e46,
+    8.159152832478977e47,
+    3.345252661316381e49,
+    1.40500611775288e51,
+    6.041526306337383e52,
+    2.658271574788449e54,
+    1.1962222086548019e56,
+    5.502622159812089e57,
+    2.5862324151116818e59,
+    1.2413915592536073e61,
+    6.082818640342675e62,
+    3.0414093201713376e64,
+    1.5511187532873822e66,
+    8.065817517094388e67,
+    4.2748832840600255e69,
+    2.308436973392414e71,
+    1.2696403353658276e73,
+    7.109985878048635e74,
+    4.0526919504877214e76,
+    2.3505613312828785e78,
+    1.3868311854568984e80,
+    8.32098711274139e81,
+    5.075802138772248e83,
+    3.146997326038794e85,
+    1.98260831540444e87,
+    1.2688693218588417e89,
+    8.247650592082472e90,
+    5.443449390774431e92,
+    3.647111091818868e94,
+    2.4800355424368305e96,
+    1.711224524281413e98,
+    1.1978571669969892e100,
+    8.504785885678623e101,
+    6.1234458376886085e103,
+    4.4701154615126844e105,
+    3.307885441519386e107,
+    2.48091408113954e109,
+    1.8854947016660504e111,
+    1.4518309202828587e113,
+    1.1324281178206297e115,
+    8.946182130782976e116,
+    7.156945704626381e118,
+    5.797126020747368e120,
+    4.753643337012842e122,
+    3.945523969720659e124,
+    3.314240134565353e126,
+    2.81710411438055e128,
+    2.4227095383672734e130,
+    2.107757298379528e132,
+    1.8548264225739844e134,
+    1.650795516090846e136,
+    1.4857159644817615e138,
+    1.352001527678403e140,
+    1.2438414054641308e142,
+    1.1567725070816416e144,
+    1.087366156656743e146,
+    1.032997848823906e148,
+    9.916779348709496e149,
+    9.619275968248212e151,
+    9.426890448883248e153,
+    9.332621544394415e155,
+    9.332621544394415e157,
+    9.42594775983836e159,
+    9.614466715035127e161,
+    9.90290071648618e163,
+    1.0299016745145628e166,
+    1.081396758240291e168,
+    1.1462805637347084e170,
+    1.226520203196138e172,
+    1.324641819451829e174,
+    1.4438595832024937e176,
+    1.588245541522743e178,
+    1.7629525510902446e180,
+    1.974506857221074e182,
+    2.2311927486598138e184,
+    2.5435597334721877e186,
+    2.925093693493016e188,
+    3.393108684451898e190,
+    3.969937160808721e192,
+    4.684525849754291e194,
+    5.574585761207606e196,
+    6.689502913449127e198,
+    8.094298525273444e200,
+    9.875044200833601e202,
+    1.214630436702533e205,
+    1.506141741511141e207,
+    1.882677176888926e209,
+    2.372173242880047e211,
+    3.0126600184576594e213,
+    3.856204823625804e215,
+    4.974504222477287e217,
+    6.466855489220474e219,
+    8.47158069087882e221,
+    1.1182486511960043e224,
+    1.4872707060906857e226,
+    1.9929427461615188e228,
+    2.6904727073180504e230,
+    3.659042881952549e232,
+    5.012888748274992e234,
+    6.917786472619489e236,
+    9.615723196941089e238,
+    1.3462012475717526e241,
+    1.898143759076171e243,
+    2.695364137888163e245,
+    3.854370717180073e247,
+    5.5502938327393044e249,
+    8.047926057471992e251,
+    1.1749972043909107e254,
+    1.727245890454639e256,
+    2.5563239178728654e258,
+    3.80892263763057e260,
+    5.713383956445855e262,
+    8.62720977423324e264,
+    1.3113358856834524e267,
+    2.0063439050956823e269,
+    3.0897696138473508e271,
+    4.789142901463394e273,
+    7.471062926282894e275,
+    1.1729568794264145e278,
+    1.853271869493735e280,
+    2.9467022724950384e282,
+    4.7147236359920616e284,
+    7.590705053947219e286,
+    1.2296942187394494e289,
+    2.0044015765453026e291,
+    3.287218585534296e293,
+    5.423910666131589e295,
+    9.003691705778438e297,
+    1.503616514864999e300,
+    2.5260757449731984e302,
+    4.269068009004705e304,
+    7.257415615307999e306,
+];
+
+/// ln 0! 到 ln 2047!, 在第一次使用时生成
+fn ln_factorial_table() -> &'static [f64; LN_FACTORIAL_TABLE_SIZE] {
+    static TABLE: OnceLock<[f64; LN_FACTORIAL_TABLE_SIZE]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; LN_FACTORIAL_TABLE_SIZE];
+        for (n, value) in table.iter_mut().enumerate() {
+            *value = if n < FACTORIAL_TABLE_SIZE {
+                FACTORIALS[n].ln()
+            } else {
+                ln_gamma(n as f64 + 1.0)
+            };
+        }
+        table
+    })
+}
+
+/// 计算阶乘 n!, `n > 170` 时上溢为 `f64::INFINITY`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::factorial;
+/// assert_eq!(factorial(10), 3628800.0);
+/// assert_eq!(factorial(171), f64::INFINITY);
+/// ```
+pub fn factorial(n: u64) -> f64 {
+    if n < FACTORIAL_TABLE_SIZE as u64 {
+        FACTORIALS[n as usize]
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// 计算对数阶乘 ln n!, 小参数查表, 较大时使用 ln Γ(n + 1)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_factorial;
+/// assert!((ln_factorial(1000) - 5912.128178488163).abs() < 1.0e-10);
+/// ```
+pub fn ln_factorial(n: u64) -> f64 {
+    if n < LN_FACTORIAL_TABLE_SIZE as u64 {
+        ln_factorial_table()[n as usize]
+    } else {
+        ln_gamma(n as f64 + 1.0)
+    }
+}
+
+/// 双阶乘可以精确连乘的最大奇数, 29!! 仍小于 2^53
+const DOUBLE_FACTORIAL_EXACT_MAX: i64 = 29;
+
+/// 计算非负整数 n 的双阶乘
+fn double_factorial_nonnegative(n: u64) -> f64 {
+    if n <= DOUBLE_FACTORIAL_EXACT_MAX as u64 {
+        return (1..=n).rev().step_by(2).fold(1.0, |acc, k| acc * k as f64);
+    }
+    let k = n / 2;
+    if n.is_multiple_of(2) {
+        // (2k)!! = 2^k k!
+        if k >= FACTORIAL_TABLE_SIZE as u64 {
+            return f64::INFINITY;
+        }
+        factorial(k) * 2.0f64.powi(k as i32)
+    } else {
+        // (2k+1)!! = 2^(k+1) Γ(k + 3/2) / √π
+        if k > 150 {
+            return f64::INFINITY;
+        }
+        gamma(k as f64 + 1.5) / PI.sqrt() * 2.0f64.powi(k as i32 + 1)
+    }
+}
+
+/// 计算双阶乘 n!! = n (n - 2) (n - 4) ...
+///
+/// 对负奇数按 (-2k-1)!! = (-1)^k / (2k-1)!! 延拓, 负偶数处返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::double_factorial;
+/// assert_eq!(double_factorial(9), 945.0);
+/// assert_eq!(double_factorial(-5), 1.0 / 3.0);
+/// ```
+pub fn double_factorial(n: i64) -> f64 {
+    if n >= 0 {
+        return double_factorial_nonnegative(n as u64);
+    }
+    if n % 2 == 0 {
+        return f64::NAN;
+    }
+    // n = -2k-1
+    let k = (-(n + 1) / 2) as u64;
+    if k == 0 {
+        return 1.0;
+    }
+    let value = 1.0 / double_factorial_nonnegative(2 * k - 1);
+    if k.is_multiple_of(2) {
+        value
+    } else {
+        -value
+    }
+}
+
+/// 计算双阶乘的对数 ln |n!!|, 较大参数时不会上溢
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_double_factorial;
+/// assert!((ln_double_factorial(9) - 945.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_double_factorial(n: i64) -> f64 {
+    if n < 0 {
+        if n % 2 == 0 {
+            return f64::NAN;
+        }
+        // ln |(-2k-1)!!| = -ln (2k-1)!!
+        return if n == -1 {
+            0.0
+        } else {
+            -ln_double_factorial(-n - 2)
+        };
+    }
+    let value = double_factorial_nonnegative(n as u64);
+    if value.is_finite() {
+        return value.ln();
+    }
+    let k = (n / 2) as f64;
+    if n % 2 == 0 {
+        k.mul_add(LN_2, ln_factorial(n as u64 / 2))
+    } else {
+        (k + 1.0).mul_add(LN_2, ln_gamma(k + 1.5)) - 0.5 * PI.ln()
+    }
+}
+
+/// 用整数算术精确计算 C(n, k), 中间结果超出 u128 时返回 `None`
+fn binomial_exact(n: u64, k: u64) -> Option<u128> {
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    // 每一步的 result 都是二项式系数 C(n, i + 1), 因此除法总是整除
+    for i in 0..k {
+        result = result.checked_mul((n - i) as u128)? / (i + 1) as u128;
+    }
+    Some(result)
+}
+
+/// 计算二项式系数 C(n, k)
+///
+/// 结果可以用整数精确计算时返回其舍入到 f64 的值, 否则按 Π (n - k + i) / i 连乘,
+/// 超出 f64 范围时返回 `f64::INFINITY`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::binomial;
+/// assert_eq!(binomial(10, 3), 120.0);
+/// assert_eq!(binomial(3, 5), 0.0);
+/// ```
+pub fn binomial(n: u64, k: u64) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    if let Some(value) = binomial_exact(n, k) {
+        return value as f64;
+    }
+    // 取 k <= n - k 后每个因子都不小于 1, 部分积单调增加, 不会在中途上溢
+    let k = k.min(n - k);
+    let mut result = 1.0;
+    for i in 1..=k {
+        result *= (n - k + i) as f64 / i as f64;
+        if result.is_infinite() {
+            break;
+        }
+    }
+    result
+}
+
+/// 计算二项式系数的对数 ln C(n, k), `k > n` 时返回 `f64::NEG_INFINITY`
+///
+/// 大参数时使用 ln C(n, k) = [ln Γ(n + 1) - ln Γ(n - k + 1)] - ln k!, 其中较小的 k
+/// 取 min(k, n - k), 而 Gamma 函数的对数差由 `ln_gamma_diff` 计算, 不会因相消而损失精度
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_binomial;
+/// assert!((ln_binomial(10, 3) - 120.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_binomial(n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    let k = k.min(n - k);
+    if let Some(value) = binomial_exact(n, k) {
+        return (value as f64).ln();
+    }
+    let n = n as f64;
+    ln_gamma_diff(n + 1.0, n - k as f64 + 1.0) - ln_factorial(k)
+}
+
+/// 计算多项式系数的对数 ln[(k_1 + ... + k_m)! / (k_1! ... k_m!)]
+///
+/// 按 ln C(k_1 + ... + k_i, k_i) 逐项累加, 某个 k_i 远大于其余各项时也不会相消
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_multinomial;
+/// // 6! / (1! 2! 3!) = 60
+/// assert!((ln_multinomial(&[1, 2, 3]) - 60.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_multinomial(k: &[u64]) -> f64 {
+    let mut total = 0;
+    let mut result = 0.0;
+    for &ki in k {
+        total += ki;
+        result += ln_binomial(total, ki);
+    }
+    result
+}
+
+/// Stirling 数递推中的权: 第一类 c(m, j) = c(m - 1, j - 1) + (m - 1) c(m - 1, j),
+/// 第二类 S(m, j) = S(m - 1, j - 1) + j S(m - 1, j). 以 m = j + d 记, 权分别为 j + d - 1 与 j
+fn stirling_weight(first: bool, j: u64, d: u64) -> u64 {
+    if first {
+        j + d - 1
+    } else {
+        j
+    }
+}
+
+/// 在 0 <= j <= k, 0 <= d <= n - k 的带状区域内递推 T(j, d) = T(j - 1, d) + w T(j, d - 1),
+/// 只保存较短的一维. 各项均为正数, 且 T 关于 j 与 d 都单调不减, 终值 T(k, n - k) 就是最大项
+fn stirling_recurrence<T: Copy>(
+    first: bool,
+    n: u64,
+    k: u64,
+    zero: T,
+    one: T,
+    mut step: impl FnMut(T, u64, T) -> Option<T>,
+) -> Option<T> {
+    let d_max = n - k;
+    if k <= d_max {
+        // 按 d 逐行递推, 行内下标为 j; d = 0 时 T(j, 0) = 1
+        let mut row = vec![one; k as usize + 1];
+        for d in 1..=d_max {
+            row[0] = zero;
+            for j in 1..=k as usize {
+                row[j] = step(row[j - 1], stirling_weight(first, j as u64, d), row[j])?;
+            }
+        }
+        Some(row[k as usize])
+    } else {
+        // 按 j 逐列递推, 列内下标为 d; j = 0 时 T(0, d) 只在 d = 0 处为 1
+        let mut column = vec![zero; d_max as usize + 1];
+        column[0] = one;
+        for j in 1..=k {
+            for d in 1..=d_max as usize {
+                column[d] = step(
+                    column[d],
+                    stirling_weight(first, j, d as u64),
+                    column[d - 1],
+                )?;
+            }
+        }
+        Some(column[d_max as usize])
+    }
+}
+
+/// 用整数算术精确计算无符号的第一类 (`first` 为真) 或第二类 Stirling 数, 结果超出 u128 时返回 `None`.
+/// 中间结果都不超过终值, 因此只有终值超出 u128 时才会失败
+fn stirling_exact(first: bool, n: u64, k: u64) -> Option<u128> {
+    stirling_recurrence(first, n, k, 0u128, 1u128, |a, w, b| {
+        a.checked_add((w as u128).checked_mul(b)?)
+    })
+}
+
+/// 缩放表示中的指数均为此值的倍数, 缩放是精确的
+const STIRLING_RESCALE_EXPONENT: i32 = 512;
+
+/// 以 (m, e) 表示 m 2^e, 其中 e 为 `STIRLING_RESCALE_EXPONENT` 的倍数且 m <= 2^STIRLING_RESCALE_EXPONENT.
+/// 递推中同一行的各项可以相差远超 f64 范围的倍数, 而较小的项此后仍可能主导终值, 因此每一项各自带有指数
+type Scaled = (f64, i64);
+
+/// 以缩放表示计算 u a + v b, 其中 u, v >= 0. 指数较小的一项按指数差缩放, 相差很大时下溢为 0, 可以忽略
+fn scaled_linear((a, ea): Scaled, u: f64, (b, eb): Scaled, v: f64) -> Scaled {
+    let shift = |d: i64| 2.0f64.powi(d.max(-4 * STIRLING_RESCALE_EXPONENT as i64) as i32);
+    let (a, b) = (u * a, v * b);
+    let (value, exponent) = if ea == eb || b == 0.0 {
+        (a + b, ea)
+    } else if a == 0.0 {
+        (b, eb)
+    } else if ea > eb {
+        (b.mul_add(shift(eb - ea), a), ea)
+    } else {
+        (a.mul_add(shift(ea - eb), b), eb)
+    };
+    if value > 2.0f64.powi(STIRLING_RESCALE_EXPONENT) {
+        (
+            value * 2.0f64.powi(-STIRLING_RESCALE_EXPONENT),
+            exponent + STIRLING_RESCALE_EXPONENT as i64,
+        )
+    } else {
+        (value, exponent)
+    }
+}
+
+/// 以 f64 递推无符号的第一类或第二类 Stirling 数, 返回 (m, e), 其中结果为 m 2^e 且 m >= 1
+fn stirling_scaled(first: bool, n: u64, k: u64) -> Scaled {
+    stirling_recurrence(first, n, k, (0.0, 0), (1.0, 0), |a, w, b| {
+        Some(scaled_linear(a, 1.0, b, w as f64))
+    })
+    .unwrap_or((f64::NAN, 0))
+}
+
+/// k (n - k) 不超过此值时递推计算 Stirling 数, 更大的参数改用计算量与 n 无关的展开
+const STIRLING_RECURRENCE_MAX_WORK: u64 = 1 << 22;
+
+/// 递推代价过高而 n - k 不超过此值时使用二阶 Euler 数展开, 计算量为 O((n - k)^2);
+/// n - k 更大时鞍点展开的截断误差已低于机器精度
+const STIRLING_EULERIAN_MAX_GAP: u64 = 512;
+
+/// 第一类 Stirling 数在 k 不超过此值时由广义调和数计算, 此时鞍点展开的修正项收敛太慢
+const STIRLING1_HARMONIC_MAX_K: u64 = 16;
+
+/// 鞍点展开用到的最高阶导数
+const SADDLE_ORDER: usize = 8;
+
+/// ln(-ln(1 - t)/t) 的幂级数在 t <= STIRLING1_SERIES_MAX_T 时使用的项数
+const STIRLING1_SERIES_TERMS: usize = 256;
+
+/// 第一类 Stirling 数的鞍点在 k >= n - k 时不超过此值
+const STIRLING1_SERIES_MAX_T: f64 = 0.72;
+
+/// ln((e^t - 1)/t) 在 t 不超过此值时使用 Bernoulli 数的幂级数
+const STIRLING2_SERIES_MAX_T: f64 = 2.0;
+
+/// 0 <= i <= j <= 8 的第二类 Stirling 数 S(j, i), 用于把 (t d/dt)^j 展开为普通导数
+const STIRLING2_SMALL: [[f64; SADDLE_ORDER + 1]; SADDLE_ORDER + 1] = [
+    [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 3.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 7.0, 6.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 15.0, 25.0, 10.0, 1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 31.0, 90.0, 65.0, 15.0, 1.0, 0.0, 0.0],
+    [0.0, 1.0, 63.0, 301.0, 350.0, 140.0, 21.0, 1.0, 0.0],
+    [0.0, 1.0, 127.0, 966.0, 1701.0, 1050.0, 266.0, 28.0, 1.0],
+];
+
+/// k (n - k) 不超过 `STIRLING_RECURRENCE_MAX_WORK` 时递推, 返回 (m, e) 使结果为 m 2^e, 否则返回 `None`.
+/// 低于 2^53 时 f64 递推的每一步都是精确的; `exact` 为真且结果不超过 2^128 时再用整数算术精确计算
+fn stirling_small(first: bool, n: u64, k: u64, exact: bool) -> Option<(f64, i64)> {
+    if k.saturating_mul(n - k) > STIRLING_RECURRENCE_MAX_WORK {
+        return None;
+    }
+    let (mantissa, exponent) = stirling_scaled(first, n, k);
+    if exact && exponent == 0 && (2.0f64.powi(53)..=2.0f64.powi(128)).contains(&mantissa) {
+        if let Some(value) = stirling_exact(first, n, k) {
+            return Some((value as f64, 0));
+        }
+    }
+    Some((mantissa, exponent))
+}
+
+/// 二阶 Euler 数 <<m, j>> (0 <= j < m) 的递推 <<i, j>> = (j + 1) <<i - 1, j>> + (2i - 1 - j) <<i - 1, j - 1>>,
+/// 按 j 从大到小原地更新. `step(a, u, b, v)` 计算 u a + v b, 各项均为正数
+fn eulerian2_recurrence<T: Copy>(
+    m: u64,
+    zero: T,
+    one: T,
+    mut step: impl FnMut(T, u64, T, u64) -> Option<T>,
+) -> Option<Vec<T>> {
+    let mut row = vec![zero; m.max(1) as usize];
+    row[0] = one;
+    for i in 1..=m {
+        for j in (0..i as usize).rev() {
+            let left = if j > 0 { row[j - 1] } else { zero };
+            row[j] = step(row[j], j as u64 + 1, left, 2 * i - 1 - j as u64)?;
+        }
+    }
+    Some(row)
+}
+
+/// n - k = m 较小时 {n, n - m} = Σ_j <<m, j>> C(n + m - 1 - j, 2m), [n, n - m] = Σ_j <<m, j>> C(n + j, 2m),
+/// 统一写成 Σ_i e_i C(n + m - 1 - i, 2m), 第一类时 e_i = <<m, m - 1 - i>>. 用整数算术精确计算, 超出 u128 时返回 `None`
+fn stirling_eulerian_exact(first: bool, n: u64, m: u64) -> Option<u128> {
+    let row = eulerian2_recurrence(m, 0u128, 1u128, |a, u, b, v| {
+        (u as u128)
+            .checked_mul(a)?
+            .checked_add((v as u128).checked_mul(b)?)
+    })?;
+    let top = n + m - 1;
+    let len = row.len();
+    (0..len).try_fold(0u128, |sum, i| {
+        let e = if first { row[len - 1 - i] } else { row[i] };
+        sum.checked_add(e.checked_mul(binomial_exact(top - i as u64, 2 * m)?)?)
+    })
+}
+
+/// 以 f64 计算 `stirling_eulerian_exact` 中展开式的对数, 要求 k = n - m > m. 二项式系数以
+/// C(N - i, 2m) = C(N, 2m) Π_(l<i) (N - l - 2m)/(N - l) 相对于首项给出, 各项均为正数
+fn ln_stirling_eulerian(first: bool, n: u64, m: u64) -> f64 {
+    let row = eulerian2_recurrence(m, (0.0, 0), (1.0, 0), |a, u, b, v| {
+        Some(scaled_linear(a, u as f64, b, v as f64))
+    })
+    .unwrap_or_default();
+    let top = n + m - 1;
+    let len = row.len();
+    let mut weight = 1.0;
+    let mut sum = (0.0, 0);
+    for i in 0..len {
+        let e = if first { row[len - 1 - i] } else { row[i] };
+        sum = scaled_linear(sum, 1.0, e, weight);
+        let l = top - i as u64;
+        weight *= (l - 2 * m) as f64 / l as f64;
+    }
+    sum.0.ln() + (sum.1 as f64).mul_add(LN_2, ln_binomial(top, 2 * m))
+}
+
+/// 2 <= k <= `STIRLING1_HARMONIC_MAX_K` 时 c(n, k) = (n - 1)! e_(k-1)(1, 1/2, ..., 1/(n - 1)), 初等对称多项式
+/// 由 Newton 恒等式 r e_r = Σ_(i=1)^r (-1)^(i-1) e_(r-i) H_(n-1)^(i) 递推. 这里 n 很大, H_(n-1) 远大于
+/// 高阶的广义调和数, 主项 e_(r-1) H_(n-1) 占优, 不会相消
+fn ln_stirling1_harmonic(n: u64, k: u64) -> f64 {
+    let r = (k - 1) as usize;
+    let mut power_sums = [0.0; STIRLING1_HARMONIC_MAX_K as usize];
+    for (i, value) in power_sums.iter_mut().enumerate().take(r) {
+        *value = harmonic_generalized(n - 1, i as f64 + 1.0);
+    }
+    let mut e = [1.0; STIRLING1_HARMONIC_MAX_K as usize];
+    for j in 1..=r {
+        let mut sum = 0.0;
+        for i in 1..=j {
+            let term = e[j - i] * power_sums[i - 1];
+            sum += if i % 2 == 1 { term } else { -term };
+        }
+        e[j] = sum / j as f64;
+    }
+    ln_factorial(n - 1) + e[r].ln()
+}
+
+/// ln(-ln(1 - t)/t) = Σ_(i>=1) c_i t^i 的系数, 下标 0 处为 0. 由 f = -ln(1 - t)/t = Σ t^i/(i + 1) 与
+/// (ln f)' = f'/f 得 i c_i = i/(i + 1) - Σ_(j=1)^(i-1) j c_j/(i - j + 1), 各系数均为正数. 在第一次使用时生成
+fn stirling1_series() -> &'static [f64; STIRLING1_SERIES_TERMS + 1] {
+    static TABLE: OnceLock<[f64; STIRLING1_SERIES_TERMS + 1]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; STIRLING1_SERIES_TERMS + 1];
+        for i in 1..=STIRLING1_SERIES_TERMS {
+            let sum: f64 = (1..i)
+                .map(|j| j as f64 * table[j] / (i - j + 1) as f64)
+                .sum();
+            table[i] = (i as f64 / (i + 1) as f64 - sum) / i as f64;
+        }
+        table
+    })
+}
+
+/// 第二类时 ℓ(t) = ln((e^t - 1)/t), 第一类时 ℓ(t) = ln(-ln(1 - t)/t), 返回 ℓ 及其关于 D = t d/dt 的 1 至 8 阶导数.
+/// 第一类只在 t <= `STIRLING1_SERIES_MAX_T` 时调用, 逐项求导幂级数, 各项均为正数; 第二类在 t 较小时逐项求导
+/// ℓ = t/2 + Σ_(i>=1) B_(2i)/(2i (2i)!) t^(2i), 否则利用 ℓ = t - ln t + ln(1 - e^(-t)) 与
+/// D^j e^(-st) = T_j(-st) e^(-st), 其中 T_j(y) = Σ_i S(j, i) y^i 为 Touchard 多项式
+fn stirling_log_derivatives(first: bool, t: f64) -> [f64; SADDLE_ORDER + 1] {
+    let mut d = [0.0; SADDLE_ORDER + 1];
+    if first {
+        let mut power = 1.0;
+        for (i, &c) in stirling1_series().iter().enumerate().skip(1) {
+            power *= t;
+            let mut term = c * power;
+            for value in d.iter_mut() {
+                *value += term;
+                term *= i as f64;
+            }
+        }
+    } else if t <= STIRLING2_SERIES_MAX_T {
+        d.iter_mut().for_each(|value| *value = 0.5 * t);
+        let t2 = t * t;
+        let mut power = 1.0;
+        for i in 1..=30u32 {
+            power *= t2;
+            let mut term = bernoulli(2 * i) / (2 * i) as f64 / factorial(2 * i as u64) * power;
+            for value in d.iter_mut() {
+                *value += term;
+                term *= (2 * i) as f64;
+            }
+        }
+    } else {
+        d[0] = t - t.ln() + (-(-t).exp()).ln_1p();
+        d[1] = t - 1.0;
+        d[2..].iter_mut().for_each(|value| *value = t);
+        let mut s = 1.0;
+        loop {
+            let y = -s * t;
+            let scale = y.exp();
+            if scale * y.powi(SADDLE_ORDER as i32) < 1.0e-20 {
+                break;
+            }
+            for (j, value) in d.iter_mut().enumerate().skip(1) {
+                let touchard = STIRLING2_SMALL[j][1..=j]
+                    .iter()
+                    .rev()
+                    .fold(0.0f64, |acc, &c| acc.mul_add(y, c))
+                    * y;
+                *value -= scale * touchard / s;
+            }
+            s += 1.0;
+        }
+    }
+    d
+}
+
+/// 鞍点展开的因子 ln[(2π κ_2)^(-1/2) (1 + c_1 + c_2 + c_3)], 其中 κ_j 为鞍点处指数关于 ln t 的 j 阶导数,
+/// c_i 是标准化累积量 λ_j = κ_j/κ_2^(j/2) 的多项式, 量级为 κ_2^(-i)
+fn saddle_point_ln_factor(kappa: &[f64; SADDLE_ORDER + 1]) -> f64 {
+    let k2 = kappa[2];
+    let lambda =
+        |j: usize| kappa[j] / k2.powi(j as i32 / 2) / if j % 2 == 1 { k2.sqrt() } else { 1.0 };
+    let (l3, l4, l5, l6, l7, l8) = (
+        lambda(3),
+        lambda(4),
+        lambda(5),
+        lambda(6),
+        lambda(7),
+        lambda(8),
+    );
+    let l3s = l3 * l3;
+    let c1 = l4 / 8.0 - 5.0 / 24.0 * l3s;
+    let c2 = l3s * (385.0 / 1152.0 * l3s - 35.0 / 64.0 * l4)
+        + 7.0 / 48.0 * l3 * l5
+        + 35.0 / 384.0 * l4 * l4
+        - l6 / 48.0;
+    let c3 = l3s
+        * (l3s * (25025.0 / 9216.0 * l4 - 85085.0 / 82944.0 * l3s) - 5005.0 / 3072.0 * l4 * l4
+            + 77.0 / 384.0 * l6)
+        + l3 * (77.0 / 128.0 * l4 * l5 - 1001.0 / 1152.0 * l3s * l5 - l7 / 32.0)
+        + 385.0 / 3072.0 * l4 * l4 * l4
+        - 7.0 / 128.0 * l4 * l6
+        - 21.0 / 640.0 * l5 * l5
+        + l8 / 384.0;
+    (c1 + c2 + c3).ln_1p() - 0.5 * (2.0 * PI * k2).ln()
+}
+
+/// 由 n!/k! [t^(n-k)] e^(k ℓ(t)) 的鞍点展开计算 ln c(n, k) (第一类要求 k >= n - k) 或 ln S(n, k).
+/// 鞍点 r 满足 k Dℓ(r) = n - k; Dℓ 关于 t 单调增加且为凸函数, 从右侧出发的 Newton 迭代单调收敛
+fn ln_stirling_saddle_t(first: bool, n: u64, k: u64) -> f64 {
+    let (m, kf) = ((n - k) as f64, k as f64);
+    let rho = m / kf;
+    // 两种情形都有 Dℓ(t) >= t/2, 第一类还有 Dℓ(STIRLING1_SERIES_MAX_T) > 1 >= ρ
+    let mut t = if first {
+        (2.0 * rho).min(STIRLING1_SERIES_MAX_T)
+    } else {
+        2.0 * rho
+    };
+    let mut d = stirling_log_derivatives(first, t);
+    for _ in 0..100 {
+        let step = (d[1] - rho) / d[2] * t;
+        t -= step;
+        d = stirling_log_derivatives(first, t);
+        if step.abs() <= 4.0 * f64::EPSILON * t {
+            break;
+        }
+    }
+    let kappa = d.map(|value| kf * value);
+    ln_gamma_diff_positive(kf + 1.0, m)
+        + m.mul_add(-t.ln(), kappa[0])
+        + saddle_point_ln_factor(&kappa)
+}
+
+/// `STIRLING1_HARMONIC_MAX_K` < k < n - k 时由 c(n, k) = [x^(k-1)] Γ(x + n)/Γ(x + 1) 的鞍点展开计算 ln c(n, k).
+/// 记 F(x) = ln Γ(x + n) - ln Γ(x + 1), 则 D^j F = Σ_i S(j, i) x^i F^(i)(x), F^(i) 为多 Gamma 函数之差.
+/// 鞍点满足 G(r) = D F(r) = k - 1, 而 (n - 1) x/(x + n - 1) <= G(x) <= H_(n-1) x 给出了 ln r 的上下界,
+/// 在其中以 Newton 迭代求 ln G(e^u) = ln(k - 1) 的根, 越界时改为二分
+fn ln_stirling1_saddle_x(n: u64, k: u64) -> f64 {
+    let (nf, target) = (n as f64, (k - 1) as f64);
+    let derivatives = |x: f64| {
+        let mut scaled = [0.0; SADDLE_ORDER + 1];
+        let mut power = 1.0;
+        for (i, value) in scaled.iter_mut().enumerate().skip(1) {
+            power *= x;
+            let order = i as u32 - 1;
+            *value = power * (polygamma(order, x + nf) - polygamma(order, x + 1.0));
+        }
+        let mut d = [0.0; SADDLE_ORDER + 1];
+        d[0] = ln_gamma_diff_positive(x + 1.0, nf - 1.0);
+        for (j, value) in d.iter_mut().enumerate().skip(1) {
+            *value = (1..=j).map(|i| STIRLING2_SMALL[j][i] * scaled[i]).sum();
+        }
+        d
+    };
+    let mut lo = (target / harmonic(n - 1)).ln();
+    let mut hi = (target * (nf - 1.0) / (n - k) as f64).ln();
+    let mut u = hi;
+    let mut d = derivatives(hi.exp());
+    for _ in 0..100 {
+        let residual = (d[1] / target).ln();
+        if residual > 0.0 {
+            hi = u;
+        } else {
+            lo = u;
+        }
+        let mut next = u - residual * d[1] / d[2];
+        if !(lo..=hi).contains(&next) {
+            next = 0.5 * (lo + hi);
+        }
+        let step = next - u;
+        u = next;
+        d = derivatives(u.exp());
+        if step.abs() <= 4.0 * f64::EPSILON * u.abs().max(1.0) {
+            break;
+        }
+    }
+    target.mul_add(-u, d[0]) + saddle_point_ln_factor(&d)
+}
+
+/// 2 <= k < n 且递推代价过高时计算无符号的第一类或第二类 Stirling 数的对数
+fn ln_stirling_large(first: bool, n: u64, k: u64) -> f64 {
+    let m = n - k;
+    if m <= STIRLING_EULERIAN_MAX_GAP {
+        ln_stirling_eulerian(first, n, m)
+    } else if !first {
+        ln_stirling_saddle_t(false, n, k)
+    } else if k <= STIRLING1_HARMONIC_MAX_K {
+        ln_stirling1_harmonic(n, k)
+    } else if k < m {
+        ln_stirling1_saddle_x(n, k)
+    } else {
+        ln_stirling_saddle_t(true, n, k)
+    }
+}
+
+/// 计算无符号的第一类或第二类 Stirling 数
+fn stirling(first: bool, n: u64, k: u64) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    if k == n || k <= 1 {
+        // c(n, 1) = (n - 1)!, S(n, 1) = 1
+        return match (k == n, k, first) {
+            (true, _, _) | (false, 1, false) => 1.0,
+            (false, 1, true) => factorial(n - 1),
+            _ => 0.0,
+        };
+    }
+    if let Some((mantissa, exponent)) = stirling_small(first, n, k, true) {
+        if exponent > f64::MAX_EXP as i64 {
+            return f64::INFINITY;
+        }
+        return mantissa * 2.0f64.powi(exponent as i32);
+    }
+    let m = n - k;
+    let ln_value = ln_stirling_large(first, n, k);
+    // 可以用 u128 表示的结果只出现在 n - k 很小时, 此时二阶 Euler 数展开可以精确计算
+    if m <= STIRLING_EULERIAN_MAX_GAP && ln_value < 128.0 * LN_2 {
+        if let Some(value) = stirling_eulerian_exact(first, n, m) {
+            return value as f64;
+        }
+    }
+    ln_value.exp()
+}
+
+/// 计算无符号的第一类或第二类 Stirling 数的对数
+fn ln_stirling(first: bool, n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    if k == n || k <= 1 {
+        return match (k == n, k, first) {
+            (true, _, _) | (false, 1, false) => 0.0,
+            (false, 1, true) => ln_factorial(n - 1),
+            _ => f64::NEG_INFINITY,
+        };
+    }
+    if let Some((mantissa, exponent)) = stirling_small(first, n, k, false) {
+        return (exponent as f64).mul_add(LN_2, mantissa.ln());
+    }
+    ln_stirling_large(first, n, k)
+}
+
+/// 计算无符号的第一类 Stirling 数 c(n, k) = |s(n, k)|, 即 n 个元素的置换中恰有 k 个轮换的个数
+///
+/// 结果可以用 u128 精确表示时返回其舍入到 f64 的值, 超出 f64 范围时返回 `f64::INFINITY`.
+/// k (n - k) 不超过 2^22 时用 f64 递推, 只有正数相加, 相对误差约为 n 个机器精度; 否则 n - k 较小时用二阶
+/// Euler 数展开, k 较小时用广义调和数, 其余情形用鞍点展开, 计算量与 n 无关
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::stirling1_unsigned;
+/// assert_eq!(stirling1_unsigned(5, 2), 50.0);
+/// assert_eq!(stirling1_unsigned(3, 5), 0.0);
+/// ```
+pub fn stirling1_unsigned(n: u64, k: u64) -> f64 {
+    stirling(true, n, k)
+}
+
+/// 计算带符号的第一类 Stirling 数 s(n, k) = (-1)^(n-k) c(n, k), 即 x(x - 1)...(x - n + 1) 中 x^k 的系数
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::stirling1;
+/// assert_eq!(stirling1(5, 2), -50.0);
+/// assert_eq!(stirling1(5, 3), 35.0);
+/// ```
+pub fn stirling1(n: u64, k: u64) -> f64 {
+    let value = stirling1_unsigned(n, k);
+    if k <= n && !(n - k).is_multiple_of(2) {
+        -value
+    } else {
+        value
+    }
+}
+
+/// 计算第二类 Stirling 数 S(n, k), 即把 n 个元素划分为 k 个非空子集的方法数
+///
+/// 结果可以用 u128 精确表示时返回其舍入到 f64 的值, 超出 f64 范围时返回 `f64::INFINITY`.
+/// k (n - k) 不超过 2^22 时用 f64 递推, 只有正数相加, 相对误差约为 n 个机器精度; 否则 n - k 较小时用二阶
+/// Euler 数展开, 其余情形用鞍点展开, 计算量与 n 无关
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::stirling2;
+/// assert_eq!(stirling2(5, 2), 15.0);
+/// assert_eq!(stirling2(0, 0), 1.0);
+/// ```
+pub fn stirling2(n: u64, k: u64) -> f64 {
+    stirling(false, n, k)
+}
+
+/// 计算第一类 Stirling 数的对数 ln |s(n, k)|, 结果为零时返回 `f64::NEG_INFINITY`
+///
+/// 算法与 [`stirling1_unsigned`] 相同, 但不会溢出, 对 n 直到 u64 的范围都有效
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_stirling1;
+/// assert!((ln_stirling1(5, 2) - 50.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_stirling1(n: u64, k: u64) -> f64 {
+    ln_stirling(true, n, k)
+}
+
+/// 计算第二类 Stirling 数的对数 ln S(n, k), 结果为零时返回 `f64::NEG_INFINITY`
+///
+/// 算法与 [`stirling2`] 相同, 但不会溢出, 对 n 直到 u64 的范围都有效
+///
+/// # Example
+///
+/// ```
+/// use special_functions::factorial::ln_stirling2;
+/// assert!((ln_stirling2(5, 2) - 15.0f64.ln()).abs() < 1.0e-14);
+/// ```
+pub fn ln_stirling2(n: u64, k: u64) -> f64 {
+    ln_stirling(false, n, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), 1.0);
+        assert_eq!(factorial(1), 1.0);
+        assert_eq!(factorial(20), 2432902008176640000.0);
+        assert_eq!(factorial(170), 7.257415615307999e306);
+        assert_eq!(factorial(171), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_factorial() {
+        let tol = 1.0e-14;
+        assert_eq!(ln_factorial(0), 0.0);
+        assert!(approx_eq(ln_factorial(5), 120.0f64.ln(), tol));
+        assert!(approx_eq(
+            ln_factorial(170) / 7.257415615307999e306f64.ln(),
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(ln_factorial(1000) / 5912.128178488163, 1.0, tol));
+        assert!(approx_eq(ln_factorial(2047) / 13564.326353384677, 1.0, tol));
+        assert!(approx_eq(
+            ln_factorial(1000000) / 12815518.384658169,
+            1.0,
+            tol
+        ));
+    }
+
+    #[test]
+    fn test_double_factorial() {
+        let tol = 1.0e-14;
+        assert_eq!(double_factorial(0), 1.0);
+        assert_eq!(double_factorial(1), 1.0);
+        assert_eq!(double_factorial(8), 384.0);
+        assert_eq!(double_factorial(29), 6190283353629375.0);
+        assert!(approx_eq(
+            double_factorial(31) / 1.9189878396251062e17,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            double_factorial(100) / 3.4243224702511973e79,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            double_factorial(151) / 9.204685605100357e132,
+            1.0,
+            tol
+        ));
+        assert_eq!(double_factorial(301), f64::INFINITY);
+        assert_eq!(double_factorial(-1), 1.0);
+        assert_eq!(double_factorial(-3), -1.0);
+        assert!(approx_eq(double_factorial(-7), -1.0 / 15.0, tol));
+        assert!(double_factorial(-4).is_nan());
+    }
+
+    #[test]
+    fn test_ln_double_factorial() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(ln_double_factorial(31), 39.79574446107524, tol));
+        assert!(approx_eq(
+            ln_double_factorial(301) / 711.6207772764014,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_double_factorial(302) / 714.7026099507904,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_double_factorial(5001) / 18801.846928635627,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_double_factorial(1000000) / 6407762.759102525,
+            1.0,
+            tol
+        ));
+        assert_eq!(ln_double_factorial(-1), 0.0);
+        assert!(approx_eq(ln_double_factorial(-7), -(15.0f64.ln()), tol));
+    }
+
+    #[test]
+    fn test_binomial() {
+        let tol = 1.0e-14;
+        assert_eq!(binomial(10, 3), 120.0);
+        assert_eq!(binomial(0, 0), 1.0);
+        assert_eq!(binomial(3, 5), 0.0);
+        assert_eq!(binomial(100, 50), 1.008913445455642e29);
+        assert!(approx_eq(
+            binomial(1000, 500) / 2.7028824094543655e299,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            binomial(1_000_000_000_000, 5) / 8.33333333325e57,
+            1.0,
+            tol
+        ));
+        assert_eq!(binomial(1_000_000, 100), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_binomial() {
+        let tol = 1.0e-14;
+        assert_eq!(ln_binomial(3, 5), f64::NEG_INFINITY);
+        assert!(approx_eq(
+            ln_binomial(2000, 1000) / 1382.2679935374802,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_binomial(1_000_000_000, 3) / 60.37803803861118,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_binomial(1_000_000, 1000) / 7902.882712976144,
+            1.0,
+            tol
+        ));
+        let r = ln_binomial(1_000_000_000_000_000_000, 500_000_000_000_000_000);
+        assert!(approx_eq(r / 6.931471805599453e17, 1.0, tol));
+    }
+
+    #[test]
+    fn test_ln_multinomial() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(ln_multinomial(&[1, 2, 3]), 60.0f64.ln(), tol));
+        assert!(approx_eq(
+            ln_multinomial(&[1_000_000, 3]) / 39.65477820465777,
+            1.0,
+            tol
+        ));
+        let r = ln_multinomial(&[3000, 4000, 5000]);
+        assert!(approx_eq(r / 12921.125515166668, 1.0, tol));
+        assert_eq!(ln_multinomial(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_stirling1_unsigned() {
+        let tol = 1.0e-14;
+        assert_eq!(stirling1_unsigned(0, 0), 1.0);
+        assert_eq!(stirling1_unsigned(5, 0), 0.0);
+        assert_eq!(stirling1_unsigned(10, 3), 1172700.0);
+        assert_eq!(stirling1_unsigned(3, 5), 0.0);
+        assert!(approx_eq(
+            stirling1_unsigned(30, 15) / 8.459574446076318e24,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            stirling1_unsigned(150, 75) / 1.1058062128941252e181,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            stirling1_unsigned(171, 1) / factorial(170),
+            1.0,
+            tol
+        ));
+        assert_eq!(stirling1_unsigned(172, 1), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_stirling_large_n() {
+        let tol = 1.0e-14;
+        assert_eq!(stirling2(100_000_000, 99_999_999), 4999999950000000.0);
+        assert!(approx_eq(
+            stirling1_unsigned(100_000_000, 99_999_998) / 1.2499999583333338e31,
+            1.0,
+            tol
+        ));
+        assert_eq!(stirling2(100_000_000, 50_000_000), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_stirling1() {
+        assert_eq!(stirling1(10, 3), -1172700.0);
+        assert_eq!(stirling1(25, 12), -1.3077092873675588e20);
+        assert_eq!(stirling1(7, 7), 1.0);
+        assert_eq!(stirling1(6, 5), -15.0);
+        assert_eq!(stirling1(3, 5), 0.0);
+    }
+
+    #[test]
+    fn test_stirling2() {
+        let tol = 1.0e-14;
+        assert_eq!(stirling2(0, 0), 1.0);
+        assert_eq!(stirling2(5, 0), 0.0);
+        assert_eq!(stirling2(10, 3), 9330.0);
+        assert_eq!(stirling2(3, 5), 0.0);
+        assert!(approx_eq(
+            stirling2(60, 30) / 9.563528855094403e53,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            stirling2(100, 50) / 4.3098323700936636e101,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            stirling2(1000, 2) / 5.357543035931337e300,
+            1.0,
+            tol
+        ));
+        assert_eq!(stirling2(1100, 2), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_stirling1() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(ln_stirling1(10, 3), 1172700.0f64.ln(), tol));
+        assert!(approx_eq(
+            ln_stirling1(1000, 500) / 3745.363976600817,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_stirling1(3000, 2) / 21018.16831751221,
+            1.0,
+            tol
+        ));
+        // 递推中同一行跨越的范围远超 f64
+        assert!(approx_eq(
+            ln_stirling1(3000, 2000) / 9155.37414840977,
+            1.0,
+            tol
+        ));
+        // 二阶 Euler 数展开
+        assert!(approx_eq(
+            ln_stirling1(8600, 8000) / 7184.654444540391,
+            1.0,
+            tol
+        ));
+        // 广义调和数
+        assert!(approx_eq(
+            ln_stirling1(100_000_000, 5) / 1742068074.676821,
+            1.0,
+            tol
+        ));
+        // 鞍点展开
+        assert!(approx_eq(
+            ln_stirling1(1_000_000, 30) / 12815508.62801543,
+            1.0,
+            tol
+        ));
+        assert_eq!(ln_stirling1(5, 0), f64::NEG_INFINITY);
+        assert_eq!(ln_stirling1(3, 5), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ln_stirling2() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(ln_stirling2(10, 3), 9330.0f64.ln(), tol));
+        assert!(approx_eq(
+            ln_stirling2(2000, 1000) / 7724.403677208048,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_stirling2(3000, 2000) / 8885.00057848748,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_stirling2(8600, 8000) / 7155.773278896569,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_stirling2(2_000_000, 1_999_000) / 22411.373479124988,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ln_stirling2(1_000_000, 100) / 4604806.446612536,
+            1.0,
+            tol
+        ));
+        assert_eq!(ln_stirling2(4, 4), 0.0);
+        assert_eq!(ln_stirling2(3, 5), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,451 @@
+use crate::triangle::sincospi;
+use crate::utils::eval_poly;
+use std::f64::consts::{FRAC_PI_6, PI};
+
+/// 使用 Taylor 级数计算 C(x) / x, 参数为 x^4, 适用于 |x| <= 1
+fn fresnel_c_series(x4: f64) -> f64 {
+    eval_poly(
+        x4,
+        &[
+            1.6748476126215183e-21,
+            -4.079981449233878e-19,
+            8.384729705118554e-17,
+            -1.4309189731715198e-14,
+            1.989685792418022e-12,
+            -2.2022769254454663e-10,
+            1.8843499115272686e-08,
+            -1.2000972558600288e-06,
+            5.4074133814083916e-05,
+            -0.0016048831356425355,
+            0.028185500877894225,
+            -0.24674011002723398,
+            1.0,
+        ],
+    )
+}
+
+/// 使用 Taylor 级数计算 S(x) / x^3, 参数为 x^4, 适用于 |x| <= 1
+fn fresnel_s_series(x4: f64) -> f64 {
+    eval_poly(
+        x4,
+        &[
+            1.011069642466722e-22,
+            -2.6678713628413992e-20,
+            5.980053239210405e-18,
+            -1.1223244787983955e-15,
+            1.7334102088874846e-13,
+            -2.1574306805843444e-11,
+            2.1082121933214546e-09,
+            -1.564714450092211e-07,
+            8.444272883545254e-06,
+            -0.0003121169423545792,
+            0.007244784204197004,
+            -0.09228058535803518,
+            FRAC_PI_6,
+        ],
+    )
+}
+
+/// 计算辅助函数 (f(x), g(x)), 要求 0 <= x < 3, 在三个区间上分别使用最佳一致逼近多项式
+///
+/// f 与 g 都是整函数, x < 1 时直接逼近也避免了由 C(x), S(x) 计算时的相消
+fn fresnel_aux_poly(x: f64) -> (f64, f64) {
+    if x < 1.0 {
+        let t = x - 0.5;
+        let f = eval_poly(
+            t,
+            &[
+                -2.1983406951709733e-06,
+                7.872134178269498e-06,
+                -1.3206178192229329e-05,
+                -2.0141385179495582e-07,
+                8.270441526617675e-05,
+                -0.00030210913810297925,
+                0.0006111853801265153,
+                -0.0005185246434559431,
+                -0.0012751560123101407,
+                0.006488822947475504,
+                -0.014781754885818236,
+                0.018939560378336162,
+                -0.0018758484049810292,
+                -0.055033569512336455,
+                0.14635972170762132,
+                -0.21622352471642667,
+                0.17436550030020992,
+                0.020141348349924326,
+                -0.2727573147272235,
+                0.39920505852570226,
+            ],
+        );
+        let g = eval_poly(
+            t,
+            &[
+                -1.945498390719186e-06,
+                1.419653207743112e-07,
+                1.548543811132205e-05,
+                -5.190566571748188e-05,
+                9.630438691164757e-05,
+                -4.757418184604923e-05,
+                -0.00037079735347204083,
+                0.0015318154510382323,
+                -0.0032950588706078033,
+                0.0036281298035472924,
+                0.0026507823442544964,
+                -0.021979954454656742,
+                0.05333658537620956,
+                -0.07489748724512178,
+                0.041628451278358236,
+                0.08429214985000609,
+                -0.27508480671838953,
+                0.41284674553013523,
+                -0.3729301604298852,
+                0.17364269961323775,
+            ],
+        );
+        (f, g)
+    } else if x < 2.0 {
+        let t = x - 1.5;
+        let f = eval_poly(
+            t,
+            &[
+                1.6863146331832407e-08,
+                1.5531781943952073e-07,
+                -9.311943682009271e-07,
+                2.963588549447709e-06,
+                -6.699474390330288e-06,
+                9.077489969789508e-06,
+                5.99840398951149e-06,
+                -8.637979912100371e-05,
+                0.0003330637370607639,
+                -0.0008874976688378104,
+                0.0017947429387382644,
+                -0.0026099372810357777,
+                0.0016810246236650158,
+                0.0046668670331757745,
+                -0.022350397275458476,
+                0.05829231841838155,
+                -0.11785589151490045,
+                0.20341843122601397,
+            ],
+        );
+        let g = eval_poly(
+            t,
+            &[
+                -1.250280558553184e-07,
+                3.4311399189676165e-07,
+                -4.7448635566031006e-07,
+                -2.346881066924025e-07,
+                4.740867279015455e-06,
+                -2.0254404343909866e-05,
+                5.811761309928333e-05,
+                -0.00012186364720291556,
+                0.00016179079453413248,
+                3.2270936018360525e-05,
+                -0.0010025635832231455,
+                0.00376383951389219,
+                -0.009644750221024458,
+                0.01945173836901712,
+                -0.031853041336544104,
+                0.04183752234808726,
+                -0.04141322628339094,
+                0.025009796942798094,
+            ],
+        );
+        (f, g)
+    } else {
+        let t = x - 2.5;
+        let f = eval_poly(
+            t,
+            &[
+                -5.811961370125623e-10,
+                5.86738598269922e-10,
+                3.903428271642621e-09,
+                -2.7372930930213846e-08,
+                1.2205356135727504e-07,
+                -4.381817504132845e-07,
+                1.3281206998037587e-06,
+                -3.3932949079807985e-06,
+                6.8819188339913286e-06,
+                -8.237412142498729e-06,
+                -1.290517807785583e-05,
+                0.00013420949473741064,
+                -0.0006024607171064001,
+                0.0021182215782935537,
+                -0.006524934455095854,
+                0.018444738799707774,
+                -0.04919447149613147,
+                0.1264069204948636,
+            ],
+        );
+        let g = eval_poly(
+            t,
+            &[
+                8.706286756948656e-10,
+                -3.6287297449200436e-09,
+                1.1295624445838602e-08,
+                -2.9776068238092478e-08,
+                5.6207196677749937e-08,
+                -1.9129746284868216e-08,
+                -4.573321110725823e-07,
+                2.8171900407915385e-06,
+                -1.1693255505536584e-05,
+                4.0034316130158573e-05,
+                -0.00011980103657244178,
+                0.0003204789902954751,
+                -0.0007724425553363848,
+                0.0016747851343333012,
+                -0.003228116824372561,
+                0.005373288582937365,
+                -0.00720236802606864,
+                0.006263634649122138,
+            ],
+        );
+        (f, g)
+    }
+}
+
+/// 计算辅助函数 (f(x), g(x)), 要求 x >= 3
+///
+/// f(x) ~ 1 / (πx), g(x) ~ 1 / (π^2 x^3), 将 πx f(x) 与 π^2 x^3 g(x) 表示为 u = 1 / x^4 的多项式,
+/// u -> 0 时二者都趋于 1
+fn fresnel_aux_tail(x: f64) -> (f64, f64) {
+    let r = 1.0 / x;
+    let r2 = r * r;
+    let u = r2 * r2;
+    let f = eval_poly(
+        u,
+        &[
+            2.413222303000579e18,
+            -2.4506693001043024e17,
+            1.1536920624827808e16,
+            -337551273947477.4,
+            7003227590293.205,
+            -113292281429.26627,
+            1579475373.5590498,
+            -21584030.19141699,
+            337586.4799769922,
+            -6983.128218200059,
+            213.6188659166305,
+            -10.812470889567974,
+            1.077928133663919,
+            -0.3039635509265145,
+            1.0,
+        ],
+    );
+    let g = eval_poly(
+        u,
+        &[
+            5.2028500733120767e23,
+            -5.827291349854848e22,
+            3.0271402411397224e21,
+            -9.723680516984201e19,
+            2.1788621758003336e18,
+            -3.663384556122867e16,
+            490518515627793.25,
+            -5584821397494.81,
+            59009621496.75144,
+            -650229973.021469,
+            8499340.042788813,
+            -146743.70228367238,
+            3631.621376634561,
+            -140.5621792181805,
+            9.701353217670812,
+            -1.519817754633426,
+            1.0,
+        ],
+    );
+    (f * r / PI, g * r * r2 / (PI * PI))
+}
+
+/// 计算 (sin(πx^2 / 2), cos(πx^2 / 2))
+///
+/// 将 x 拆分为高位 hi 与低位 lo, 使 hi^2 / 2 可精确表示, 再由
+/// x^2 / 2 = hi^2 / 2 + lo (x + hi) / 2 与和角公式计算, 避免 x^2 的舍入误差进入相位
+fn sincospi_half_square(x: f64) -> (f64, f64) {
+    let x = x.abs();
+    // x >= 2^53 时 x 为偶数, x^2 / 2 也是偶数
+    if x >= 9007199254740992.0 {
+        return (0.0, 1.0);
+    }
+    let hi = f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000);
+    let lo = x - hi;
+    let (s1, c1) = sincospi(0.5 * hi * hi);
+    let (s2, c2) = sincospi(0.5 * lo * (x + hi));
+    (s1 * c2 + c1 * s2, c1 * c2 - s1 * s2)
+}
+
+/// 对 x >= 0 计算 (S(x), C(x))
+fn fresnel_positive(x: f64) -> (f64, f64) {
+    if x <= 1.0 {
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        return (x * x2 * fresnel_s_series(x4), x * fresnel_c_series(x4));
+    }
+    if x.is_infinite() {
+        return (0.5, 0.5);
+    }
+    let (f, g) = if x < 3.0 {
+        fresnel_aux_poly(x)
+    } else {
+        fresnel_aux_tail(x)
+    };
+    let (sin, cos) = sincospi_half_square(x);
+    (0.5 - f * cos - g * sin, 0.5 + f * sin - g * cos)
+}
+
+/// 对 x >= 0 计算辅助函数 (f(x), g(x))
+fn fresnel_aux_positive(x: f64) -> (f64, f64) {
+    if x < 3.0 {
+        fresnel_aux_poly(x)
+    } else if x.is_infinite() {
+        (0.0, 0.0)
+    } else {
+        fresnel_aux_tail(x)
+    }
+}
+
+/// 计算 Fresnel 积分 S(x) = ∫_0^x sin(πt^2 / 2) dt
+///
+/// |x| <= 1 时使用 Taylor 级数, 否则由辅助函数 f(x), g(x) 计算
+/// S(x) = 1/2 - f(x) cos(πx^2 / 2) - g(x) sin(πx^2 / 2), 其中的三角函数借助 `sincospi` 计算
+///
+/// # Example
+///
+/// ```
+/// use special_functions::fresnel::fresnel_s;
+/// assert!((fresnel_s(1.0) - 0.43825914739035476).abs() < 1.0e-16);
+/// ```
+pub fn fresnel_s(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    fresnel_positive(x.abs()).0.copysign(x)
+}
+
+/// 计算 Fresnel 积分 C(x) = ∫_0^x cos(πt^2 / 2) dt
+///
+/// |x| <= 1 时使用 Taylor 级数, 否则由辅助函数 f(x), g(x) 计算
+/// C(x) = 1/2 + f(x) sin(πx^2 / 2) - g(x) cos(πx^2 / 2), 其中的三角函数借助 `sincospi` 计算
+///
+/// # Example
+///
+/// ```
+/// use special_functions::fresnel::fresnel_c;
+/// assert!((fresnel_c(1.0) - 0.7798934003768229).abs() < 2.0e-16);
+/// ```
+pub fn fresnel_c(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    fresnel_positive(x.abs()).1.copysign(x)
+}
+
+/// 计算 Fresnel 积分的辅助函数 f(x) = (1/2 - S(x)) cos(πx^2 / 2) - (1/2 - C(x)) sin(πx^2 / 2)
+///
+/// 使用分段的最佳一致逼近多项式, x 很大时 f(x) ~ 1 / (πx); x < 0 时利用
+/// f(-x) = cos(πx^2 / 2) - sin(πx^2 / 2) - f(x)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::fresnel::fresnel_f;
+/// assert!((fresnel_f(2.0) - 0.15658432163630176).abs() < 1.0e-16);
+/// ```
+pub fn fresnel_f(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    let (f, _) = fresnel_aux_positive(x.abs());
+    if x < 0.0 {
+        let (sin, cos) = sincospi_half_square(x);
+        return cos - sin - f;
+    }
+    f
+}
+
+/// 计算 Fresnel 积分的辅助函数 g(x) = (1/2 - C(x)) cos(πx^2 / 2) + (1/2 - S(x)) sin(πx^2 / 2)
+///
+/// 使用分段的最佳一致逼近多项式, x 很大时 g(x) ~ 1 / (π^2 x^3); x < 0 时利用
+/// g(-x) = cos(πx^2 / 2) + sin(πx^2 / 2) - g(x)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::fresnel::fresnel_g;
+/// assert!((fresnel_g(2.0) - 0.011746593924659246).abs() < 1.0e-16);
+/// ```
+pub fn fresnel_g(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    let (_, g) = fresnel_aux_positive(x.abs());
+    if x < 0.0 {
+        let (sin, cos) = sincospi_half_square(x);
+        return cos + sin - g;
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_fresnel_s() {
+        let tol = 1.0e-16;
+        assert!(approx_eq(
+            fresnel_s(1.0e-5) / 5.23598775598299e-16,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(fresnel_s(0.5), 0.06473243285999927, tol));
+        assert!(approx_eq(fresnel_s(1.0), 0.43825914739035476, tol));
+        assert!(approx_eq(fresnel_s(1.7), 0.5491959403215686, tol));
+        assert!(approx_eq(fresnel_s(2.5), 0.6191817558195929, tol));
+        assert!(approx_eq(fresnel_s(4.0), 0.42051575424692844, tol));
+        assert!(approx_eq(fresnel_s(30.0), 0.4893896744421938, tol));
+        assert!(approx_eq(fresnel_s(1.0e5), 0.4999968169011382, tol));
+        assert!(approx_eq(fresnel_s(1.0e10), 0.499999999968169, tol));
+        assert!(approx_eq(fresnel_s(-2.0), -0.34341567836369824, tol));
+        assert_eq!(fresnel_s(f64::INFINITY), 0.5);
+        assert!(fresnel_s(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fresnel_c() {
+        let tol = 2.0e-16;
+        assert!(approx_eq(fresnel_c(1.0e-5), 1.0e-5, tol));
+        assert!(approx_eq(fresnel_c(0.5), 0.4923442258714464, tol));
+        assert!(approx_eq(fresnel_c(1.0), 0.7798934003768229, tol));
+        assert!(approx_eq(fresnel_c(1.7), 0.32382687600390025, tol));
+        assert!(approx_eq(fresnel_c(2.5), 0.45741300964177706, tol));
+        assert!(approx_eq(fresnel_c(4.0), 0.4984260330381776, tol));
+        assert!(approx_eq(fresnel_c(30.0), 0.4999962473706099, tol));
+        assert!(approx_eq(fresnel_c(1.0e5), 0.4999999999999999, tol));
+        assert!(approx_eq(fresnel_c(-2.0), -0.48825340607534073, tol));
+        assert_eq!(fresnel_c(f64::NEG_INFINITY), -0.5);
+    }
+
+    #[test]
+    fn test_fresnel_aux() {
+        let tol = 1.0e-15;
+        let cases = [
+            (0.0, 0.5, 0.5),
+            (0.5, 0.39920505852570226, 0.17364269961323775),
+            (1.5, 0.20341843122601397, 0.025009796942798094),
+            (2.0, 0.15658432163630176, 0.011746593924659246),
+            (2.8, 0.1131482205164123, 0.004511259624699436),
+            (3.0, 0.10572078929768564, 0.003687001032624964),
+            (10.0, 0.03183002141511776, 0.00010130579448427639),
+            (1.0e10, 3.1830988618379065e-11, 1.0132118364233778e-31),
+            (-1.0, -1.2798934003768228, 0.9382591473903548),
+        ];
+        for (x, f, g) in cases {
+            assert!(approx_eq(fresnel_f(x) / f, 1.0, tol));
+            assert!(approx_eq(fresnel_g(x) / g, 1.0, tol));
+        }
+        assert_eq!(fresnel_f(f64::INFINITY), 0.0);
+        assert!(fresnel_g(f64::NEG_INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,257 @@
+use crate::gamma::{digamma, BERNOULLI_OVER_FACTORIAL, EULER};
+use crate::zeta::{hurwitz_zeta, zeta};
+use std::f64::consts::LN_2;
+
+/// n 不超过此值时直接求和
+const HARMONIC_SUM_MAX: u64 = 32;
+
+/// |x| 不超过此值时 H(x) 使用 ζ 值的幂级数, 项按 |x| 衰减
+const HARMONIC_SERIES_RADIUS: f64 = 0.25;
+
+/// 直接求和 Σ_(k=1)^n k^(-m), 从较小的项开始累加
+fn harmonic_direct(n: u64, m: f64) -> f64 {
+    if m > 0.0 {
+        (1..=n).rev().map(|k| (k as f64).powf(-m)).sum()
+    } else {
+        (1..=n).map(|k| (k as f64).powf(-m)).sum()
+    }
+}
+
+/// Euler–Maclaurin 求和公式计算 Σ_(k=1)^n k^(-m): 先直接求和前 N - 1 项, 使 N >= max(|m| + 10, 15), 其余部分为
+/// ∫_N^n x^(-m) dx + (N^(-m) + n^(-m))/2 + Σ_j B_(2j)/(2j)! m(m+1)...(m+2j-2) (N^(-m-2j+1) - n^(-m-2j+1)).
+/// m 接近 1 时积分以 expm1 计算, 没有相消; m 为非正整数时修正项有限, 结果即 Faulhaber 公式
+fn harmonic_euler_maclaurin(n: f64, m: f64) -> f64 {
+    let start = (m.abs() + 10.0).max(15.0).ceil();
+    let mut sum = 0.0;
+    let mut k = start;
+    while k > 1.0 {
+        k -= 1.0;
+        sum += k.powf(-m);
+    }
+    let ratio = (n / start).ln();
+    let exponent = (1.0 - m) * ratio;
+    let integral = if m == 1.0 {
+        ratio
+    } else if exponent.abs() < 1.0 {
+        start.powf(1.0 - m) * exponent.exp_m1() / (1.0 - m)
+    } else {
+        (n.powf(1.0 - m) - start.powf(1.0 - m)) / (1.0 - m)
+    };
+    let mut value = integral + 0.5 * (start.powf(-m) + n.powf(-m));
+    let (w_start, w_end) = (1.0 / (start * start), 1.0 / (n * n));
+    let mut power_start = start.powf(-m) / start;
+    let mut power_end = n.powf(-m) / n;
+    let mut fac = m;
+    for (j, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate() {
+        let term = c * fac * (power_start - power_end);
+        value += term;
+        if term.abs() <= 0.5 * f64::EPSILON * (sum + value).abs() {
+            break;
+        }
+        let r = m + 2.0 * j as f64;
+        fac *= (r + 1.0) * (r + 2.0);
+        power_start *= w_start;
+        power_end *= w_end;
+    }
+    sum + value
+}
+
+/// 计算调和数 H_n = Σ_(k=1)^n 1/k
+///
+/// n <= 32 时直接求和, 否则使用 H_n = ψ(n + 1) + γ
+///
+/// # Example
+///
+/// ```
+/// use special_functions::harmonic::harmonic;
+/// assert_eq!(harmonic(0), 0.0);
+/// assert!((harmonic(4) - 25.0 / 12.0).abs() < 1.0e-15);
+/// ```
+pub fn harmonic(n: u64) -> f64 {
+    if n <= HARMONIC_SUM_MAX {
+        harmonic_direct(n, 1.0)
+    } else {
+        digamma(n as f64 + 1.0) + EULER
+    }
+}
+
+/// 计算 m 阶广义调和数 H_n^(m) = Σ_(k=1)^n k^(-m), m 可以为任意实数
+///
+/// n 较小时直接求和; m > 1 且 n^(1-m) <= 1/2 时使用 H_n^(m) = ζ(m) - ζ(m, n + 1), 两者相减至多损失一位;
+/// 其余情形 (m 接近 1, m < 1) 使用 Euler–Maclaurin 求和公式. m = 1 时即 `harmonic`.
+/// 结果上溢时返回 `f64::INFINITY`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::harmonic::harmonic_generalized;
+/// // 1 + 1/4 + 1/9
+/// assert!((harmonic_generalized(3, 2.0) - 49.0 / 36.0).abs() < 1.0e-15);
+/// // 1 + 2 + 3 + 4
+/// assert_eq!(harmonic_generalized(4, -1.0), 10.0);
+/// ```
+pub fn harmonic_generalized(n: u64, m: f64) -> f64 {
+    if m.is_nan() {
+        return f64::NAN;
+    }
+    if n == 0 {
+        return 0.0;
+    }
+    if m == 1.0 {
+        return harmonic(n);
+    }
+    if m == f64::INFINITY || n == 1 {
+        return 1.0;
+    }
+    if m == f64::NEG_INFINITY {
+        return f64::INFINITY;
+    }
+    let nf = n as f64;
+    if n <= HARMONIC_SUM_MAX || nf <= (m.abs() + 10.0).max(15.0) {
+        return harmonic_direct(n, m);
+    }
+    if m > 1.0 && (1.0 - m) * nf.ln() <= -LN_2 {
+        return zeta(m) - hurwitz_zeta(m, nf + 1.0);
+    }
+    harmonic_euler_maclaurin(nf, m)
+}
+
+/// 计算调和数在实数上的延拓 H(x) = ψ(x + 1) + γ, 满足 H(x) = H(x - 1) + 1/x
+///
+/// |x| <= 1/4 时使用 H(x) = Σ_(k>=2) (-1)^k ζ(k) x^(k-1), 零点 x = 0 附近保持相对精度; 非负整数处与 `harmonic` 一致.
+/// 负整数是 H 的极点, 返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::harmonic::harmonic_real;
+/// // H(1/2) = 2 - 2 ln 2
+/// assert!((harmonic_real(0.5) - (2.0 - 2.0 * std::f64::consts::LN_2)).abs() < 1.0e-15);
+/// ```
+pub fn harmonic_real(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+    if x >= 0.0 && x == x.floor() && x <= HARMONIC_SUM_MAX as f64 {
+        return harmonic(x as u64);
+    }
+    if x.abs() > HARMONIC_SERIES_RADIUS {
+        return digamma(x + 1.0) + EULER;
+    }
+    let mut sum = 0.0;
+    let mut power = x;
+    let mut k = 2.0;
+    loop {
+        let term = zeta(k) * power;
+        sum += term;
+        if term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        power *= -x;
+        k += 1.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_harmonic() {
+        let tol = 1.0e-15;
+        assert_eq!(harmonic(0), 0.0);
+        assert_eq!(harmonic(1), 1.0);
+        assert!(approx_eq(harmonic(10), 2.9289682539682538, tol));
+        assert!(approx_eq(harmonic(33), 4.08879822573955, tol));
+        assert!(approx_eq(harmonic(1000) / 7.485470860550345, 1.0, tol));
+        assert!(approx_eq(
+            harmonic(1_000_000_000) / 21.300481502347942,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic(1_000_000_000_000_000) / 35.11599205981222,
+            1.0,
+            tol
+        ));
+    }
+
+    #[test]
+    fn test_harmonic_generalized() {
+        let tol = 1.0e-15;
+        assert_eq!(harmonic_generalized(0, 2.0), 0.0);
+        assert_eq!(harmonic_generalized(7, 0.0), 7.0);
+        assert_eq!(harmonic_generalized(5, f64::INFINITY), 1.0);
+        assert_eq!(harmonic_generalized(1000, 1.0), harmonic(1000));
+        assert!(approx_eq(
+            harmonic_generalized(10, 2.0),
+            1.5497677311665408,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(100, 3.0),
+            1.2020074006596777,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(1_000_000, 2.0),
+            1.6449330668487265,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(1_000_000_000, 1.5) / 2.6123121031323007,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(1_000_000_000_000, 1.000001) / 28.207855120498397,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(1_000_000, 0.5) / 1998.5401454911487,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(10000, -0.5) / 666716.4591971084,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            harmonic_generalized(1_000_000, -3.0) / 2.5000050000025e23,
+            1.0,
+            tol
+        ));
+        assert!(harmonic_generalized(3, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_harmonic_real() {
+        let tol = 1.0e-15;
+        assert_eq!(harmonic_real(0.0), 0.0);
+        assert_eq!(harmonic_real(10.0), harmonic(10));
+        assert!(approx_eq(
+            harmonic_real(1.0e-10) / 1.644934066728021e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(harmonic_real(-0.1), -0.17771128504551853, tol));
+        assert!(approx_eq(harmonic_real(0.2), 0.2881757683093446, tol));
+        assert!(approx_eq(harmonic_real(0.7), 0.7857635397750268, tol));
+        assert!(approx_eq(harmonic_real(-0.5), -1.3862943611198906, tol));
+        assert!(approx_eq(harmonic_real(-1.5), 0.6137056388801094, tol));
+        assert!(approx_eq(harmonic_real(5.5), 2.3701269953014656, tol));
+        assert!(approx_eq(
+            harmonic_real(1.0e6) / 14.392726722865724,
+            1.0,
+            tol
+        ));
+        assert!(harmonic_real(-2.0).is_nan());
+        assert_eq!(harmonic_real(f64::INFINITY), f64::INFINITY);
+    }
+}
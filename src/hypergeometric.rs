@@ -0,0 +1,1936 @@
+use crate::bessel::{bessel_ive, bessel_jv, ln_bessel_iv};
+use crate::gamma::{
+    digamma, gamma, gamma_ratio, ln_gamma_diff, ln_gamma_diff_positive, ln_gamma_sign, rgamma,
+    rising_factorial, EULER,
+};
+use crate::harmonic::harmonic;
+use crate::polylog::complex_exp_m1;
+use crate::triangle::{cospi, sinpi};
+use num_complex::Complex64;
+use std::f64::consts::{LN_2, PI};
+use std::ops::{Add, Mul};
+
+/// 级数与渐近展开求和的最大项数
+const HYP_MAX_TERMS: usize = 10000;
+
+/// x 超过此值与 |a| + |b| 之和时优先尝试 ₁F₁ 的大参数渐近展开
+const HYP1F1_ASYMPTOTIC_MIN: f64 = 30.0;
+
+/// 幂级数最大项与和之比超过此值时认为相消严重, 尝试其他方法
+const HYP1F1_SERIES_MAX_LOSS: f64 = 16.0;
+
+/// Bessel 函数展开最大项与和之比不超过此值时停止增大平移量 N
+const HYP1F1_BESSEL_MAX_LOSS: f64 = 256.0;
+
+/// 参数 a 向下平移的最大量
+const HYP1F1_MAX_SHIFT: f64 = 4096.0;
+
+/// 逐步求解 Kummer 方程时每步跨越的振荡相位
+const HYP1F1_TAYLOR_STEP: f64 = 2.0;
+
+/// a 的递推中部分结果超过 2^HYP1F1_RESCALE_EXPONENT 时乘以 2^(-HYP1F1_RESCALE_EXPONENT), 缩放是精确的
+const HYP1F1_RESCALE_EXPONENT: i32 = 512;
+
+/// U(a, b, x) 的连接公式中两部分的绝对值之和与结果之比不超过此值时使用连接公式, 否则使用 Miller 算法
+const HYPU_CONNECTION_MAX_LOSS: f64 = 256.0;
+
+/// Miller 算法的起始阶数, 不收敛时加倍
+const HYPU_MILLER_MIN_ORDER: usize = 16;
+
+/// Miller 算法的最大阶数
+const HYPU_MILLER_MAX_ORDER: usize = 1 << 20;
+
+/// ₂F₁ 幂级数最大项与和之比超过此值时尝试 Euler 变换
+const HYP2F1_SERIES_MAX_LOSS: f64 = 16.0;
+
+/// x 不超过此值时幂级数收敛较快, 1 - x 变换相消严重时可以改用幂级数
+const HYP2F1_SERIES_MAX_X: f64 = 0.9;
+
+/// 复数情形变换后变量的模不超过此值时使用对应的级数, 否则使用 Bühring 展开
+const HYP2F1_COMPLEX_SERIES_MAX: f64 = 0.75;
+
+/// Bühring 展开两部分的绝对值之和与结果之比超过此值时改为逐步求解超几何方程
+const HYP2F1_BUHRING_MAX_LOSS: f64 = 256.0;
+
+/// 逐步求解超几何方程时步长与 Taylor 级数收敛半径之比
+const HYP2F1_TAYLOR_STEP: f64 = 0.5;
+
+/// 1 - x 变换中 c - a - b 与最近整数之差小于此值时使用极限形式
+const HYP2F1_LIMIT_MAX: f64 = 0.1;
+
+/// |x| 超过此值与 max(|b|, 1) 之积时 ₀F₁ 的幂级数所需项数过多, 改用 Bessel 函数
+const HYP0F1_SERIES_MAX: f64 = 1.0e4;
+
+/// ₀F₁ 幂级数最大项与和之比超过此值时改用 Bessel 函数
+const HYP0F1_SERIES_MAX_LOSS: f64 = 16.0;
+
+/// x 是否为非正整数
+fn is_nonpositive_integer(x: f64) -> bool {
+    x <= 0.0 && x == x.floor()
+}
+
+/// 计算 Γ(b)/Γ(a) x^c, 结果上溢或下溢时在对数尺度下计算
+fn gamma_ratio_power(b: f64, a: f64, x: f64, c: f64) -> f64 {
+    let value = gamma_ratio(b, a) * x.powf(c);
+    if value.is_normal() || value == 0.0 && is_nonpositive_integer(a) {
+        return value;
+    }
+    let (_, sign_b) = ln_gamma_sign(b);
+    let (_, sign_a) = ln_gamma_sign(a);
+    let sign = (sign_a * sign_b) as f64;
+    sign * c.mul_add(x.ln(), ln_gamma_diff(b, a)).exp()
+}
+
+/// 直接求和 ₁F₁(a; b; x) = Σ_(k>=0) (a)_k / (b)_k x^k / k!, 同时返回最大项与和之比, 用于估计相消损失的精度.
+/// a 为非正整数时级数在 k = -a 处终止; a 或 b 为负时各项可能先减小再增大, 因此至少求和到 k = max(-a, -b)
+fn hyp1f1_series(a: f64, b: f64, x: f64) -> (f64, f64) {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut max_term = 1.0f64;
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        term *= (a + k) / (b + k) * x / (k + 1.0);
+        sum += term;
+        max_term = max_term.max(term.abs());
+        if term == 0.0 || term.abs() <= 0.5 * f64::EPSILON * sum.abs() && k + 1.0 > -a.min(b) {
+            break;
+        }
+    }
+    (sum, max_term / sum.abs())
+}
+
+/// a < b/2, x > 0 时 e^(-x) ₁F₁(a; b; x) 的 Bessel 函数展开 (Tricomi, A&S 13.3.7): 令 κ = b/2 - a, ζ = 2√(κx),
+/// M(a, b, x) = Γ(b) e^(x/2) (κx)^((1 - b)/2) Σ_(n>=0) A_n (x/(4κ))^(n/2) J_(b-1+n)(ζ),
+/// A_0 = 1, A_1 = 0, A_2 = b/2, (n + 1) A_(n+1) = (n + b - 1) A_(n-1) + (2a - b) A_(n-2).
+/// a → -∞ 时收敛很快, 且不像幂级数那样在振荡区域中严重相消. 同时返回最大项与和之比
+fn hyp1f1_bessel_scaled(a: f64, b: f64, x: f64) -> (f64, f64) {
+    let kappa = 0.5 * b - a;
+    let zeta = 2.0 * (kappa * x).sqrt();
+    let r = (x / (4.0 * kappa)).sqrt();
+    // (B_(n-2), B_(n-1), B_n), B_n = A_n r^n, A_(-1) = A_(-2) = 0
+    let (mut prev2, mut prev1, mut current) = (0.0, 0.0, 1.0);
+    let mut sum = 0.0;
+    let mut max_term = 0.0f64;
+    let mut small = 0;
+    for n in 0..HYP_MAX_TERMS {
+        let n = n as f64;
+        let term = current * bessel_jv(b - 1.0 + n, zeta);
+        sum += term;
+        max_term = max_term.max(term.abs());
+        // J_ν(ζ) 在 ν > ζ 后单调衰减, 连续两项可忽略时停止
+        if term.abs() <= 0.5 * f64::EPSILON * sum.abs() && b - 1.0 + n > zeta {
+            small += 1;
+            if small == 2 {
+                break;
+            }
+        } else {
+            small = 0;
+        }
+        let next = ((n + b - 1.0) * prev1 + (2.0 * a - b) * r * prev2) * r * r / (n + 1.0);
+        (prev2, prev1, current) = (prev1, current, next);
+    }
+    let (ln_gamma_b, sign) = ln_gamma_sign(b);
+    let scale = sign as f64
+        * (0.5 * (1.0 - b))
+            .mul_add((kappa * x).ln(), ln_gamma_b - 0.5 * x)
+            .exp();
+    (scale * sum, max_term / sum.abs())
+}
+
+/// 渐近级数 ₂F₀(p, q; ; 1/z) = Σ_k (p)_k (q)_k / k! z^(-k), 各项开始增大而仍未收敛时返回 None
+fn hyp2f0_asymptotic(p: f64, q: f64, z: f64) -> Option<f64> {
+    let mut sum = 1.0;
+    let mut term = 1.0f64;
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        let next = term * (p + k) * (q + k) / ((k + 1.0) * z);
+        if next == 0.0 {
+            return Some(sum);
+        }
+        if next.abs() > term.abs() {
+            return None;
+        }
+        sum += next;
+        if next.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return Some(sum);
+        }
+        term = next;
+    }
+    None
+}
+
+/// x → +∞ 时 e^(-x) ₁F₁(a; b; x) 的渐近展开 (DLMF 13.7.2):
+/// Γ(b)/Γ(a) x^(a-b) ₂F₀(b - a, 1 - a; ; 1/x) + cos(πa) e^(-x) Γ(b)/Γ(b - a) x^(-a) ₂F₀(a, a - b + 1; ; -1/x).
+/// 第二项在 a 为非正整数时给出 Laguerre 多项式, 其余情形通常可以忽略. 渐近级数不收敛时返回 None
+fn hyp1f1_asymptotic_scaled(a: f64, b: f64, x: f64) -> Option<f64> {
+    let dominant = if is_nonpositive_integer(a) {
+        0.0
+    } else {
+        gamma_ratio_power(b, a, x, a - b) * hyp2f0_asymptotic(b - a, 1.0 - a, x)?
+    };
+    let c = cospi(a);
+    let recessive = if c == 0.0 || is_nonpositive_integer(b - a) {
+        0.0
+    } else {
+        let scale = gamma_ratio_power(b, b - a, x, -a) * (-x).exp();
+        if scale.abs() <= f64::EPSILON * dominant.abs() {
+            0.0
+        } else {
+            c * scale * hyp2f0_asymptotic(a, a - b + 1.0, -x)?
+        }
+    };
+    Some(dominant + recessive)
+}
+
+/// a < 0, a < b/2, b < x 时的 e^(-x) ₁F₁(a; b; x). Bessel 函数展开在 x/(4κ) 较小时相消很少, 因此先在
+/// a - N (N 约为 x) 处计算 M(a - N) 与 M(a - N + 1), 再由 a 的三项递推 (DLMF 13.3.1)
+/// a M(a + 1) = (2a - b + x) M(a) + (b - a) M(a - 1) 向上递推到 a; 在振荡区域中递推的两个解量级相当, 误差不会放大.
+/// 起点处相消仍严重时加倍 N. 同时返回起点处最大项与和之比
+fn hyp1f1_bessel_recurrence_scaled(a: f64, b: f64, x: f64) -> (f64, f64) {
+    let mut shift = (x + a).ceil().max(0.0);
+    let (start, loss) = loop {
+        let (value, loss) = hyp1f1_bessel_scaled(a - shift, b, x);
+        if loss <= HYP1F1_BESSEL_MAX_LOSS || shift >= HYP1F1_MAX_SHIFT {
+            break (value, loss);
+        }
+        shift = 2.0 * shift + 16.0;
+    };
+    if shift == 0.0 {
+        return (start, loss);
+    }
+    let (next, next_loss) = hyp1f1_bessel_scaled(a - shift + 1.0, b, x);
+    let (mut previous, mut current) = (start, next);
+    for k in 1..shift as usize {
+        let c = a - shift + k as f64;
+        let value = ((2.0 * c - b + x) * current + (b - c) * previous) / c;
+        (previous, current) = (current, value);
+    }
+    (current, loss.max(next_loss))
+}
+
+/// a < 0 < b, x > 0 时的 e^(-x) ₁F₁(a; b; x), 要求 x <= b 或 a 为负整数. 令 a_0 = a + ⌊-a⌋ ∈ (-1, 0],
+/// x <= b 时 M(a_0) 与 M(a_0 + 1) 的级数相消很少; a 为负整数时 a_0 = 0, 改用精确的 M(0) = 1 与 M(-1) = 1 - x/b,
+/// 此时 M 是广义 Laguerre 多项式. 再由 a 的三项递推 (b - a) M(a - 1) = a M(a + 1) - (2a - b + x) M(a)
+/// 向下递推到 a. 此时 M 沿 a 减小的方向为主导解, 或处于两个解量级相当的振荡区域, 递推稳定.
+/// 多项式的值可以远超 f64 范围, 因此递推中按 2 的幂缩放, 最后与 e^(-x) 合并
+fn hyp1f1_recurrence_down_scaled(a: f64, b: f64, x: f64) -> f64 {
+    let steps = (-a).floor();
+    let a0 = a + steps;
+    let (mut c, mut current, mut next, steps) = if a0 == 0.0 {
+        (-1.0, 1.0 - x / b, 1.0, steps - 1.0)
+    } else {
+        (
+            a0,
+            hyp1f1_series(a0, b, x).0,
+            hyp1f1_series(a0 + 1.0, b, x).0,
+            steps,
+        )
+    };
+    let mut exponent = 0;
+    for _ in 0..steps as usize {
+        let value = (c * next - (2.0 * c - b + x) * current) / (b - c);
+        (current, next) = (value, current);
+        c -= 1.0;
+        if current.abs() > 2.0f64.powi(HYP1F1_RESCALE_EXPONENT) {
+            current *= 2.0f64.powi(-HYP1F1_RESCALE_EXPONENT);
+            next *= 2.0f64.powi(-HYP1F1_RESCALE_EXPONENT);
+            exponent += HYP1F1_RESCALE_EXPONENT;
+        }
+    }
+    if current == 0.0 {
+        return 0.0;
+    }
+    current.signum()
+        * (exponent as f64)
+            .mul_add(LN_2, current.abs().ln() - x)
+            .exp()
+}
+
+/// 由 x_0 = b/(4(1 - a)) 出发逐步求解 Kummer 方程 xM'' + (b - x)M' - aM = 0 得到 e^(-x) ₁F₁(a; b; x).
+/// 在 ξ 处展开 M(ξ + h) = Σ_k f_k h^k, 系数满足 (k + 1)(k + 2)ξ f_(k+2) = (k + a) f_k - (k + 1)(k + b - ξ) f_(k+1).
+/// 收敛半径为 ξ, 但解在 κ = b/2 - a 较大时以局部频率约 √(κ/ξ) 振荡, 因此步长不超过 ξ/2 与
+/// HYP1F1_TAYLOR_STEP/(1 + √(|κ|/ξ)), 使 Taylor 级数相消很少. 振荡区域中两个解量级相当, 其外 M 为主导解
+/// (除 a 为负整数外), 误差不会放大, 但每个振荡周期都累积舍入误差, 相对误差约为 √(κx) 个机器精度.
+/// 起点处的 M 与 M' = (a/b) M(a + 1, b + 1, x_0) 由幂级数给出, 递推中按 2 的幂缩放
+fn hyp1f1_taylor_scaled(a: f64, b: f64, x: f64) -> f64 {
+    let kappa = (0.5 * b - a).abs();
+    let mut xi = (0.25 * b.abs().max(1.0) / (1.0 - a)).min(x);
+    let mut value = hyp1f1_series(a, b, xi).0;
+    let mut derivative = hyp1f1_series(a + 1.0, b + 1.0, xi).0 * (a / b);
+    let mut exponent = 0;
+    while xi < x {
+        let step = (0.5 * xi).min(HYP1F1_TAYLOR_STEP / (1.0 + (kappa / xi).sqrt()));
+        let h = step.min(x - xi);
+        // g_k = f_k h^k
+        let (mut g0, mut g1) = (value, derivative * h);
+        let mut sum = g0 + g1;
+        let mut derivative_sum = g1;
+        for k in 0..HYP_MAX_TERMS {
+            let k = k as f64;
+            let g2 = (g0 * h * (k + a) - g1 * (k + 1.0) * (k + b - xi)) * h
+                / (xi * ((k + 1.0) * (k + 2.0)));
+            sum += g2;
+            derivative_sum += g2 * (k + 2.0);
+            if g2.abs().max(g1.abs()) <= 0.5 * f64::EPSILON * sum.abs() {
+                break;
+            }
+            (g0, g1) = (g1, g2);
+        }
+        value = sum;
+        derivative = derivative_sum / h;
+        xi += h;
+        if value.abs().max(derivative.abs()) > 2.0f64.powi(HYP1F1_RESCALE_EXPONENT) {
+            value *= 2.0f64.powi(-HYP1F1_RESCALE_EXPONENT);
+            derivative *= 2.0f64.powi(-HYP1F1_RESCALE_EXPONENT);
+            exponent += HYP1F1_RESCALE_EXPONENT;
+        }
+    }
+    if value == 0.0 {
+        return 0.0;
+    }
+    value.signum() * (exponent as f64).mul_add(LN_2, value.abs().ln() - x).exp()
+}
+
+/// x > 0 时的 ₁F₁(a; b; x), 返回值乘以 e^(-x). x 较大时使用渐近展开; 否则使用幂级数.
+/// a < 0, a < b/2 且级数相消严重时改用 a 的递推: b > 0 且 x <= b 或 a 为负整数时向下递推, 否则由 Bessel
+/// 函数展开向上递推. x 较大时 Bessel 函数展开本身也严重相消, 此时改为逐步求解 Kummer 方程
+fn hyp1f1_positive_scaled(a: f64, b: f64, x: f64) -> f64 {
+    if x > HYP1F1_ASYMPTOTIC_MIN + a.abs() + b.abs() {
+        if let Some(value) = hyp1f1_asymptotic_scaled(a, b, x) {
+            return value;
+        }
+    }
+    let (series, loss) = hyp1f1_series(a, b, x);
+    if loss <= HYP1F1_SERIES_MAX_LOSS || a >= 0.0 || a >= 0.5 * b {
+        return series * (-x).exp();
+    }
+    if 0.0 < b && (x <= b || a == a.floor()) {
+        return hyp1f1_recurrence_down_scaled(a, b, x);
+    }
+    let (bessel, bessel_loss) = hyp1f1_bessel_recurrence_scaled(a, b, x);
+    if bessel_loss <= HYP1F1_BESSEL_MAX_LOSS {
+        bessel
+    } else {
+        hyp1f1_taylor_scaled(a, b, x)
+    }
+}
+
+/// 计算合流超几何函数 (Kummer 函数) M(a, b, x) = ₁F₁(a; b; x) = Σ_(k>=0) (a)_k / (b)_k x^k / k!
+///
+/// x < 0 时使用 Kummer 变换 M(a, b, x) = e^x M(b - a, b, -x) 化为 x > 0 (a 为非正整数时多项式各项同号, 直接求和),
+/// 并在 e^(-x) 缩放下计算, 以免 e^x 与 M 分别上溢或下溢. x 较大时使用渐近展开; a >= 0 时级数各项同号, 直接求和;
+/// a < 0 时级数可能严重相消, 改用参数 a 的三项递推, 起点由级数或 Bessel 函数展开给出, 包括 a 为负整数的
+/// (广义 Laguerre) 多项式情形; |a| 与 x 都较大时逐步求解 Kummer 方程. b 为非正整数且级数不提前终止时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::hypergeometric::hyp1f1;
+/// // M(a, a, x) = e^x
+/// assert!((hyp1f1(2.5, 2.5, 1.0) - std::f64::consts::E).abs() < 1.0e-15);
+/// ```
+pub fn hyp1f1(a: f64, b: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    if is_nonpositive_integer(b) && !(is_nonpositive_integer(a) && a >= b) {
+        return f64::NAN;
+    }
+    if a == 0.0 || x == 0.0 {
+        return 1.0;
+    }
+    if a == b {
+        return x.exp();
+    }
+    // b 为非正整数时只剩多项式情形; a 为非正整数且 x < 0 时级数各项同号
+    if is_nonpositive_integer(b) || is_nonpositive_integer(a) && x < 0.0 {
+        return hyp1f1_series(a, b, x).0;
+    }
+    if x < 0.0 {
+        return hyp1f1_positive_scaled(b - a, b, -x);
+    }
+    let scaled = hyp1f1_positive_scaled(a, b, x);
+    // e^x 拆为两个因子, 避免结果有限时 e^x 提前上溢
+    let half = (0.5 * x).exp();
+    scaled * half * half
+}
+
+/// 由 Miller 算法计算 (x^a U(a, b, x), U(a + 1, b, x)/U(a, b, x)), 要求 a > 0, a - b + 1 > 0, x > 0.
+/// U(a + n, b, x) 是 a 的三项递推 (DLMF 13.3.7) U(a - 1) + (b - 2a - x) U(a) + a(a - b + 1) U(a + 1) = 0
+/// 在 n → ∞ 时的最小解, 从 n = N 向下递推比值, 并由 Σ_(n>=0) (a)_n (a - b + 1)_n / n! U(a + n, b, x) = x^(-a)
+/// 归一化 (Temme). 参数条件保证归一化级数各项同号. 加倍 N 直到结果收敛
+fn hypu_miller(a: f64, b: f64, x: f64) -> (f64, f64) {
+    let miller = |order: usize| {
+        // ratio = U(a + n + 1)/U(a + n), sum = Σ_(k>=n) c_k U(a + k) / (c_n U(a + n))
+        let mut ratio = 0.0;
+        let mut sum = 1.0;
+        for n in (1..=order).rev() {
+            let c = a + n as f64;
+            ratio = 1.0 / (2.0 * c + x - b - c * (c - b + 1.0) * ratio);
+            sum = 1.0 + ratio * (c - 1.0) * (c - b) / n as f64 * sum;
+        }
+        (1.0 / sum, ratio)
+    };
+    let mut order = HYPU_MILLER_MIN_ORDER;
+    let (mut previous, _) = miller(order);
+    loop {
+        order *= 2;
+        let (value, ratio) = miller(order);
+        if (value - previous).abs() <= f64::EPSILON * value.abs() || order >= HYPU_MILLER_MAX_ORDER
+        {
+            return (value, ratio);
+        }
+        previous = value;
+    }
+}
+
+/// 由 Miller 算法计算 x^a U(a, b, x): 由 Kummer 变换 U(a, b, x) = x^(1-b) U(a - b + 1, 2 - b, x)
+/// 使 b <= 1, 取整数 m 使 a + m > 0, 求出 a + m 与 a + m + 1 处的值后向下递推到 a. 两种形式中 x^a U(a, b, x)
+/// 的值相同. 连接公式严重相消时 U 沿 a 减小的方向通常为主导解, 向下递推稳定; U 由连接公式中的一部分
+/// 主导时则不然, 此时应使用连接公式 (见 `hypu_connection`)
+fn hypu_recurrence_scaled(a: f64, b: f64, x: f64) -> f64 {
+    let (a, b) = if b > 1.0 {
+        (a - b + 1.0, 2.0 - b)
+    } else {
+        (a, b)
+    };
+    let shift = if a > 0.0 { 0.0 } else { (-a).floor() + 1.0 };
+    let (value, ratio) = hypu_miller(a + shift, b, x);
+    // V(c) = x^c U(c, b, x), V(c - 1) = [(2c + x - b) V(c) - c(c - b + 1) V(c + 1)/x] / x
+    let (mut current, mut next) = (value, ratio * value * x);
+    for k in 0..shift as usize {
+        let c = a + shift - k as f64;
+        let previous = ((2.0 * c + x - b) * current - c * (c - b + 1.0) * next / x) / x;
+        (current, next) = (previous, current);
+    }
+    current
+}
+
+/// b = n + 1 为正整数时 U(a, b, x) 的级数 (DLMF 13.2.9):
+/// U(a, n + 1, x) = (-1)^(n+1) / (n! Γ(a - n)) Σ_(k>=0) (a)_k / ((n + 1)_k k!) x^k [ln x + ψ(a + k) - ψ(1 + k) - ψ(n + k + 1)]
+/// + 1/Γ(a) Σ_(k=1)^n (k - 1)! (1 - a + k)_(n-k) / (n - k)! x^(-k). 同时返回各项绝对值之和与结果之比
+fn hypu_integer_b(a: f64, n: u32, x: f64) -> (f64, f64) {
+    let ln_x = x.ln();
+    let n_f = n as f64;
+    let mut psi_a = digamma(a);
+    let mut psi_1 = -EULER;
+    let mut psi_n = harmonic(n as u64) - EULER;
+    let mut term = 1.0;
+    let mut sum = 0.0;
+    let mut magnitude = 0.0;
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        let value = term * (ln_x + psi_a - psi_1 - psi_n);
+        sum += value;
+        magnitude += value.abs();
+        if value.abs() <= 0.5 * f64::EPSILON * sum.abs() && k > -a {
+            break;
+        }
+        term *= (a + k) / ((n_f + 1.0 + k) * (k + 1.0)) * x;
+        psi_a += 1.0 / (a + k);
+        psi_1 += 1.0 / (k + 1.0);
+        psi_n += 1.0 / (n_f + k + 1.0);
+    }
+    let factorial: f64 = (1..=n).map(|i| i as f64).product();
+    let sign = if n.is_multiple_of(2) { -1.0 } else { 1.0 };
+    let scale = sign * rgamma(a - n_f) / factorial;
+    let mut finite = 0.0;
+    let mut finite_magnitude = 0.0;
+    // (k - 1)! / (n - k)! 由 k = 1 处的 1/(n - 1)! 递推
+    let mut ratio = n_f / factorial;
+    for k in 1..=n {
+        let k_f = k as f64;
+        if k > 1 {
+            ratio *= (k_f - 1.0) * (n_f - k_f + 1.0);
+        }
+        let value = ratio * rising_factorial(1.0 - a + k_f, (n - k) as i32) * x.powi(-(k as i32));
+        finite += value;
+        finite_magnitude += value.abs();
+    }
+    let value = scale * sum + rgamma(a) * finite;
+    let magnitude = scale.abs() * magnitude + rgamma(a).abs() * finite_magnitude;
+    (value, magnitude / value.abs())
+}
+
+/// 由 ₁F₁ 表示的连接公式 (DLMF 13.2.42)
+/// U(a, b, x) = Γ(1 - b)/Γ(a - b + 1) M(a, b, x) + Γ(b - 1)/Γ(a) x^(1-b) M(a - b + 1, 2 - b, x);
+/// b 为整数时使用含 ln x 的极限形式, b <= 0 时先由 Kummer 变换化为 2 - b >= 2.
+/// 同时返回两部分绝对值之和与结果之比, x 较大时两部分严重相消
+fn hypu_connection(a: f64, b: f64, x: f64) -> (f64, f64) {
+    if b == b.floor() {
+        return if b >= 1.0 {
+            hypu_integer_b(a, (b - 1.0) as u32, x)
+        } else {
+            let (value, loss) = hypu_integer_b(a - b + 1.0, (1.0 - b) as u32, x);
+            (x.powf(1.0 - b) * value, loss)
+        };
+    }
+    let first = gamma_ratio(1.0 - b, a - b + 1.0) * hyp1f1(a, b, x);
+    let second = gamma_ratio(b - 1.0, a) * x.powf(1.0 - b) * hyp1f1(a - b + 1.0, 2.0 - b, x);
+    let value = first + second;
+    (value, (first.abs() + second.abs()) / value.abs())
+}
+
+/// a 为非正整数时的多项式 U(a, b, x) = (-1)^m (b)_m M(-m, b, x), m = -a. b 为非正整数时 (b)_m 可能为零,
+/// 若 a - b + 1 也为非正整数则由 Kummer 变换化为 2 - b >= 2, 否则使用 (DLMF 13.2.7)
+/// U(-m, b, x) = (-1)^m Σ_(s=0)^m C(m, s) (b + s)_(m-s) (-x)^s, 此时各项同号
+fn hypu_polynomial(a: f64, b: f64, x: f64) -> f64 {
+    let m = -a;
+    let sign = if (m as u64).is_multiple_of(2) {
+        1.0
+    } else {
+        -1.0
+    };
+    if !is_nonpositive_integer(b) {
+        return sign * rising_factorial(b, m as i32) * hyp1f1(a, b, x);
+    }
+    if is_nonpositive_integer(a - b + 1.0) {
+        return x.powf(1.0 - b) * hypu_polynomial(a - b + 1.0, 2.0 - b, x);
+    }
+    if x == 0.0 {
+        return sign * rising_factorial(b, m as i32);
+    }
+    // 由 s = m 处的 x^m 开始, 相邻两项之比为 s (b + s - 1) / ((m - s + 1)(-x))
+    let mut term = x.powf(m);
+    let mut sum = term;
+    let mut s = m;
+    while s > 0.0 {
+        term *= s * (b + s - 1.0) / ((m - s + 1.0) * -x);
+        sum += term;
+        s -= 1.0;
+    }
+    sum
+}
+
+/// 计算 U(a, b, x), `scaled` 为真时返回 x^a U(a, b, x). x 较大时使用渐近展开 x^a U(a, b, x) ~ ₂F₀(a, a - b + 1; ; -1/x);
+/// 否则连接公式中相消不严重时使用连接公式, 相消严重时使用 Miller 算法与递推
+fn hypu_general(a: f64, b: f64, x: f64, scaled: bool) -> f64 {
+    if a.is_nan() || b.is_nan() || x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if a == 0.0 {
+        return 1.0;
+    }
+    if x == f64::INFINITY {
+        return if scaled {
+            1.0
+        } else if a > 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    let scale = |value: f64| if scaled { x.powf(a) * value } else { value };
+    if is_nonpositive_integer(a) {
+        return scale(hypu_polynomial(a, b, x));
+    }
+    if is_nonpositive_integer(a - b + 1.0) {
+        // U(a, b, x) = x^(1-b) U(a - b + 1, 2 - b, x), 后者为多项式
+        let polynomial = hypu_polynomial(a - b + 1.0, 2.0 - b, x);
+        return if scaled {
+            x.powf(a - b + 1.0) * polynomial
+        } else if x == 0.0 && b < 1.0 {
+            0.0
+        } else {
+            x.powf(1.0 - b) * polynomial
+        };
+    }
+    if x == 0.0 {
+        let value = if b < 1.0 {
+            gamma_ratio(1.0 - b, a - b + 1.0)
+        } else {
+            f64::INFINITY.copysign(rgamma(a))
+        };
+        return scale(value);
+    }
+    let unscale = |value: f64| if scaled { value } else { x.powf(-a) * value };
+    if x > HYP1F1_ASYMPTOTIC_MIN + a.abs() + b.abs() {
+        if let Some(value) = hyp2f0_asymptotic(a, a - b + 1.0, -x) {
+            return unscale(value);
+        }
+    }
+    let (value, loss) = hypu_connection(a, b, x);
+    if loss <= HYPU_CONNECTION_MAX_LOSS {
+        return scale(value);
+    }
+    unscale(hypu_recurrence_scaled(a, b, x))
+}
+
+/// 计算第二类合流超几何函数 (Tricomi 函数) U(a, b, x), 要求 x >= 0
+///
+/// U(a, b, x) 是 Kummer 方程 x y'' + (b - x) y' - a y = 0 在 x → +∞ 时满足 U ~ x^(-a) 的解.
+/// x 较小时使用由 ₁F₁ 表示的连接公式, b 为整数时使用含 ln x 的极限形式; 连接公式中两部分严重相消时
+/// 改用 Temme 的 Miller 算法及参数 a 的递推 (见 `hypu_scaled`); x 较大时使用渐近展开.
+/// a 或 a - b + 1 为非正整数时为多项式. x = 0 且 b >= 1 时 U 发散, 返回带符号的 ∞; x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::hypergeometric::hypu;
+/// // U(a, a + 1, x) = x^(-a)
+/// assert!((hypu(1.5, 2.5, 4.0) - 0.125).abs() < 1.0e-15);
+/// ```
+pub fn hypu(a: f64, b: f64, x: f64) -> f64 {
+    hypu_general(a, b, x, false)
+}
+
+/// 计算缩放的第二类合流超几何函数 x^a U(a, b, x), 要求 x >= 0
+///
+/// x → +∞ 时 x^a U(a, b, x) → 1, 缩放后不会因 x^(-a) 上溢或下溢. Miller 算法中先由 Kummer 变换
+/// U(a, b, x) = x^(1-b) U(a - b + 1, 2 - b, x) 使 b <= 1 (两种形式的 x^a U(a, b, x) 相同), 将 a 平移为正,
+/// 由 U(a + n, b, x) 的三项递推向下求出比值, 以 Σ_(n>=0) (a)_n (a - b + 1)_n / n! U(a + n, b, x) = x^(-a)
+/// 归一化, 最后沿 a 减小的方向递推回 a. x = 0 时返回 0^a U(a, b, 0), x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::hypergeometric::hypu_scaled;
+/// assert!((hypu_scaled(1.5, 2.5, 4.0) - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn hypu_scaled(a: f64, b: f64, x: f64) -> f64 {
+    hypu_general(a, b, x, true)
+}
+
+/// 计算 Γ(p_1) Γ(p_2) ⋯ / (Γ(q_1) Γ(q_2) ⋯), 分子不含极点. 直接计算上溢或下溢时在对数尺度下计算
+fn gamma_quotient(numerator: &[f64], denominator: &[f64]) -> f64 {
+    if denominator.iter().any(|&q| is_nonpositive_integer(q)) {
+        return 0.0;
+    }
+    let direct = numerator.iter().map(|&p| gamma(p)).product::<f64>()
+        * denominator.iter().map(|&q| rgamma(q)).product::<f64>();
+    if direct.is_normal() {
+        return direct;
+    }
+    let mut ln = 0.0;
+    let mut sign = 1;
+    for &p in numerator {
+        let (value, s) = ln_gamma_sign(p);
+        ln += value;
+        sign *= s;
+    }
+    for &q in denominator {
+        let (value, s) = ln_gamma_sign(q);
+        ln -= value;
+        sign *= s;
+    }
+    sign as f64 * ln.exp()
+}
+
+/// 计算 (ln |Γ(x + ε)/Γ(x)|, sgn(Γ(x + ε)/Γ(x))), 要求 |ε| < 1, x 与 x + ε 均不是极点.
+/// x 或 x + ε 非正时由反射公式化为 Γ(1 - x)/Γ(1 - x - ε) 与 sin(πx)/sin(π(x + ε)), ε 很小时也不损失相对精度
+fn ln_gamma_shift(x: f64, eps: f64) -> (f64, f64) {
+    if x > 0.0 && x + eps > 0.0 {
+        return (ln_gamma_diff_positive(x, eps), 1.0);
+    }
+    // sin(π(x + ε))/sin(πx) = cos(πε) + cot(πx) sin(πε)
+    let half = sinpi(0.5 * eps);
+    let t = cospi(x) / sinpi(x) * sinpi(eps) - 2.0 * half * half;
+    let ln_gamma = ln_gamma_diff_positive(1.0 - x - eps, eps);
+    if t > -1.0 {
+        (ln_gamma - t.ln_1p(), 1.0)
+    } else {
+        (ln_gamma - (1.0 + t).abs().ln(), -1.0)
+    }
+}
+
+/// 直接求和 ₂F₁(a, b; c; x) = Σ_(k>=0) (a)_k (b)_k / (c)_k x^k / k!, 同时返回最大项与和之比.
+/// 参数为负时各项可能先减小再增大, 因此至少求和到 k = max(-a, -b, -c)
+fn hyp2f1_series(a: f64, b: f64, c: f64, x: f64) -> (f64, f64) {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut max_term = 1.0f64;
+    let min_parameter = a.min(b).min(c);
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        term *= (a + k) * (b + k) / ((c + k) * (k + 1.0)) * x;
+        sum += term;
+        max_term = max_term.max(term.abs());
+        if term == 0.0 || term.abs() <= 0.5 * f64::EPSILON * sum.abs() && k + 1.0 > -min_parameter {
+            break;
+        }
+    }
+    (sum, max_term / sum.abs())
+}
+
+/// α = c - 1 与 β = b - c + a 都大于 -1 时, a = -n 为非正整数的多项式 ₂F₁(a, b; c; x) 是经典的 Jacobi 多项式
+/// ₂F₁(-k, k + α + β + 1; α + 1; x) = k!/(α + 1)_k P_k^(α,β)(1 - 2x) 在 k = n 处的值
+fn is_jacobi_polynomial(a: f64, b: f64, c: f64) -> bool {
+    c > 0.0 && b - c + a > -1.0
+}
+
+/// 由 Jacobi 多项式关于次数的三项递推计算 `is_jacobi_polynomial` 情形的 F_n = ₂F₁(-n, b; c; x). 令 s = α + β,
+/// t = 1 - 2x, 则 2(k + s)(2k + s - 2)(k + α) F_k
+/// = (2k + s - 1)[(2k + s)(2k + s - 2)t + (α - β)s] F_(k-1) - 2(k - 1)(k + β - 1)(2k + s) F_(k-2),
+/// 从 F_0 = 1, F_1 = 1 - (s + 2)x/(α + 1) 递推. 0 <= x <= 1 时两个解量级相当, 其外多项式为主导解, 递推都是稳定的,
+/// 而直接求和在 x 接近 1 或次数较高时相消严重
+fn hyp2f1_jacobi<T>(a: f64, b: f64, c: f64, x: T) -> T
+where
+    T: Copy
+        + From<f64>
+        + Add<Output = T>
+        + Add<f64, Output = T>
+        + Mul<Output = T>
+        + Mul<f64, Output = T>,
+{
+    let alpha = c - 1.0;
+    let beta = b - c + a;
+    let s = alpha + beta;
+    let t = x * -2.0 + 1.0;
+    let (mut previous, mut current) = (T::from(1.0), x * (-(s + 2.0) / (alpha + 1.0)) + 1.0);
+    for k in 2..=(-a) as usize {
+        let k = k as f64;
+        let u = 2.0 * k + s;
+        let next = ((t * (u * (u - 2.0)) + (alpha - beta) * s) * current * (u - 1.0)
+            + previous * (-2.0 * (k - 1.0) * (k + beta - 1.0) * u))
+            * (0.5 / ((k + s) * (u - 2.0) * (k + alpha)));
+        (previous, current) = (current, next);
+    }
+    current
+}
+
+/// 由 a 的三项递推 (DLMF 15.5.11) (c - a) F(a - 1) + [2a - c + (b - a)x] F(a) + a(x - 1) F(a + 1) = 0
+/// 从 F(0) = 1, F(-1) = 1 - bx/c 递推多项式 ₂F₁(a, b; c; x), 要求 a 为负整数. 用于 c > 0 但参数不对应经典
+/// Jacobi 多项式的情形, 此时 Jacobi 多项式的递推可能不稳定, 而此递推在 c > 0 时仍很少损失精度
+fn hyp2f1_degree_recurrence<T>(a: f64, b: f64, c: f64, x: T) -> T
+where
+    T: Copy
+        + From<f64>
+        + Add<Output = T>
+        + Add<f64, Output = T>
+        + Mul<Output = T>
+        + Mul<f64, Output = T>,
+{
+    let (mut previous, mut current) = (T::from(1.0), x * (-b / c) + 1.0);
+    for k in 1..(-a) as usize {
+        let k = -(k as f64);
+        let next = ((x * (b - k) + (2.0 * k - c)) * current + (x + -1.0) * previous * k)
+            * (-1.0 / (c - k));
+        (previous, current) = (current, next);
+    }
+    current
+}
+
+/// a 为非正整数时的多项式 ₂F₁(a, b; c; x). 直接求和相消严重时, 参数对应经典 Jacobi 多项式则改用其关于次数的递推,
+/// 其余 c > 0 的情形改用 a 的递推; c < 0 且 x < 1 时尝试 Pfaff 变换
+/// (1 - x)^(-a) ₂F₁(a, c - b; c; x/(x - 1)) 以及 DLMF 15.8.7
+/// ₂F₁(-m, b; c; x) = (c - b)_m/(c)_m ₂F₁(-m, b; b - c - m + 1; 1 - x) 作用于 x 或 x/(x - 1), 取相消最少的结果
+fn hyp2f1_polynomial(a: f64, b: f64, c: f64, x: f64) -> f64 {
+    let (mut value, mut loss) = hyp2f1_series(a, b, c, x);
+    if loss <= HYP2F1_SERIES_MAX_LOSS || !x.is_finite() {
+        return value;
+    }
+    if is_jacobi_polynomial(a, b, c) {
+        return hyp2f1_jacobi(a, b, c, x);
+    }
+    if c > 0.0 {
+        return hyp2f1_degree_recurrence(a, b, c, x);
+    }
+    if x >= 1.0 {
+        return value;
+    }
+    let m = -a as i32;
+    let mut candidates = Vec::with_capacity(3);
+    let reflection = |b: f64, y: f64, scale: f64| {
+        let d = b - c + a + 1.0;
+        if is_nonpositive_integer(d) && d > a {
+            return None;
+        }
+        let (value, loss) = hyp2f1_series(a, b, d, y);
+        Some((
+            scale * rising_factorial(c - b, m) / rising_factorial(c, m) * value,
+            loss,
+        ))
+    };
+    if x > 0.0 {
+        candidates.extend(reflection(b, 1.0 - x, 1.0));
+    } else {
+        let y = 1.0 / (1.0 - x);
+        let scale = (-a * (-x).ln_1p()).exp();
+        let (pfaff, pfaff_loss) = hyp2f1_series(a, c - b, c, -x * y);
+        candidates.push((scale * pfaff, pfaff_loss));
+        candidates.extend(reflection(c - b, y, scale));
+    }
+    for (candidate, candidate_loss) in candidates {
+        if candidate_loss < loss {
+            (value, loss) = (candidate, candidate_loss);
+        }
+    }
+    value
+}
+
+/// c - a - b = n + ε (n 为非负整数, |ε| 较小) 时 1 - x 变换的极限形式 (Michel 与 Stoitsov 的方法), y = 1 - x.
+/// 1 - x 变换 (DLMF 15.8.4) 的第一个级数中 k < n 的项有限, 其余各项与第二个级数的各项成对组合为
+/// Γ(c)/(Γ(a)Γ(b)) (-1)^n y^n πε/sin(πε) Σ_(j>=0) (A_j - B_j)/ε,
+/// A_j = Γ(a + n + j)Γ(b + n + j)/(Γ(a + n + ε)Γ(b + n + ε)) y^j/((n + j)! Γ(1 + j - ε)),
+/// B_j = (a + n + ε)_j (b + n + ε)_j y^(j+ε)/(Γ(n + 1 + j + ε) j!).
+/// (A_0 - B_0)/ε 由 ln Γ 的差值与 expm1 计算, 其后 (A_j - B_j)/ε 由 A_j 与 B_j 的递推比值之差 (含因子 ε) 递推,
+/// 不会因相消损失精度. ε = 0 时即为 DLMF 15.8.10 的含 ψ 的极限公式. 同时返回各项绝对值之和与结果之比
+fn hyp2f1_near_integer(a: f64, b: f64, c: f64, y: f64, n: f64, eps: f64) -> (f64, f64) {
+    let m = n + eps;
+    // 1 - x 变换第一个级数中 k < n 的部分
+    let (mut finite, mut finite_abs) = (0.0, 0.0);
+    if n > 0.0 {
+        let mut term = 1.0f64;
+        for k in 0..n as usize {
+            let k = k as f64;
+            finite += term;
+            finite_abs += term.abs();
+            term *= (a + k) * (b + k) / ((1.0 - m + k) * (k + 1.0)) * y;
+        }
+        let scale = gamma_quotient(&[c, m], &[c - a, c - b]);
+        finite *= scale;
+        finite_abs *= scale.abs();
+    }
+    let factorial = gamma(n + 1.0);
+    let ln_y = y.ln();
+    let mut difference = if eps == 0.0 {
+        (harmonic(n as u64) - 2.0 * EULER - digamma(a + n) - digamma(b + n) - ln_y) / factorial
+    } else {
+        // A_0 n! = ±e^(L_A), B_0 n! = e^(L_B)
+        let (ln_a, sign_a) = ln_gamma_shift(a + n, eps);
+        let (ln_b, sign_b) = ln_gamma_shift(b + n, eps);
+        let ln_a0 = -ln_a - ln_b - ln_gamma_diff_positive(1.0, -eps);
+        let ln_b0 = eps.mul_add(ln_y, -ln_gamma_diff_positive(n + 1.0, eps));
+        if sign_a * sign_b > 0.0 {
+            ln_b0.exp() * (ln_a0 - ln_b0).exp_m1() / (eps * factorial)
+        } else {
+            -(ln_a0.exp() + ln_b0.exp()) / (eps * factorial)
+        }
+    };
+    let mut b_term = (eps * ln_y).exp() * rgamma(n + 1.0 + eps);
+    let mut sum = difference;
+    let mut sum_abs = difference.abs();
+    let min_parameter = (a + n).min(b + n);
+    for j in 0..HYP_MAX_TERMS {
+        let j = j as f64;
+        let (alpha, beta) = (a + n + j, b + n + j);
+        let (big, small) = (n + 1.0 + j, 1.0 + j);
+        let ratio_a = y * alpha * beta / (big * (small - eps));
+        let ratio_b = y * (alpha + eps) * (beta + eps) / ((big + eps) * small);
+        // (ratio_a - ratio_b)/ε
+        let ratio_difference = y
+            * (eps.mul_add(
+                eps * big + (alpha + beta) * big - big * small,
+                alpha * beta * (small + big),
+            ) - (alpha + beta) * big * small)
+            / (big * (small - eps) * (big + eps) * small);
+        difference = ratio_a * difference + ratio_difference * b_term;
+        b_term *= ratio_b;
+        sum += difference;
+        sum_abs += difference.abs();
+        if difference == 0.0 && b_term == 0.0
+            || difference.abs() <= 0.5 * f64::EPSILON * sum.abs() && j + 1.0 > -min_parameter
+        {
+            break;
+        }
+    }
+    let sign = if (n as u64).is_multiple_of(2) {
+        1.0
+    } else {
+        -1.0
+    };
+    let sinc = if eps == 0.0 {
+        1.0
+    } else {
+        PI * eps / sinpi(eps)
+    };
+    let scale = gamma_quotient(&[c], &[a, b]) * sign * y.powf(n) * sinc;
+    let value = finite + scale * sum;
+    (value, (finite_abs + (scale * sum_abs).abs()) / value.abs())
+}
+
+/// 0 < x < 1 时 ₂F₁(a, b; c; x) 的 1 - x 变换 (DLMF 15.8.4), y = 1 - x 单独给出以免舍入误差:
+/// Γ(c)Γ(c - a - b)/(Γ(c - a)Γ(c - b)) ₂F₁(a, b; a + b - c + 1; y) +
+/// y^(c-a-b) Γ(c)Γ(a + b - c)/(Γ(a)Γ(b)) ₂F₁(c - a, c - b; c - a - b + 1; y).
+/// c - a - b < 0 时先作 Euler 变换; c - a - b 接近整数时两部分分别发散, 改用极限形式. 同时返回相消损失的估计
+fn hyp2f1_one_minus(a: f64, b: f64, c: f64, y: f64) -> (f64, f64) {
+    let m = c - a - b;
+    let n = m.round();
+    if n < 0.0 {
+        let (value, loss) = hyp2f1_one_minus(c - a, c - b, c, y);
+        return (y.powf(m) * value, loss);
+    }
+    let eps = m - n;
+    if eps.abs() < HYP2F1_LIMIT_MAX {
+        return hyp2f1_near_integer(a, b, c, y, n, eps);
+    }
+    let (first, first_loss) = hyp2f1_series(a, b, 1.0 - m, y);
+    let (second, second_loss) = hyp2f1_series(c - a, c - b, 1.0 + m, y);
+    let first = gamma_quotient(&[c, m], &[c - a, c - b]) * first;
+    let second = y.powf(m) * gamma_quotient(&[c, -m], &[a, b]) * second;
+    let value = first + second;
+    (
+        value,
+        (first.abs() * first_loss + second.abs() * second_loss) / value.abs(),
+    )
+}
+
+/// 0 <= x < 1 时的 ₂F₁(a, b; c; x), y = 1 - x, 同时返回相消损失的估计. x <= 1/2 时直接求和幂级数, 否则使用
+/// 1 - x 变换. 相消严重时再尝试 Euler 变换 (1 - x)^(c-a-b) ₂F₁(c - a, c - b; c; x) 的级数以及 x 不太接近 1 时的
+/// 幂级数, 取相消最少的结果
+fn hyp2f1_unit(a: f64, b: f64, c: f64, x: f64, y: f64) -> (f64, f64) {
+    let mut best = if x <= 0.5 {
+        hyp2f1_series(a, b, c, x)
+    } else {
+        hyp2f1_one_minus(a, b, c, y)
+    };
+    if best.1 <= HYP2F1_SERIES_MAX_LOSS || x > HYP2F1_SERIES_MAX_X {
+        return best;
+    }
+    if x > 0.5 {
+        let series = hyp2f1_series(a, b, c, x);
+        if series.1 < best.1 {
+            best = series;
+        }
+    }
+    let (euler, euler_loss) = hyp2f1_series(c - a, c - b, c, x);
+    if euler_loss < best.1 {
+        best = (((c - a - b) * y.ln()).exp() * euler, euler_loss);
+    }
+    best
+}
+
+/// 计算 Gauss 超几何函数 ₂F₁(a, b; c; x) = Σ_(k>=0) (a)_k (b)_k / (c)_k x^k / k!, 要求 x <= 1
+///
+/// |x| <= 1/2 时直接求和幂级数; 1/2 < x < 1 时使用 1 - x 变换, c - a - b 接近整数时改用极限形式以免两部分
+/// 分别发散而严重相消; x < 0 时由 Pfaff 变换 ₂F₁(a, b; c; x) = (1 - x)^(-a) ₂F₁(a, c - b; c; x/(x - 1))
+/// 化为 (0, 1) 内的情形 (x < -1 时相当于 1/x 变换, 其极限情形对应 a - b 接近整数).
+/// x = 1 时 c - a - b > 0 则由 Gauss 求和公式给出 Γ(c)Γ(c - a - b)/(Γ(c - a)Γ(c - b)), 否则发散, 返回 ∞.
+/// a 或 b 为非正整数时为多项式, 对任意 x 有定义; c - a 或 c - b 为非正整数时由 Euler 变换化为多项式.
+/// c 为非正整数且级数不提前终止时, 或 x > 1 且不是多项式时, 返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::hypergeometric::hyp2f1;
+/// // ₂F₁(1, 1; 2; x) = -ln(1 - x)/x
+/// assert!((hyp2f1(1.0, 1.0, 2.0, 0.5) - 2.0 * std::f64::consts::LN_2).abs() < 1.0e-15);
+/// ```
+pub fn hyp2f1(a: f64, b: f64, c: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || c.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+    // 令 a 为非正整数时的较大者以便多项式取最低次数
+    let (a, b) = if is_nonpositive_integer(b) && !(is_nonpositive_integer(a) && a > b) {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    if is_nonpositive_integer(a) {
+        if is_nonpositive_integer(c) && c > a {
+            return f64::NAN;
+        }
+        return hyp2f1_polynomial(a, b, c, x);
+    }
+    if is_nonpositive_integer(c) || x > 1.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return 1.0;
+    }
+    let m = c - a - b;
+    if x == 1.0 {
+        return if m > 0.0 {
+            gamma_quotient(&[c, m], &[c - a, c - b])
+        } else {
+            f64::INFINITY
+        };
+    }
+    if a == c {
+        return (-b * (-x).ln_1p()).exp();
+    }
+    if b == c {
+        return (-a * (-x).ln_1p()).exp();
+    }
+    if x == f64::NEG_INFINITY {
+        return if a > 0.0 && b > 0.0 { 0.0 } else { f64::NAN };
+    }
+    let euler = (m * (-x).ln_1p()).exp();
+    if is_nonpositive_integer(c - a) && !(is_nonpositive_integer(c - b) && c - b > c - a) {
+        return euler * hyp2f1_polynomial(c - a, c - b, c, x);
+    }
+    if is_nonpositive_integer(c - b) {
+        return euler * hyp2f1_polynomial(c - b, c - a, c, x);
+    }
+    if x < 0.0 {
+        let y = 1.0 / (1.0 - x);
+        let ln_y = -(-x).ln_1p();
+        let (value, loss) = hyp2f1_unit(a, c - b, c, -x * y, y);
+        if loss > HYP2F1_SERIES_MAX_LOSS {
+            // 另一种 Pfaff 变换 (1 - x)^(-b) ₂F₁(b, c - a; c; x/(x - 1))
+            let (other, other_loss) = hyp2f1_unit(b, c - a, c, -x * y, y);
+            if other_loss < loss {
+                return (b * ln_y).exp() * other;
+            }
+        }
+        return (a * ln_y).exp() * value;
+    }
+    hyp2f1_unit(a, b, c, x, 1.0 - x).0
+}
+
+/// 复数 z 的 1 - z, 以实数减法计算, 保留割线上虚部零的符号
+fn one_minus_complex(z: Complex64) -> Complex64 {
+    Complex64::new(1.0 - z.re, -z.im)
+}
+
+/// 直接求和复数 z 处的 ₂F₁(a, b; c; z) 幂级数, 同时返回最大项与和之比
+fn hyp2f1_series_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let mut sum = Complex64::new(1.0, 0.0);
+    let mut term = sum;
+    let mut max_term = 1.0f64;
+    let min_parameter = a.min(b).min(c);
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        term *= z * ((a + k) * (b + k) / ((c + k) * (k + 1.0)));
+        sum += term;
+        max_term = max_term.max(term.norm());
+        if term.norm() == 0.0
+            || term.norm() <= 0.5 * f64::EPSILON * sum.norm() && k + 1.0 > -min_parameter
+        {
+            break;
+        }
+    }
+    (sum, max_term / sum.norm())
+}
+
+/// a 为非正整数时复数 z 处的多项式 ₂F₁(a, b; c; z), 与实数情形 `hyp2f1_polynomial` 相同地在直接求和相消严重时
+/// 使用关于次数的递推, 或在 Pfaff 变换与 DLMF 15.8.7 中取相消最少的结果
+fn hyp2f1_polynomial_complex(a: f64, b: f64, c: f64, z: Complex64) -> Complex64 {
+    let (mut value, mut loss) = hyp2f1_series_complex(a, b, c, z);
+    if loss <= HYP2F1_SERIES_MAX_LOSS || !(z.re.is_finite() && z.im.is_finite()) {
+        return value;
+    }
+    if is_jacobi_polynomial(a, b, c) {
+        return hyp2f1_jacobi(a, b, c, z);
+    }
+    if c > 0.0 {
+        return hyp2f1_degree_recurrence(a, b, c, z);
+    }
+    let m = -a as i32;
+    let reflection = |b: f64, y: Complex64, scale: Complex64| {
+        let d = b - c + a + 1.0;
+        if is_nonpositive_integer(d) && d > a {
+            return None;
+        }
+        let (value, loss) = hyp2f1_series_complex(a, b, d, y);
+        Some((
+            scale * (rising_factorial(c - b, m) / rising_factorial(c, m)) * value,
+            loss,
+        ))
+    };
+    let one = Complex64::new(1.0, 0.0);
+    let y = one_minus_complex(z);
+    let scale = y.powi(m);
+    let (pfaff, pfaff_loss) = hyp2f1_series_complex(a, c - b, c, -z / y);
+    let candidates = [
+        reflection(b, y, one),
+        Some((scale * pfaff, pfaff_loss)),
+        reflection(c - b, y.inv(), scale),
+    ];
+    for (candidate, candidate_loss) in candidates.into_iter().flatten() {
+        if candidate_loss < loss {
+            (value, loss) = (candidate, candidate_loss);
+        }
+    }
+    value
+}
+
+/// 直接求和, 相消严重时尝试 Euler 变换 (1 - z)^(c-a-b) ₂F₁(c - a, c - b; c; z), 同时返回相消损失的估计
+fn hyp2f1_direct_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let series = hyp2f1_series_complex(a, b, c, z);
+    if series.1 <= HYP2F1_SERIES_MAX_LOSS {
+        return series;
+    }
+    let (euler, euler_loss) = hyp2f1_series_complex(c - a, c - b, c, z);
+    if euler_loss < series.1 {
+        (
+            ((c - a - b) * one_minus_complex(z).ln()).exp() * euler,
+            euler_loss,
+        )
+    } else {
+        series
+    }
+}
+
+/// Pfaff 变换 (1 - z)^(-a) ₂F₁(a, c - b; c; z/(z - 1)), 相消严重时尝试 a 与 b 互换的形式, 同时返回相消损失的估计
+fn hyp2f1_pfaff_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let y = one_minus_complex(z);
+    let ln_y = y.ln();
+    let w = -z / y;
+    let (value, loss) = hyp2f1_series_complex(a, c - b, c, w);
+    if loss > HYP2F1_SERIES_MAX_LOSS {
+        let (other, other_loss) = hyp2f1_series_complex(b, c - a, c, w);
+        if other_loss < loss {
+            return ((-b * ln_y).exp() * other, other_loss);
+        }
+    }
+    ((-a * ln_y).exp() * value, loss)
+}
+
+/// 1 - z 型与 1/z 型变换的公共形式 (不含前置因子)
+/// Γ(c)Γ(m)/(Γ(p + m)Γ(q₁)) ₂F₁(p, q; 1 - m; v) + Γ(c)Γ(-m)/(Γ(p)Γ(q₂)) e^(mΛ) ₂F₁(p + m, q + m; 1 + m; v).
+/// 1 - z 与 1/(1 - z) 变换 (DLMF 15.8.4, 15.8.3) 中 q₁ = q + m, q₂ = q, e^Λ = v; 1/z 与 1 - 1/z 变换
+/// (DLMF 15.8.2, 15.8.5) 中 q₁ = 1 - q, q₂ = 1 - q - m, e^Λ = -v (reflected). Λ 由调用者按主值分支给出.
+/// m 接近非负整数时改用极限形式 `hyp2f1_near_integer_complex`; reflected 且 q = -k 为非正整数时第一个级数为多项式,
+/// n > k 时第二项的系数 Γ(-m)/Γ(1 + k - m) = 1/(-m)_(k+1) 有限, 直接计算. 同时返回相消损失的估计
+fn hyp2f1_connection_complex(
+    c: f64,
+    p: f64,
+    q: f64,
+    m: f64,
+    v: Complex64,
+    lambda: Complex64,
+    reflected: bool,
+) -> (Complex64, f64) {
+    let (q1, q2) = if reflected {
+        (1.0 - q, 1.0 - q - m)
+    } else {
+        (q + m, q)
+    };
+    let n = m.round();
+    let eps = m - n;
+    let terminating = reflected && is_nonpositive_integer(q) && n + q > 0.0;
+    if !terminating && n >= 0.0 && eps.abs() < HYP2F1_LIMIT_MAX {
+        return hyp2f1_near_integer_complex(c, p, q, n, eps, v, lambda, reflected);
+    }
+    let second_coefficient = if terminating {
+        gamma_quotient(&[c], &[p]) / rising_factorial(-m, (1.0 - q) as i32)
+    } else {
+        gamma_quotient(&[c, -m], &[p, q2])
+    };
+    let (first, first_loss) = hyp2f1_series_complex(p, q, 1.0 - m, v);
+    let (second, second_loss) = hyp2f1_series_complex(p + m, q + m, 1.0 + m, v);
+    let first = first * gamma_quotient(&[c, m], &[p + m, q1]);
+    let second = second * (m * lambda).exp() * second_coefficient;
+    let value = first + second;
+    (
+        value,
+        (first.norm() * first_loss + second.norm() * second_loss) / value.norm(),
+    )
+}
+
+/// `hyp2f1_connection_complex` 在 m = n + ε (n 为非负整数, |ε| 较小) 时的极限形式, 推导与实数情形
+/// `hyp2f1_near_integer` 相同: 第一个级数中 k < n 的项有限, 其余各项与第二个级数的各项成对组合为
+/// Γ(c)/(Γ(p)Γ(q₂)) σ v^n πε/sin(πε) Σ_(j>=0) (A_j - B_j)/ε, B_j = (p + m)_j (q + m)_j e^(εΛ) v^j/(Γ(n + 1 + j + ε) j!).
+/// 1 - z 型中 σ = (-1)^n, A_0 = Γ(p + n)Γ(q + n)/(Γ(p + m)Γ(q + m) Γ(1 - ε) n!);
+/// 1/z 型中 σ = 1, 由 (q)_n Γ(1 - q - m)/Γ(1 - q) = (-1)^n Γ(1 - q - n - ε)/Γ(1 - q - n) 得
+/// A_0 = Γ(p + n)Γ(1 - q - n - ε)/(Γ(p + m)Γ(1 - q - n) Γ(1 - ε) n!), 不含 Γ(q), q 为整数时也成立.
+/// 两种情形中 A_j 与 B_j 的递推比值相同. 同时返回各项绝对值之和与结果之比
+#[allow(clippy::too_many_arguments)]
+fn hyp2f1_near_integer_complex(
+    c: f64,
+    p: f64,
+    q: f64,
+    n: f64,
+    eps: f64,
+    v: Complex64,
+    lambda: Complex64,
+    reflected: bool,
+) -> (Complex64, f64) {
+    let m = n + eps;
+    let (q1, q2) = if reflected {
+        (1.0 - q, 1.0 - q - m)
+    } else {
+        (q + m, q)
+    };
+    let (mut finite, mut finite_abs) = (Complex64::new(0.0, 0.0), 0.0);
+    if n > 0.0 {
+        let mut term = Complex64::new(1.0, 0.0);
+        for k in 0..n as usize {
+            let k = k as f64;
+            finite += term;
+            finite_abs += term.norm();
+            term *= v * ((p + k) * (q + k) / ((1.0 - m + k) * (k + 1.0)));
+        }
+        let scale = gamma_quotient(&[c, m], &[p + m, q1]);
+        finite *= scale;
+        finite_abs *= scale.abs();
+    }
+    let factorial = gamma(n + 1.0);
+    let mut difference = if eps == 0.0 {
+        let psi_q = if reflected {
+            digamma(1.0 - q - n)
+        } else {
+            digamma(q + n)
+        };
+        let psi = harmonic(n as u64) - 2.0 * EULER - digamma(p + n) - psi_q;
+        (Complex64::new(psi, 0.0) - lambda) / factorial
+    } else {
+        // A_0 n! = ±e^(L_A), B_0 n! = e^(L_B)
+        let (ln_p, sign_p) = ln_gamma_shift(p + n, eps);
+        let (ln_q, sign_q) = if reflected {
+            let (value, sign) = ln_gamma_shift(1.0 - q - n, -eps);
+            (-value, sign)
+        } else {
+            ln_gamma_shift(q + n, eps)
+        };
+        let ln_a0 = -ln_p - ln_q - ln_gamma_diff_positive(1.0, -eps);
+        let ln_b0 = lambda * eps - ln_gamma_diff_positive(n + 1.0, eps);
+        if sign_p * sign_q > 0.0 {
+            ln_b0.exp() * complex_exp_m1(Complex64::new(ln_a0, 0.0) - ln_b0) / (eps * factorial)
+        } else {
+            -(ln_b0.exp() + ln_a0.exp()) / (eps * factorial)
+        }
+    };
+    let mut b_term = (lambda * eps).exp() * rgamma(n + 1.0 + eps);
+    let mut sum = difference;
+    let mut sum_abs = difference.norm();
+    let min_parameter = (p + n).min(q + n);
+    for j in 0..HYP_MAX_TERMS {
+        let j = j as f64;
+        let (alpha, beta) = (p + n + j, q + n + j);
+        let (big, small) = (n + 1.0 + j, 1.0 + j);
+        let ratio_a = alpha * beta / (big * (small - eps));
+        let ratio_b = (alpha + eps) * (beta + eps) / ((big + eps) * small);
+        // (ratio_a - ratio_b)/ε
+        let ratio_difference = (eps.mul_add(
+            eps * big + (alpha + beta) * big - big * small,
+            alpha * beta * (small + big),
+        ) - (alpha + beta) * big * small)
+            / (big * (small - eps) * (big + eps) * small);
+        difference = v * (difference * ratio_a + b_term * ratio_difference);
+        b_term *= v * ratio_b;
+        sum += difference;
+        sum_abs += difference.norm();
+        if difference.norm() == 0.0 && b_term.norm() == 0.0
+            || difference.norm() <= 0.5 * f64::EPSILON * sum.norm() && j + 1.0 > -min_parameter
+        {
+            break;
+        }
+    }
+    let sign = if reflected || (n as u64).is_multiple_of(2) {
+        1.0
+    } else {
+        -1.0
+    };
+    let sinc = if eps == 0.0 {
+        1.0
+    } else {
+        PI * eps / sinpi(eps)
+    };
+    let scale = v.powi(n as i32) * (gamma_quotient(&[c], &[p, q2]) * sign * sinc);
+    let value = finite + scale * sum;
+    (value, (finite_abs + scale.norm() * sum_abs) / value.norm())
+}
+
+/// 1 - z 变换 (DLMF 15.8.4), c - a - b 的最近整数为负时先作 Euler 变换
+fn hyp2f1_one_minus_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let y = one_minus_complex(z);
+    let ln_y = y.ln();
+    let m = c - a - b;
+    if m.round() < 0.0 {
+        let (value, loss) = hyp2f1_one_minus_complex(c - a, c - b, c, z);
+        return ((m * ln_y).exp() * value, loss);
+    }
+    hyp2f1_connection_complex(c, a, b, m, y, ln_y, false)
+}
+
+/// 1/(1 - z) 变换 (DLMF 15.8.3), 交换 a 与 b 使 b - a 的最近整数非负
+fn hyp2f1_inverse_one_minus_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let (a, b) = if (b - a).round() < 0.0 {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    let y = one_minus_complex(z);
+    let ln_y = y.ln();
+    let (value, loss) = hyp2f1_connection_complex(c, a, c - b, b - a, y.inv(), -ln_y, false);
+    ((-a * ln_y).exp() * value, loss)
+}
+
+/// 1/z 变换 (DLMF 15.8.2), 交换 a 与 b 使 b - a 的最近整数非负
+fn hyp2f1_inverse_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let (a, b) = if (b - a).round() < 0.0 {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    let ln_minus_z = (-z).ln();
+    let (value, loss) =
+        hyp2f1_connection_complex(c, a, a - c + 1.0, b - a, z.inv(), -ln_minus_z, true);
+    ((-a * ln_minus_z).exp() * value, loss)
+}
+
+/// 1 - 1/z 变换 (DLMF 15.8.5, 第二项的 Pfaff 变换作用于 c - b), c - a - b 的最近整数为负时先作 Euler 变换
+fn hyp2f1_one_minus_inverse_complex(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let y = one_minus_complex(z);
+    let ln_y = y.ln();
+    let m = c - a - b;
+    if m.round() < 0.0 {
+        let (value, loss) = hyp2f1_one_minus_inverse_complex(c - a, c - b, c, z);
+        return ((m * ln_y).exp() * value, loss);
+    }
+    let ln_z = z.ln();
+    let (value, loss) = hyp2f1_connection_complex(c, a, a - c + 1.0, m, -y / z, ln_y - ln_z, true);
+    ((-a * ln_z).exp() * value, loss)
+}
+
+/// Bühring 在 z_0 = 1/2 处的展开 (Bühring 1987)
+/// ₂F₁(a, b; c; z) = Γ(c)Γ(b - a)/(Γ(b)Γ(c - a)) (z_0 - z)^(-a) Σ_(n>=0) d_n(a) (z - z_0)^(-n) + (a 与 b 互换),
+/// d_(-1) = 0, d_0 = 1, n(n + 2s - a - b) d_n(s) = (n + s - 1)[((a + b + 1)/2 - c) d_(n-1) + (n + s - 2)/4 d_(n-2)],
+/// 在 |z - 1/2| > 1/2 时收敛, 覆盖 e^(±iπ/3) 附近六个变换后的变量的模均接近 1 的区域.
+/// 两部分即超几何方程在 z = ∞ 附近的两个解, 要求 a - b 不是整数. 同时返回相消损失的估计
+fn hyp2f1_buhring(a: f64, b: f64, c: f64, z: Complex64) -> (Complex64, f64) {
+    let r = (z - 0.5).inv();
+    let ln_u = Complex64::new(0.5 - z.re, -z.im).ln();
+    let mut total = Complex64::new(0.0, 0.0);
+    let mut total_abs = 0.0;
+    for (s, other) in [(a, b), (b, a)] {
+        // e_n = d_n (z - z_0)^(-n)
+        let (mut previous, mut current) = (Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0));
+        let mut sum = current;
+        let mut max_term = 1.0f64;
+        let mut small = 0;
+        for n in 1..HYP_MAX_TERMS {
+            let n = n as f64;
+            let next = (current * (0.5 * (a + b + 1.0) - c)
+                + previous * r * (0.25 * (n + s - 2.0)))
+                * r
+                * ((n + s - 1.0) / (n * (n + 2.0 * s - a - b)));
+            sum += next;
+            max_term = max_term.max(next.norm());
+            (previous, current) = (current, next);
+            if next.norm() <= 0.5 * f64::EPSILON * sum.norm() {
+                small += 1;
+                if small == 2 {
+                    break;
+                }
+            } else {
+                small = 0;
+            }
+        }
+        let scale = (-s * ln_u).exp() * gamma_quotient(&[c, other - s], &[other, c - s]);
+        total += scale * sum;
+        total_abs += scale.norm() * max_term;
+    }
+    (total, total_abs / total.norm())
+}
+
+/// 沿射线由 z_0 = z/(2|z|) 出发逐步求解超几何方程 z(1 - z)F'' + [c - (a + b + 1)z]F' - abF = 0 得到 ₂F₁(a, b; c; z).
+/// 在 ζ 处展开 F(ζ + h) = Σ_k f_k h^k, 系数满足
+/// (k + 1)(k + 2)ζ(1 - ζ) f_(k+2) = (k + a)(k + b) f_k - (k + 1)[(1 - 2ζ)k + c - (a + b + 1)ζ] f_(k+1),
+/// 收敛半径为 min(|ζ|, |1 - ζ|), 每步步长不超过其 HYP2F1_TAYLOR_STEP 倍. 起点处的 F 与
+/// F' = (ab/c) ₂F₁(a + 1, b + 1; c + 1; z_0) 由幂级数给出
+fn hyp2f1_taylor_complex(a: f64, b: f64, c: f64, z: Complex64) -> Complex64 {
+    let mut zeta = z * (0.5 / z.norm());
+    let mut value = hyp2f1_series_complex(a, b, c, zeta).0;
+    let mut derivative = hyp2f1_series_complex(a + 1.0, b + 1.0, c + 1.0, zeta).0 * (a * b / c);
+    loop {
+        let remaining = z - zeta;
+        let step = HYP2F1_TAYLOR_STEP * zeta.norm().min(one_minus_complex(zeta).norm());
+        let last = remaining.norm() <= step;
+        let h = if last {
+            remaining
+        } else {
+            remaining * (step / remaining.norm())
+        };
+        let denominator = zeta * one_minus_complex(zeta);
+        // g_k = f_k h^k
+        let (mut g0, mut g1) = (value, derivative * h);
+        let mut sum = g0 + g1;
+        let mut derivative_sum = g1;
+        for k in 0..HYP_MAX_TERMS {
+            let k = k as f64;
+            let g2 = (g0 * h * ((k + a) * (k + b))
+                - g1 * ((1.0 - 2.0 * zeta) * k + c - (a + b + 1.0) * zeta) * (k + 1.0))
+                * h
+                / (denominator * ((k + 1.0) * (k + 2.0)));
+            sum += g2;
+            derivative_sum += g2 * (k + 2.0);
+            if g2.norm().max(g1.norm()) <= 0.5 * f64::EPSILON * sum.norm() {
+                break;
+            }
+            (g0, g1) = (g1, g2);
+        }
+        if last {
+            return sum;
+        }
+        value = sum;
+        derivative = derivative_sum / h;
+        zeta += h;
+    }
+}
+
+/// 一般情形的复数 ₂F₁: 在六个变换 z, z/(z - 1), 1 - z, 1/(1 - z), 1/z, 1 - 1/z 中按变换后变量的模从小到大尝试,
+/// 相消较少时即返回. 各变量的模均超过 HYP2F1_COMPLEX_SERIES_MAX 时 (e^(±iπ/3) 附近) 使用 Bühring 展开,
+/// a - b 接近整数或其相消严重时改为逐步求解超几何方程
+fn hyp2f1_general_complex(a: f64, b: f64, c: f64, z: Complex64) -> Complex64 {
+    type Transform = fn(f64, f64, f64, Complex64) -> (Complex64, f64);
+    let z_norm = z.norm();
+    let y_norm = one_minus_complex(z).norm();
+    let mut candidates: [(f64, Transform); 6] = [
+        (z_norm, hyp2f1_direct_complex),
+        (z_norm / y_norm, hyp2f1_pfaff_complex),
+        (y_norm, hyp2f1_one_minus_complex),
+        (1.0 / y_norm, hyp2f1_inverse_one_minus_complex),
+        (1.0 / z_norm, hyp2f1_inverse_complex),
+        (y_norm / z_norm, hyp2f1_one_minus_inverse_complex),
+    ];
+    candidates.sort_by(|x, y| x.0.total_cmp(&y.0));
+    let mut best = (Complex64::new(f64::NAN, f64::NAN), f64::INFINITY);
+    for (radius, transform) in candidates {
+        if radius > HYP2F1_COMPLEX_SERIES_MAX {
+            break;
+        }
+        let (value, loss) = transform(a, b, c, z);
+        if loss < best.1 {
+            best = (value, loss);
+        }
+        if best.1 <= HYP2F1_SERIES_MAX_LOSS {
+            return best.0;
+        }
+    }
+    let d = b - a;
+    if (d - d.round()).abs() >= HYP2F1_LIMIT_MAX {
+        let (value, loss) = hyp2f1_buhring(a, b, c, z);
+        if loss < best.1 {
+            best = (value, loss);
+        }
+    }
+    if best.1 <= HYP2F1_BUHRING_MAX_LOSS {
+        return best.0;
+    }
+    hyp2f1_taylor_complex(a, b, c, z)
+}
+
+/// 计算复数 z 处的 Gauss 超几何函数 ₂F₁(a, b; c; z), 参数 a, b, c 为实数, 取主值分支, 割线为 [1, +∞)
+///
+/// 在六个线性变换 z, z/(z - 1), 1 - z, 1/(1 - z), 1/z, 1 - 1/z 中选取变换后变量的模最小且相消较少者求和;
+/// 1 - z 型变换中 c - a - b、1/z 型变换中 a - b 接近整数时两部分分别发散, 与实数情形相同地改用极限形式.
+/// 六个变量的模在 z = e^(±iπ/3) 附近均接近 1, 此时使用 Bühring 在 z_0 = 1/2 处关于 1/(z - z_0) 的展开;
+/// a - b 接近整数时该展开也退化, 改为由 |z| = 1/2 处出发沿射线逐步求解超几何方程的 Taylor 级数.
+/// z 为小于 1 的实数时与 `hyp2f1` 相同; 割线上的点按虚部零的符号取值, z = x + 0i (x > 1) 为从上半平面趋近的极限.
+/// a 或 b 为非正整数时为多项式; c 为非正整数且级数不提前终止时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::hypergeometric::hyp2f1_complex;
+/// // ₂F₁(1, 1; 2; z) = -ln(1 - z)/z, z = 2 + 0i 处为 iπ/2
+/// let w = hyp2f1_complex(1.0, 1.0, 2.0, Complex64::new(2.0, 0.0));
+/// assert!((w - Complex64::new(0.0, std::f64::consts::FRAC_PI_2)).norm() < 1.0e-14);
+/// ```
+pub fn hyp2f1_complex(a: f64, b: f64, c: f64, z: Complex64) -> Complex64 {
+    let nan = Complex64::new(f64::NAN, f64::NAN);
+    if a.is_nan() || b.is_nan() || c.is_nan() || z.re.is_nan() || z.im.is_nan() {
+        return nan;
+    }
+    let (a, b) = if is_nonpositive_integer(b) && !(is_nonpositive_integer(a) && a > b) {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    if is_nonpositive_integer(a) {
+        if is_nonpositive_integer(c) && c > a {
+            return nan;
+        }
+        return hyp2f1_polynomial_complex(a, b, c, z);
+    }
+    if is_nonpositive_integer(c) {
+        return nan;
+    }
+    if z.im == 0.0 && z.re < 1.0 {
+        return Complex64::new(hyp2f1(a, b, c, z.re), 0.0);
+    }
+    let m = c - a - b;
+    if z == Complex64::new(1.0, 0.0) {
+        return Complex64::new(
+            if m > 0.0 {
+                gamma_quotient(&[c, m], &[c - a, c - b])
+            } else {
+                f64::INFINITY
+            },
+            0.0,
+        );
+    }
+    if z.re.is_infinite() || z.im.is_infinite() {
+        return if a > 0.0 && b > 0.0 {
+            Complex64::new(0.0, 0.0)
+        } else {
+            nan
+        };
+    }
+    let ln_y = one_minus_complex(z).ln();
+    if a == c {
+        return (-b * ln_y).exp();
+    }
+    if b == c {
+        return (-a * ln_y).exp();
+    }
+    let euler = (m * ln_y).exp();
+    if is_nonpositive_integer(c - a) && !(is_nonpositive_integer(c - b) && c - b > c - a) {
+        return euler * hyp2f1_polynomial_complex(c - a, c - b, c, z);
+    }
+    if is_nonpositive_integer(c - b) {
+        return euler * hyp2f1_polynomial_complex(c - b, c - a, c, z);
+    }
+    hyp2f1_general_complex(a, b, c, z)
+}
+
+/// 直接求和 ₀F₁(; b; x) = Σ_(k>=0) x^k / ((b)_k k!), 同时返回最大项与和之比.
+/// b < 0 时 (b)_k 在 k < -b 时变号, 至少求和到 k = -b
+fn hyp0f1_series(b: f64, x: f64) -> (f64, f64) {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut max_term = 1.0f64;
+    for k in 0..HYP_MAX_TERMS {
+        let k = k as f64;
+        term *= x / ((b + k) * (k + 1.0));
+        sum += term;
+        max_term = max_term.max(term.abs());
+        if term == 0.0 || term.abs() <= 0.5 * f64::EPSILON * sum.abs() && k + 1.0 > -b {
+            break;
+        }
+    }
+    (sum, max_term / sum.abs())
+}
+
+/// 计算合流超几何极限函数 ₀F₁(; b; x) = Σ_(k>=0) x^k / ((b)_k k!), 即 lim_(a→∞) ₁F₁(a; b; x/a)
+///
+/// |x| 较小且级数相消不严重时直接求和; 否则使用与 Bessel 函数的关系 (DLMF 10.16.9)
+/// ₀F₁(; b; x) = Γ(b) x^((1 - b)/2) I_(b-1)(2√x) (x > 0), ₀F₁(; b; x) = Γ(b) (-x)^((1 - b)/2) J_(b-1)(2√(-x)) (x < 0),
+/// 其中 Bessel 函数与 Γ(b) 在对数尺度下合并, 避免中间结果上溢或下溢. x → -∞ 时 ₀F₁ ~ |x|^((1 - 2b)/4) 振荡,
+/// 仅当 b > 1/2 时趋于 0. b 为非正整数时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::hypergeometric::hyp0f1;
+/// // ₀F₁(; 1/2; x^2/4) = cosh x
+/// assert!((hyp0f1(0.5, 0.25) - 1.0f64.cosh()).abs() < 1.0e-15);
+/// ```
+pub fn hyp0f1(b: f64, x: f64) -> f64 {
+    if b.is_nan() || x.is_nan() || is_nonpositive_integer(b) {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return 1.0;
+    }
+    if x.abs() <= HYP0F1_SERIES_MAX * b.abs().max(1.0) {
+        let (sum, loss) = hyp0f1_series(b, x);
+        if loss <= HYP0F1_SERIES_MAX_LOSS {
+            return sum;
+        }
+    }
+    if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+    if x == f64::NEG_INFINITY {
+        return if b > 0.5 { 0.0 } else { f64::NAN };
+    }
+    let (ln_gamma_b, sign) = ln_gamma_sign(b);
+    let z = 2.0 * x.abs().sqrt();
+    // ln|B|, B 为 Bessel 函数的值
+    let (ln_bessel, bessel_sign) = if x < 0.0 {
+        let value = bessel_jv(b - 1.0, z);
+        (value.abs().ln(), value.signum())
+    } else if b >= 1.0 {
+        (ln_bessel_iv(b - 1.0, z), 1.0)
+    } else {
+        let value = bessel_ive(b - 1.0, z);
+        (value.abs().ln() + z, value.signum())
+    };
+    if ln_bessel == f64::NEG_INFINITY {
+        return 0.0;
+    }
+    let ln_scale = (0.5 * (1.0 - b)).mul_add(x.abs().ln(), ln_gamma_b);
+    bessel_sign * sign as f64 * (ln_scale + ln_bessel).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+    use std::f64::consts::E;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn test_hyp1f1() {
+        let tol = 1.0e-14;
+        assert_eq!(hyp1f1(1.5, 2.5, 0.0), 1.0);
+        assert_eq!(hyp1f1(0.0, 2.5, 3.0), 1.0);
+        assert!(approx_eq(hyp1f1(2.5, 2.5, 1.0), E, tol));
+        assert!(approx_eq(hyp1f1(0.5, 1.5, 2.0), 2.3644538928052095, tol));
+        assert!(approx_eq(hyp1f1(2.3, 4.1, -7.5), 0.05261681161444853, tol));
+        assert!(approx_eq(
+            hyp1f1(1.2, -3.5, 4.0) / 12741.897870030129,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp1f1(10.0, 3.0, -40.0) / -5.091487960605437e-13,
+            1.0,
+            1.0e-13
+        ));
+        // 大 x 的渐近展开
+        assert!(approx_eq(
+            hyp1f1(1.5, 2.5, 700.0) / 2.171800895762503e301,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp1f1(-0.5, 3.0, -800.0) / 17.048120986105737,
+            1.0,
+            tol
+        ));
+        // a 为非正整数时为多项式
+        assert!(approx_eq(hyp1f1(-3.0, 2.0, 5.0), 0.7916666666666666, tol));
+        assert!(approx_eq(
+            hyp1f1(-20.0, 1.5, 30.0) / 29570.40917564079,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(hyp1f1(-3.0, -5.0, 2.0), 2.933333333333333, tol));
+        assert!(approx_eq(hyp1f1(-1.0, -2.0, 0.5), 1.25, tol));
+        // a < 0 时由递推计算
+        assert!(approx_eq(
+            hyp1f1(-7.5, 3.25, 25.0) / -294.98110596336323,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(-15.5, 20.0, 12.0) / -1.7331136935433865e-5,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(-30.25, 4.5, 60.0) / 16813817.465871938,
+            1.0,
+            1.0e-12
+        ));
+        // 大参数: b - a 为负整数时为 Laguerre 多项式, 否则逐步求解 Kummer 方程
+        assert!(approx_eq(
+            hyp1f1(500.0, 1.0, -500.0) / 6.999451348319588e-111,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(500.0, 1.0, -400.0) / -1.2184092017009078e-90,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(500.0, 1.0, -600.0) / 1.3319967786250316e-132,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(1000.0, 3.0, -1000.0) / -1.9485898778910806e-225,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(-499.0, 1.0, 500.0) / 9.824375441740925e106,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp1f1(500.5, 1.0, -500.0) / 5.135167209653901e-111,
+            1.0,
+            1.0e-11
+        ));
+        assert!(approx_eq(
+            hyp1f1(1000.0, 2.5, -900.0) / -3.1929248435001394e-202,
+            1.0,
+            1.0e-11
+        ));
+        assert!(hyp1f1(1.0, -2.0, 1.0).is_nan());
+        assert!(hyp1f1(f64::NAN, 1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_hypu() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(hypu(1.5, 2.5, 4.0), 0.125, tol));
+        assert_eq!(hypu(0.0, 2.5, 3.0), 1.0);
+        // U(1/2, 1/2, x^2) = √π e^(x^2) erfc(x)
+        assert!(approx_eq(hypu(0.5, 0.5, 4.0), 0.4526770499811746, tol));
+        // U(1, 1, x) = e^x E_1(x)
+        assert!(approx_eq(hypu(1.0, 1.0, 2.0), 0.3613286168882226, tol));
+        assert!(approx_eq(hypu(2.3, 0.7, 0.3), 0.30758106280874914, tol));
+        assert!(approx_eq(hypu(0.6, 3.0, 0.5), 5.098337074701798, tol));
+        assert!(approx_eq(hypu(2.5, 1.2, 5.0), 0.008085872854699958, tol));
+        assert!(approx_eq(
+            hypu(-3.7, 2.1, 4.0) / 29.649866304891298,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hypu(1.5, 3.2, 200.0) / 0.0003554060916595157,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hypu(-20.5, 15.0, 2.0) / -6.329503847510058e26,
+            1.0,
+            1.0e-13
+        ));
+        // a 或 a - b + 1 为非正整数时为多项式
+        assert!(approx_eq(hypu(-3.0, 2.0, 5.0) / -19.0, 1.0, tol));
+        assert!(approx_eq(hypu(2.0, 4.0, 1.0), 3.0, tol));
+        assert!(approx_eq(hypu(1.5, 0.5, 0.0), 1.772453850905516, tol));
+        assert_eq!(hypu(1.5, 2.5, 0.0), f64::INFINITY);
+        assert_eq!(hypu(-0.5, 2.5, 0.0), f64::NEG_INFINITY);
+        assert_eq!(hypu(1.5, 2.5, f64::INFINITY), 0.0);
+        assert!(hypu(1.5, 2.5, -1.0).is_nan());
+        assert!(hypu(f64::NAN, 2.5, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_hypu_scaled() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(hypu_scaled(1.5, 2.5, 4.0), 1.0, tol));
+        assert!(approx_eq(
+            hypu_scaled(2.3, 0.7, 0.3),
+            0.01929027767244686,
+            tol
+        ));
+        assert!(approx_eq(
+            hypu_scaled(-3.7, 2.1, 4.0),
+            0.1755499748169616,
+            tol
+        ));
+        assert!(approx_eq(
+            hypu_scaled(1.5, 3.2, 200.0),
+            1.0052402299498049,
+            tol
+        ));
+        assert!(approx_eq(
+            hypu_scaled(-20.5, 15.0, 2.0) / -4.268298236962038e20,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(hypu_scaled(-3.0, 2.0, 5.0), -0.152, tol));
+        assert!(approx_eq(hypu_scaled(2.5, 1.2, 1.0e300), 1.0, tol));
+        assert_eq!(hypu_scaled(2.5, 1.2, f64::INFINITY), 1.0);
+        assert!(hypu_scaled(1.5, 2.5, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_hyp2f1() {
+        let tol = 1.0e-14;
+        // ₂F₁(1, 1; 2; x) = -ln(1 - x)/x
+        assert!(approx_eq(
+            hyp2f1(1.0, 1.0, 2.0, 0.5),
+            1.3862943611198906,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(1.0, 1.0, 2.0, -0.5),
+            0.8109302162163288,
+            tol
+        ));
+        // ₂F₁(1/2, 1/2; 3/2; x^2) = arcsin(x)/x
+        assert!(approx_eq(hyp2f1(0.5, 0.5, 1.5, 0.25), PI / 3.0, tol));
+        assert!(approx_eq(
+            hyp2f1(0.25, 0.75, 1.0, 0.9),
+            1.468223828302127,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-0.5, -0.5, 1.0, 0.95),
+            1.2576618364910905,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(2.0, 3.0, 4.0, 0.99) / 280.6753164581961,
+            1.0,
+            tol
+        ));
+        // c - a - b 为整数或接近整数
+        assert!(approx_eq(
+            hyp2f1(1.5, 2.5, 4.0, 0.999999999999) / 131.0772878929811,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(2.0, 2.0, 4.000000001, 0.8),
+            3.8827206417484597,
+            tol
+        ));
+        // x < -1, a - b 接近整数
+        assert!(approx_eq(
+            hyp2f1(2.0, 2.0, 4.000000001, -40.0),
+            0.007122190016662279,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(3.0, 2.999999999999, 2.0, -1.0e6) / -4.999970000154083e-19,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(1.0, 2.0, 3.5, -1.0e10) / 2.4999999915954455e-10,
+            1.0,
+            tol
+        ));
+        // Gauss 求和公式
+        assert!(approx_eq(
+            hyp2f1(0.3, 0.4, 2.0, 1.0),
+            1.1054192265872007,
+            tol
+        ));
+        assert_eq!(hyp2f1(1.0, 2.0, 3.0, 1.0), f64::INFINITY);
+        // 多项式
+        assert!(approx_eq(
+            hyp2f1(-3.0, 2.0, 1.5, 5.0) / -127.57142857142857,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(hyp2f1(-3.0, 2.0, -4.0, 0.3), 1.612, tol));
+        // 高次多项式由 Jacobi 多项式或 a 的递推计算
+        assert!(approx_eq(
+            hyp2f1(-30.0, 31.0, 1.0, 0.5) / -0.14446444809436798,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-50.0, 51.0, 1.0, 0.3) / 0.04156903338182536,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-100.0, 101.5, 1.0, 0.25) / -0.045979202011187215,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-50.0, 60.0, 3.0, 0.7) / -0.0032821736734585686,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-20.0, 25.0, 3.0, 0.5) / 0.002669652303059896,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp2f1(-40.0, 45.0, 10.0, 0.2) / 8.950301452487576e-8,
+            1.0,
+            tol
+        ));
+        // ₂F₁(a, b; a; x) = (1 - x)^(-b)
+        assert!(approx_eq(hyp2f1(2.5, 1.0, 2.5, 0.7), 1.0 / 0.3, tol));
+        assert_eq!(hyp2f1(1.5, 2.5, 4.0, 0.0), 1.0);
+        assert_eq!(hyp2f1(2.0, 3.0, 5.0, f64::NEG_INFINITY), 0.0);
+        assert!(hyp2f1(1.0, 1.0, -2.0, 0.5).is_nan());
+        assert!(hyp2f1(1.0, 1.0, 2.0, 1.5).is_nan());
+        assert!(hyp2f1(f64::NAN, 1.0, 2.0, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_hyp2f1_complex() {
+        let tol = 1.0e-14;
+        assert!(
+            (hyp2f1_complex(0.25, 0.75, 1.5, c(0.3, 0.4))
+                - c(1.0289357081813033, 0.06323818973682803))
+            .norm()
+                < tol
+        );
+        assert!(
+            (hyp2f1_complex(1.5, -0.5, 2.5, c(-3.0, 2.0))
+                - c(1.6903588330943846, -0.33735592684601745))
+            .norm()
+                < tol
+        );
+        assert!(
+            (hyp2f1_complex(1.0, 2.0, 3.0, c(-2.0, 0.5))
+                - c(0.442744192228387, 0.0575428638096074))
+            .norm()
+                < tol
+        );
+        assert!(
+            (hyp2f1_complex(1.2, 3.2, 1.5, c(10.0, -20.0))
+                - c(-0.0022300339500929056, -0.001768508662043435))
+            .norm()
+                < tol
+        );
+        // c - a - b 接近整数
+        assert!(
+            (hyp2f1_complex(2.0, 2.0, 4.000000001, c(0.7, -0.5))
+                - c(1.1250499862937517, -1.4700864644683778))
+            .norm()
+                < tol
+        );
+        // z = e^(iπ/3) 附近六个变换的变量的模均为 1
+        let z = c(0.5, 0.8660254037844386);
+        assert!(
+            (hyp2f1_complex(2.1, 2.5, 5.5, z) - c(0.6296662114888522, 0.9780031762161059)).norm()
+                < tol
+        );
+        assert!(
+            (hyp2f1_complex(1.0, 1.0, 2.0, z) - c(0.9068996821171089, 0.5235987755982988)).norm()
+                < tol
+        );
+        // 割线两侧: ₂F₁(1, 1; 2; 2 ± i0) = ±iπ/2, ₂F₁(1/2, 1/2; 1; 2 ± i0) = K(2)/(π/2)
+        assert!((hyp2f1_complex(1.0, 1.0, 2.0, c(2.0, 0.0)) - c(0.0, PI / 2.0)).norm() < tol);
+        assert!((hyp2f1_complex(1.0, 1.0, 2.0, c(2.0, -0.0)) - c(0.0, -PI / 2.0)).norm() < tol);
+        assert!(
+            (hyp2f1_complex(0.5, 0.5, 1.0, c(2.0, 0.0))
+                - c(0.8346268416740732, 0.8346268416740732))
+            .norm()
+                < tol
+        );
+        // 多项式与 Gauss 求和公式
+        assert!(
+            (hyp2f1_complex(-3.0, 2.0, 1.5, c(1.0, 2.0))
+                - c(2.7142857142857144, 14.857142857142858))
+            .norm()
+                < tol
+        );
+        let value = hyp2f1_complex(-60.0, 70.5, 2.5, c(1.5, 1.0));
+        assert!(
+            (value - c(-1.8537005048757055e44, -2.544562309312902e44)).norm() < tol * value.norm()
+        );
+        assert!((hyp2f1_complex(0.5, 1.5, 2.5, c(1.0, 0.0)) - c(0.75 * PI, 0.0)).norm() < tol);
+        assert_eq!(
+            hyp2f1_complex(1.0, 1.0, 2.0, c(-0.5, 0.0)),
+            c(hyp2f1(1.0, 1.0, 2.0, -0.5), 0.0)
+        );
+        assert!(hyp2f1_complex(1.0, 1.0, -2.0, c(0.5, 0.5)).re.is_nan());
+        assert!(hyp2f1_complex(1.0, 1.0, 2.0, c(f64::NAN, 0.0)).re.is_nan());
+    }
+
+    #[test]
+    fn test_hyp0f1() {
+        let tol = 1.0e-14;
+        // ₀F₁(; 1/2; x^2/4) = cosh x, ₀F₁(; 3/2; -x^2/4) = sin x/x
+        assert!(approx_eq(hyp0f1(0.5, 1.0), 2.0f64.cosh(), tol));
+        assert!(approx_eq(hyp0f1(1.5, -25.0), 10.0f64.sin() / 10.0, tol));
+        assert!(approx_eq(hyp0f1(1.5, 2.0), 2.980406103535168, tol));
+        assert!(approx_eq(hyp0f1(2.5, -3.0), 0.21423710771131335, tol));
+        assert!(approx_eq(hyp0f1(-1.5, 0.7), 1.0312595486469638, tol));
+        assert!(approx_eq(hyp0f1(-2.5, -4.0), 3.731489551412285, tol));
+        // Bessel 函数形式
+        assert!(approx_eq(hyp0f1(1.0, -30.0), -0.17907879620570685, tol));
+        assert!(approx_eq(
+            hyp0f1(3.0, -400.0) / -5.324873411790198e-6,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            hyp0f1(100.0, -3000.0) / -5.463903523146745e-18,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp0f1(0.75, -1.0e5),
+            -0.13507540573994828,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            hyp0f1(0.5, 1.0e4) / 3.6129868840628745e86,
+            1.0,
+            tol
+        ));
+        assert_eq!(hyp0f1(10.0, 1.0e6), f64::INFINITY);
+        assert_eq!(hyp0f1(2.0, 0.0), 1.0);
+        assert_eq!(hyp0f1(2.0, f64::NEG_INFINITY), 0.0);
+        assert!(hyp0f1(-2.0, 1.0).is_nan());
+        assert!(hyp0f1(f64::NAN, 1.0).is_nan());
+    }
+}
@@ -0,0 +1,421 @@
+use crate::elliptic::{ellip_k, ellip_rf};
+use std::f64::consts::PI;
+
+/// 下降 Landen 变换的最多层数. 参数 m 每层大致平方地减小, m = 1 - 2^(-53) 时约需 9 层
+const LANDEN_MAX_DEPTH: usize = 16;
+
+/// 0 <= m < 1, |u| <= K(m) 时由下降 Landen (Gauss) 变换计算 (sn, cn, dn)
+///
+/// 记 k' = √(1 - m), k_1 = (1 - k')/(1 + k'), v = u/(1 + k_1), 则
+/// sn(u|m) = (1 + k_1) sn(v|k_1^2) / (1 + k_1 sn^2), cn(u|m) = cn dn / (1 + k_1 sn^2),
+/// dn(u|m) = (cn^2 + (1 - k_1) sn^2) / (1 + k_1 sn^2), 右端均在 (v|k_1^2) 处取值.
+/// 反复变换至 m < ε 后以 sn ≈ sin v - (m/4)(v - sin v cos v) cos v 等一阶展开结束, 再逐层回代.
+/// 回代公式只含同号项的乘除, 各量均有良好的相对精度. 由于 u/K(m) 在变换下不变, 最终 |v| <= π/2
+fn jacobi_landen(u: f64, m: f64) -> (f64, f64, f64) {
+    // 各层的 (k_1, 1 - k_1), 其中 1 - k_1 按 2k'/(1 + k') 计算, m 接近 1 时避免相消
+    let mut k1 = [(0.0, 0.0); LANDEN_MAX_DEPTH];
+    let mut depth = 0;
+    let mut m = m;
+    // k' = √(1 - m) 按 2√k'/(1 + k') 递推, 避免 1 - m 的舍入
+    let mut kc = (1.0 - m).sqrt();
+    let mut v = u;
+    while m >= f64::EPSILON && depth < LANDEN_MAX_DEPTH {
+        let k = m / ((1.0 + kc) * (1.0 + kc));
+        k1[depth] = (k, 2.0 * kc / (1.0 + kc));
+        v /= 1.0 + k;
+        m = k * k;
+        kc = 2.0 * kc.sqrt() / (1.0 + kc);
+        depth += 1;
+    }
+    let (s, c) = v.sin_cos();
+    let t = 0.25 * m * (v - s * c);
+    let (mut sn, mut cn, mut dn) = (s - t * c, c + t * s, 1.0 - 0.5 * m * s * s);
+    for &(k, kc) in k1[..depth].iter().rev() {
+        let ss = sn * sn;
+        let denom = 1.0 + k * ss;
+        let next_dn = (cn * cn + kc * ss) / denom;
+        sn = (1.0 + k) * sn / denom;
+        cn = cn * dn / denom;
+        dn = next_dn;
+    }
+    (sn, cn, dn)
+}
+
+/// 0 <= m <= 1 时计算 (j, sn(r|m), cn(r|m), dn(r|m)), 其中 u = r + 2jK(m), |r| <= K(m).
+/// 由 sn(u + 2K) = -sn u, cn(u + 2K) = -cn u, dn(u + 2K) = dn u 即可得到 u 处的函数值. m = 1 时 j = 0
+fn jacobi_reduced(u: f64, m: f64) -> (f64, f64, f64, f64) {
+    if m == 1.0 {
+        let sech = 1.0 / u.cosh();
+        return (0.0, u.tanh(), sech, sech);
+    }
+    let k = ellip_k(m);
+    let j = (0.5 * u / k).round();
+    let (sn, cn, dn) = jacobi_landen((-2.0 * j).mul_add(k, u), m);
+    (j, sn, cn, dn)
+}
+
+/// 计算 (sn, cn, dn, am), 要求 u, m 有限
+///
+/// 0 <= m <= 1 时直接计算; m > 1 时由倒数模变换 sn(u|m) = sn(u√m|1/m)/√m, cn(u|m) = dn(u√m|1/m),
+/// dn(u|m) = cn(u√m|1/m); m < 0 时由虚模变换, 记 μ = -m/(1 - m), s = √(1 - m), v = us,
+/// sn(u|m) = sd(v|μ)/s, cn(u|m) = cd(v|μ), dn(u|m) = nd(v|μ).
+/// am 取为满足 sin am = sn, cos am = cn 的连续分支, 由约化后的 atan2 加上 jπ 得到
+fn jacobi_all(u: f64, m: f64) -> (f64, f64, f64, f64) {
+    if m > 1.0 {
+        let root = m.sqrt();
+        let (j, sn, cn, dn) = jacobi_reduced(u * root, 1.0 / m);
+        let sign = if j % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        let sn = sign * sn / root;
+        return (sn, dn, sign * cn, sn.atan2(dn));
+    }
+    let (j, sn, cn, dn) = if m < 0.0 {
+        let s = (1.0 - m).sqrt();
+        let (j, sn, cn, dn) = jacobi_reduced(u * s, -m / (1.0 - m));
+        (j, sn / (s * dn), cn / dn, 1.0 / dn)
+    } else {
+        jacobi_reduced(u, m)
+    };
+    // 约化后的 cn >= 0, atan2 落在 [-π/2, π/2] 内
+    let am = j.mul_add(PI, sn.atan2(cn));
+    let sign = if j % 2.0 == 0.0 { 1.0 } else { -1.0 };
+    (sign * sn, sign * cn, dn, am)
+}
+
+/// 同时计算 Jacobi 椭圆函数 (sn(u|m), cn(u|m), dn(u|m)), 其中 m 为参数 (m = k^2)
+///
+/// 0 <= m <= 1 时先以 2K(m) 为周期将 u 约化到 [-K, K], 再用下降 Landen 变换计算, 三者均有良好的相对精度.
+/// m > 1 时用倒数模变换, m < 0 时用虚模变换化为 [0, 1] 上的情形. m = 0 时为 (sin u, cos u, 1),
+/// m = 1 时为 (tanh u, sech u, sech u). u 或 m 为无穷大或 NaN 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::jacobi_elliptic;
+/// let (sn, cn, dn) = jacobi_elliptic(1.0, 0.5);
+/// assert!((sn - 0.8030018248956439).abs() < 1.0e-15);
+/// assert!((cn - 0.5959765676721407).abs() < 1.0e-15);
+/// assert!((dn - 0.8231610016315962).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_elliptic(u: f64, m: f64) -> (f64, f64, f64) {
+    if !u.is_finite() || !m.is_finite() {
+        return (f64::NAN, f64::NAN, f64::NAN);
+    }
+    let (sn, cn, dn, _) = jacobi_all(u, m);
+    (sn, cn, dn)
+}
+
+/// 计算 Jacobi 椭圆函数 sn(u|m) = sin am(u|m)
+///
+/// 方法见 `jacobi_elliptic`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::jacobi_sn;
+/// assert!((jacobi_sn(1.0, 0.5) - 0.8030018248956439).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_sn(u: f64, m: f64) -> f64 {
+    jacobi_elliptic(u, m).0
+}
+
+/// 计算 Jacobi 椭圆函数 cn(u|m) = cos am(u|m)
+///
+/// 方法见 `jacobi_elliptic`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::jacobi_cn;
+/// assert!((jacobi_cn(1.0, 0.5) - 0.5959765676721407).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_cn(u: f64, m: f64) -> f64 {
+    jacobi_elliptic(u, m).1
+}
+
+/// 计算 Jacobi 椭圆函数 dn(u|m) = √(1 - m sn^2(u|m))
+///
+/// 方法见 `jacobi_elliptic`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::jacobi_dn;
+/// assert!((jacobi_dn(1.0, 0.5) - 0.8231610016315962).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_dn(u: f64, m: f64) -> f64 {
+    jacobi_elliptic(u, m).2
+}
+
+/// 计算 Jacobi 振幅函数 am(u|m), 即 F(φ, m) = u 的解 φ
+///
+/// 取满足 sin am = sn, cos am = cn 的连续分支, am(u + 2K|m) = am(u|m) + π (m <= 1).
+/// m = 1 时为 Gudermann 函数 gd(u); m > 1 时 cn > 0, am 在 (-π/2, π/2) 内周期振荡.
+/// u 或 m 为无穷大或 NaN 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::jacobi_am;
+/// assert!((jacobi_am(1.0, 0.5) - 0.9323150798838539).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_am(u: f64, m: f64) -> f64 {
+    if !u.is_finite() || !m.is_finite() {
+        return f64::NAN;
+    }
+    jacobi_all(u, m).3
+}
+
+/// 计算反 Jacobi 椭圆函数 arcsn(x|m), 即 sn(u|m) = x 在 [-K(m), K(m)] 内的解 u
+///
+/// 由 arcsn(x|m) = F(arcsin x, m) = x R_F(1 - x^2, 1 - m x^2, 1) 计算, m <= 1 时 1 - m x^2 按
+/// (1 - x)(1 + x) + (1 - m) x^2 计算以避免相消. 定义域为 |x| <= 1 (m <= 1) 或 |x| <= 1/√m (m > 1),
+/// 超出时返回 NaN. m = 1 时 arcsn(x|1) = artanh x, x = ±1 时返回 ±∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::arcsn;
+/// assert!((arcsn(0.5, 0.5) - 0.5356227328054033).abs() < 1.0e-15);
+/// ```
+pub fn arcsn(x: f64, m: f64) -> f64 {
+    if x.is_nan() || !m.is_finite() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    let xx = x * x;
+    let y = if m <= 1.0 {
+        (1.0 - x) * (1.0 + x) + (1.0 - m) * xx
+    } else {
+        1.0 - m * xx
+    };
+    if y < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return x;
+    }
+    x * ellip_rf((1.0 - x) * (1.0 + x), y, 1.0)
+}
+
+/// 计算反 Jacobi 椭圆函数 arccn(x|m), 即 cn(u|m) = x 在 [0, 2K(m)] 内的解 u
+///
+/// x >= 0 时由 arccn(x|m) = F(arccos x, m) = √(1 - x^2) R_F(x^2, 1 - m + m x^2, 1) 计算,
+/// x < 0 时由 arccn(x|m) = 2K(m) - arccn(-x|m) 计算. m > 1 时 cn(u|m) 不小于 √(1 - 1/m),
+/// 定义域为 √(1 - 1/m) <= x <= 1, 解取在 [0, K(1/m)/√m] 内. 超出定义域时返回 NaN, m = 1 时 arccn(0|1) = +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::arccn;
+/// assert!((arccn(0.5, 0.5) - 1.1424290580457772).abs() < 1.0e-15);
+/// ```
+pub fn arccn(x: f64, m: f64) -> f64 {
+    if x.is_nan() || !m.is_finite() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        if m >= 1.0 {
+            return f64::NAN;
+        }
+        return 2.0 * ellip_k(m) - arccn(-x, m);
+    }
+    let xx = x * x;
+    let y = (1.0 - m) + m * xx;
+    if y < 0.0 {
+        return f64::NAN;
+    }
+    ((1.0 - x) * (1.0 + x)).sqrt() * ellip_rf(xx, y, 1.0)
+}
+
+/// 计算反 Jacobi 椭圆函数 arcdn(x|m), 即 dn(u|m) = x 在 [0, K(m)] 内的解 u
+///
+/// 0 < m <= 1 时定义域为 √(1 - m) <= x <= 1, m < 0 时为 1 <= x <= √(1 - m). 记 sin φ = √((1 - x^2)/m),
+/// 则 arcdn(x|m) = F(φ, m) = sin φ R_F((x^2 - 1 + m)/m, x^2, 1).
+/// m > 1 时 dn(u|m) = cn(u√m|1/m), 由 arcdn(x|m) = arccn(x|1/m)/√m 计算, 定义域为 |x| <= 1, 解取在 [0, 2K(1/m)/√m] 内.
+/// 超出定义域或 m = 0 时返回 NaN (m = 0 且 x = 1 时返回 0), m = 1 时 arcdn(x|1) = arsech x
+///
+/// # Example
+///
+/// ```
+/// use special_functions::jacobi::arcdn;
+/// assert!((arcdn(0.9, 0.5) - 0.688792792395509).abs() < 1.0e-15);
+/// ```
+pub fn arcdn(x: f64, m: f64) -> f64 {
+    if x.is_nan() || !m.is_finite() {
+        return f64::NAN;
+    }
+    if m > 1.0 {
+        return arccn(x, 1.0 / m) / m.sqrt();
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    let xx = x * x;
+    let ss = (1.0 - x) * (1.0 + x) / m;
+    let cc = (xx - (1.0 - m)) / m;
+    if x < 0.0 || !(ss >= 0.0 && cc >= 0.0) {
+        return f64::NAN;
+    }
+    ss.sqrt() * ellip_rf(cc, xx, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+    use crate::elliptic::ellip_f;
+
+    #[test]
+    fn test_jacobi_elliptic() {
+        let tol = 1.0e-15;
+        let (sn, cn, dn) = jacobi_elliptic(10.0, 0.5);
+        assert!(approx_eq(sn, 0.8588125059527787, 1.0e-14));
+        assert!(approx_eq(cn, -0.5122900346669925, 1.0e-14));
+        assert!(approx_eq(dn, 0.7944938890951612, 1.0e-14));
+        let (sn, cn, dn) = jacobi_elliptic(5.0, 0.999999999999);
+        assert!(approx_eq(sn, 0.9999092042628449, tol));
+        assert!(approx_eq(cn / 0.013475282202772691, 1.0, 1.0e-13));
+        assert!(approx_eq(dn / 0.013475282239870108, 1.0, 1.0e-13));
+        let (sn, cn, dn) = jacobi_elliptic(1.5, -2.0);
+        assert!(approx_eq(sn, 0.8524851046356531, tol));
+        assert!(approx_eq(cn, -0.5227515149421756, tol));
+        assert!(approx_eq(dn, 1.5663529957360571, tol));
+        let (sn, cn, dn) = jacobi_elliptic(0.7, 4.0);
+        assert!(approx_eq(sn, 0.4846658585146446, tol));
+        assert!(approx_eq(cn, 0.8746993801245445, tol));
+        assert!(approx_eq(dn, 0.24575602202397784, tol));
+        let (sn, cn, dn) = jacobi_elliptic(2.0, 0.0);
+        assert!(approx_eq(sn, 2.0f64.sin(), tol));
+        assert!(approx_eq(cn, 2.0f64.cos(), tol));
+        assert_eq!(dn, 1.0);
+        let (sn, cn, dn) = jacobi_elliptic(3.0, 1.0);
+        assert!(approx_eq(sn, 3.0f64.tanh(), tol));
+        assert!(approx_eq(cn, 1.0 / 3.0f64.cosh(), tol));
+        assert!(approx_eq(dn, 1.0 / 3.0f64.cosh(), tol));
+        for &(u, m) in &[(0.3, 0.2), (7.0, 0.8), (-4.0, -5.0), (2.5, 3.0)] {
+            let (sn, cn, dn) = jacobi_elliptic(u, m);
+            assert!(approx_eq(sn * sn + cn * cn, 1.0, 1.0e-14));
+            assert!(approx_eq(dn * dn + m * sn * sn, 1.0, 1.0e-14));
+        }
+        assert!(jacobi_elliptic(f64::INFINITY, 0.5).0.is_nan());
+        assert!(jacobi_elliptic(1.0, f64::NAN).2.is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_sn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_sn(0.5, 0.3), 0.4742156227118206, tol));
+        assert!(approx_eq(jacobi_sn(2.0, 0.9), 0.9816158695184938, tol));
+        assert!(approx_eq(jacobi_sn(-3.0, 0.99), -0.9971703129000018, tol));
+        assert!(approx_eq(
+            jacobi_sn(30.0, 0.999999),
+            -0.9965212268018178,
+            1.0e-14
+        ));
+        assert!(approx_eq(jacobi_sn(1.0e-20, 0.5) / 1.0e-20, 1.0, tol));
+        assert_eq!(jacobi_sn(0.0, 0.5), 0.0);
+        assert!(jacobi_sn(f64::NAN, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_cn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_cn(0.5, 0.3), 0.8804087364264624, tol));
+        assert!(approx_eq(jacobi_cn(2.0, 0.9), 0.19086719128611748, tol));
+        assert!(approx_eq(jacobi_cn(-3.0, 0.99), 0.07517557496230039, tol));
+        assert!(approx_eq(
+            jacobi_cn(30.0, 0.999999),
+            0.08333933365104473,
+            1.0e-14
+        ));
+        assert_eq!(jacobi_cn(0.0, 0.5), 1.0);
+        assert!(jacobi_cn(1.0, f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_dn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_dn(0.5, 0.3), 0.9656789647459512, tol));
+        assert!(approx_eq(jacobi_dn(2.0, 0.9), 0.3643998576269017, tol));
+        assert!(approx_eq(jacobi_dn(-3.0, 0.99), 0.12487935538031629, tol));
+        assert!(approx_eq(
+            jacobi_dn(30.0, 0.999999),
+            0.08334529133643755,
+            1.0e-14
+        ));
+        assert_eq!(jacobi_dn(0.0, 0.5), 1.0);
+        assert!(jacobi_dn(f64::NEG_INFINITY, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_am() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_am(1.0, 0.5), 0.9323150798838539, tol));
+        assert!(approx_eq(
+            jacobi_am(3.0, 1.0),
+            2.0 * 1.5f64.tanh().atan(),
+            tol
+        ));
+        assert!(approx_eq(jacobi_am(2.0, 0.0), 2.0, tol));
+        for &(u, m) in &[(0.5, 0.3), (1.2, 0.99), (-0.8, -3.0), (0.3, 5.0)] {
+            assert!(approx_eq(ellip_f(jacobi_am(u, m), m), u, tol));
+        }
+        // am(u + 2K) = am(u) + π
+        let k = crate::elliptic::ellip_k(0.7);
+        assert!(approx_eq(
+            jacobi_am(0.4 + 2.0 * k, 0.7),
+            jacobi_am(0.4, 0.7) + PI,
+            1.0e-14
+        ));
+        assert!(approx_eq(jacobi_am(10.0, 0.5), 8.39183082303414, 1.0e-14));
+        assert!(jacobi_am(f64::INFINITY, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_arcsn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(arcsn(0.5, 0.5), 0.5356227328054033, tol));
+        assert!(approx_eq(arcsn(0.9, 0.99), 1.4641979499934945, tol));
+        assert!(approx_eq(arcsn(-0.3, -2.0), -0.2961187318326931, tol));
+        assert!(approx_eq(arcsn(0.4, 4.0), 0.4788095499820081, tol));
+        assert!(approx_eq(arcsn(0.5, 1.0), 0.5493061443340549, tol));
+        assert!(approx_eq(arcsn(0.3, 0.0), 0.3f64.asin(), tol));
+        assert!(approx_eq(arcsn(1.0, 0.7), ellip_k(0.7), tol));
+        assert!(approx_eq(jacobi_sn(arcsn(0.8, 0.6), 0.6), 0.8, tol));
+        assert_eq!(arcsn(1.0, 1.0), f64::INFINITY);
+        assert!(arcsn(0.6, 4.0).is_nan());
+        assert!(arcsn(1.5, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_arccn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(arccn(0.5, 0.5), 1.1424290580457772, tol));
+        assert!(approx_eq(arccn(0.2, 0.7), 1.7132559075987033, tol));
+        assert!(approx_eq(arccn(-0.2, 0.7), 2.437470362986235, tol));
+        assert!(approx_eq(arccn(0.9, 3.0), 0.513108864134023, tol));
+        assert!(approx_eq(arccn(0.5, 1.0), 1.3169578969248168, tol));
+        assert!(approx_eq(arccn(0.0, 0.7), ellip_k(0.7), tol));
+        assert!(approx_eq(arccn(-1.0, 0.7), 2.0 * ellip_k(0.7), tol));
+        assert!(approx_eq(jacobi_cn(arccn(-0.4, -1.5), -1.5), -0.4, tol));
+        assert_eq!(arccn(1.0, 0.5), 0.0);
+        assert_eq!(arccn(0.0, 1.0), f64::INFINITY);
+        assert!(arccn(0.5, 3.0).is_nan());
+        assert!(arccn(-0.5, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_arcdn() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(arcdn(0.9, 0.5), 0.688792792395509, tol));
+        assert!(approx_eq(arcdn(0.6, 0.8), 1.3271506415606404, tol));
+        assert!(approx_eq(arcdn(1.5, -3.0), 0.5982964128200269, tol));
+        assert!(approx_eq(arcdn(-0.5, 3.0), 1.3637370216753593, tol));
+        assert!(approx_eq(arcdn(0.5, 1.0), 1.3169578969248168, tol));
+        assert!(approx_eq(arcdn(0.5, 0.75), ellip_k(0.75), tol));
+        assert!(approx_eq(jacobi_dn(arcdn(0.7, 0.9), 0.9), 0.7, 1.0e-14));
+        assert_eq!(arcdn(1.0, 0.5), 0.0);
+        assert!(arcdn(0.5, 0.5).is_nan());
+        assert!(arcdn(0.9, 0.0).is_nan());
+        assert!(arcdn(0.9, -1.0).is_nan());
+    }
+}
@@ -0,0 +1,571 @@
+use crate::gamma::EULER;
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_4, PI};
+
+/// x 不超过此值时以幂级数计算 I_0(w), I_1(w), K_0(w), K_1(w), 其中 w = x e^(iπ/4)
+const KELVIN_SERIES_MAX_ARG: f64 = 2.0;
+
+/// x 超过此值时以渐近展开计算 I_0(w), I_1(w)
+const KELVIN_ASYMPTOTIC_MIN_ARG: f64 = 30.0;
+
+/// e^(iπ/4)
+const E_PI_4: Complex64 = Complex64::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+
+/// 在 0 < x <= 2 上以幂级数同时计算 (I_0(w), I_1(w), K_0(w), K_1(w)), w = x e^(iπ/4), q = w^2 / 4 = i x^2 / 4
+///
+/// I_0 = Σ q^k / (k!)^2, K_0 = -(ln(w/2) + γ) I_0 + Σ H_k q^k / (k!)^2,
+/// I_1 = (w/2) Σ q^k / (k! (k+1)!), K_1 = 1/w + ln(w/2) I_1 - (w/4) Σ (ψ(k+1) + ψ(k+2)) q^k / (k! (k+1)!)
+fn kelvin_series(x: f64) -> (Complex64, Complex64, Complex64, Complex64) {
+    let w = E_PI_4 * x;
+    let q = Complex64::new(0.0, 0.25 * x * x);
+    let ln_half_w = Complex64::new((0.5 * x).ln(), FRAC_PI_4);
+    // t0 = q^k / (k!)^2, t1 = q^k / (k! (k+1)!), h = H_k
+    let mut t0 = Complex64::new(1.0, 0.0);
+    let mut t1 = Complex64::new(1.0, 0.0);
+    let mut h = 0.0;
+    let (mut i0, mut i1) = (t0, t1);
+    let mut s0 = Complex64::new(0.0, 0.0);
+    let mut s1 = Complex64::new(1.0 - 2.0 * EULER, 0.0);
+    let mut k = 1.0;
+    loop {
+        t0 *= q / (k * k);
+        t1 *= q / (k * (k + 1.0));
+        h += 1.0 / k;
+        i0 += t0;
+        i1 += t1;
+        s0 += t0 * h;
+        s1 += t1 * (2.0 * (h - EULER) + 1.0 / (k + 1.0));
+        if t0.norm() <= f64::EPSILON * i0.norm() && t1.norm() <= f64::EPSILON * i1.norm() {
+            break;
+        }
+        k += 1.0;
+    }
+    let i1 = i1 * w * 0.5;
+    let k0 = s0 - i0 * (ln_half_w + EULER);
+    let k1 = w.inv() + ln_half_w * i1 - s1 * w * 0.25;
+    (i0, i1, k0, k1)
+}
+
+/// 在 2 < x <= 30 上以 Miller 向后递推 I_(k-1) = (2k / w) I_k + I_(k+1) 计算 (I_0(w), I_1(w)),
+/// 并以 e^w = I_0 + 2 Σ I_k 归一化. 由 |I_k(w)| <= e^(Re w) 可知归一化求和没有抵消
+fn kelvin_i01_miller(x: f64) -> (Complex64, Complex64) {
+    let w = E_PI_4 * x;
+    let w_inv = w.inv();
+    let n = x as u32 + 40;
+    let mut next = Complex64::new(0.0, 0.0);
+    let mut cur = Complex64::new(1.0e-30, 0.0);
+    let mut sum = Complex64::new(0.0, 0.0);
+    for k in (1..=n).rev() {
+        let prev = cur * w_inv * (2.0 * k as f64) + next;
+        sum += cur;
+        next = cur;
+        cur = prev;
+    }
+    let scale = w.exp() / (cur + sum * 2.0);
+    (cur * scale, next * scale)
+}
+
+/// 在 x > 30 上以渐近展开 I_ν(w) ~ e^w / √(2πw) Σ (-1)^k a_k(ν) / w^k 计算 I_ν(w), ν = 0 或 1,
+/// 其中 a_k(ν) = Π_(j=1..k) (4ν^2 - (2j - 1)^2) / (k! 8^k). 另一项 e^(-w) 的相对大小不超过 e^(-√2 x), 可以忽略.
+/// e^w 拆成两半相乘, 以免 |I_ν(w)| 有限时提前上溢
+fn kelvin_i_asymptotic(nu: f64, x: f64) -> Complex64 {
+    let w = E_PI_4 * x;
+    let w_inv = w.inv();
+    let mu = 4.0 * nu * nu;
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = term;
+    let mut k = 1.0;
+    loop {
+        let odd = 2.0 * k - 1.0;
+        term *= -w_inv * ((mu - odd * odd) / (8.0 * k));
+        sum += term;
+        if term.norm() <= f64::EPSILON * sum.norm() {
+            break;
+        }
+        k += 1.0;
+    }
+    let half = (0.5 * w).exp();
+    sum * half * half / (2.0 * PI * w).sqrt()
+}
+
+/// 在 x > 2 上以 Steed 算法计算连分式 CF2, 得到 (K_0(w), K_1(w)), w = x e^(iπ/4).
+/// 方法与 `bessel_k_temme_steed` 的 x >= 2 分支相同 (取 μ = 0), 只是改用复数运算
+fn kelvin_k01_steed(x: f64) -> (Complex64, Complex64) {
+    let w = E_PI_4 * x;
+    let one = Complex64::new(1.0, 0.0);
+    let mut b = (one + w) * 2.0;
+    let mut d = b.inv();
+    let mut delh = d;
+    let mut h = d;
+    let (mut q1, mut q2) = (Complex64::new(0.0, 0.0), one);
+    let a1 = 0.25;
+    let mut q = Complex64::new(a1, 0.0);
+    let mut c = a1;
+    let mut a = -a1;
+    let mut s = one + q * delh;
+    let mut i = 2.0;
+    loop {
+        a -= 2.0 * (i - 1.0);
+        c = -a * c / i;
+        let q_new = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = q_new;
+        q += q_new * c;
+        b += 2.0;
+        d = (b + d * a).inv();
+        delh *= b * d - 1.0;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if dels.norm() < s.norm() * f64::EPSILON {
+            break;
+        }
+        i += 1.0;
+    }
+    let k = (PI / (2.0 * w)).sqrt() * (-w).exp() / s;
+    (k, k * (w + 0.5 - h * a1) / w)
+}
+
+/// 计算 (I_0(w), I_1(w)), w = x e^(iπ/4), 要求 x > 0
+fn kelvin_i01(x: f64) -> (Complex64, Complex64) {
+    if x <= KELVIN_SERIES_MAX_ARG {
+        let (i0, i1, _, _) = kelvin_series(x);
+        (i0, i1)
+    } else if x <= KELVIN_ASYMPTOTIC_MIN_ARG {
+        kelvin_i01_miller(x)
+    } else {
+        (kelvin_i_asymptotic(0.0, x), kelvin_i_asymptotic(1.0, x))
+    }
+}
+
+/// 计算 (K_0(w), K_1(w)), w = x e^(iπ/4), 要求 x > 0
+fn kelvin_k01(x: f64) -> (Complex64, Complex64) {
+    if x <= KELVIN_SERIES_MAX_ARG {
+        let (_, _, k0, k1) = kelvin_series(x);
+        (k0, k1)
+    } else {
+        kelvin_k01_steed(x)
+    }
+}
+
+/// 在 0 < x <= 2 上以实幂级数同时计算 (ber'(x), kei'(x)), y = x/2.
+/// 复数表达式 e^(iπ/4) I_1(w) 与 -e^(iπ/4) K_1(w) 在 x 较小时实部或虚部相消, 因此直接对级数逐项求导:
+///
+/// bei'(x) = Σ (-1)^k (2k+1) y^(4k+1) / ((2k+1)!)^2, ber'(x) = Σ_(k>=1) (-1)^k 2k y^(4k-1) / ((2k)!)^2,
+/// kei'(x) = -ln(y) bei'(x) - (π/4) ber'(x) + Σ (-1)^k ((2k+1) ψ(2k+2) - 1/2) y^(4k+1) / ((2k+1)!)^2
+fn kelvin_prime_series(x: f64) -> (f64, f64) {
+    let y = 0.5 * x;
+    let y2 = y * y;
+    // u = (-1)^k y^(4k+1) / ((2k+1)!)^2, h = H_(2k+1)
+    let mut u = y;
+    let mut h = 1.0;
+    let (mut ber_p, mut bei_p, mut s) = (0.0, 0.0, 0.0);
+    let mut k = 0.0;
+    loop {
+        let n = 2.0 * k + 1.0;
+        ber_p -= u * y2 / (n + 1.0);
+        bei_p += n * u;
+        s += (n * (h - EULER) - 0.5) * u;
+        if u.abs() <= f64::EPSILON * y {
+            break;
+        }
+        u *= -y2 * y2 / ((n + 1.0) * (n + 2.0)).powi(2);
+        h += 1.0 / (n + 1.0) + 1.0 / (n + 2.0);
+        k += 1.0;
+    }
+    (ber_p, s - y.ln() * bei_p - FRAC_PI_4 * ber_p)
+}
+
+/// 计算 ber(x) + i bei(x) = I_0(x e^(iπ/4)), 其为 x 的偶函数
+fn kelvin_be(x: f64) -> Complex64 {
+    let x = x.abs();
+    if x.is_nan() || x.is_infinite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if x == 0.0 {
+        return Complex64::new(1.0, 0.0);
+    }
+    kelvin_i01(x).0
+}
+
+/// 计算 ber'(x) + i bei'(x) = e^(iπ/4) I_1(x e^(iπ/4)), 其为 x 的奇函数
+fn kelvin_be_prime(x: f64) -> Complex64 {
+    if x.is_nan() || x.is_infinite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if x == 0.0 {
+        return Complex64::new(0.0, 0.0);
+    }
+    let v = E_PI_4 * kelvin_i01(x.abs()).1;
+    if x < 0.0 {
+        -v
+    } else {
+        v
+    }
+}
+
+/// 计算 ker(x) + i kei(x) = K_0(x e^(iπ/4)), x < 0 时返回 NaN
+fn kelvin_ke(x: f64) -> Complex64 {
+    if x.is_nan() || x < 0.0 {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if x == 0.0 {
+        return Complex64::new(f64::INFINITY, -FRAC_PI_4);
+    }
+    if x.is_infinite() {
+        return Complex64::new(0.0, 0.0);
+    }
+    kelvin_k01(x).0
+}
+
+/// 计算 ker'(x) + i kei'(x) = -e^(iπ/4) K_1(x e^(iπ/4)), x < 0 时返回 NaN
+fn kelvin_ke_prime(x: f64) -> Complex64 {
+    if x.is_nan() || x < 0.0 {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if x == 0.0 {
+        return Complex64::new(f64::NEG_INFINITY, 0.0);
+    }
+    if x.is_infinite() {
+        return Complex64::new(0.0, 0.0);
+    }
+    -E_PI_4 * kelvin_k01(x).1
+}
+
+/// 计算 Kelvin 函数 ber(x) = Re I_0(x e^(iπ/4))
+///
+/// x <= 2 时使用幂级数, 2 < x <= 30 时使用 Miller 向后递推, x > 30 时使用渐近展开.
+/// ber 为偶函数, x 为 ±∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_ber;
+/// assert!((kelvin_ber(1.0) - 0.9843817812130869).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_ber(x: f64) -> f64 {
+    kelvin_be(x).re
+}
+
+/// 计算 Kelvin 函数 bei(x) = Im I_0(x e^(iπ/4))
+///
+/// 方法与 `kelvin_ber` 相同. bei 为偶函数, x 为 ±∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_bei;
+/// assert!((kelvin_bei(1.0) - 0.24956604003665985).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_bei(x: f64) -> f64 {
+    kelvin_be(x).im
+}
+
+/// 计算 Kelvin 函数 ker(x) = Re K_0(x e^(iπ/4))
+///
+/// x <= 2 时使用幂级数, x > 2 时使用 Steed 连分式 CF2. x < 0 时返回 NaN, x = 0 时返回 +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_ker;
+/// assert!((kelvin_ker(1.0) - 0.28670620872831604).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_ker(x: f64) -> f64 {
+    kelvin_ke(x).re
+}
+
+/// 计算 Kelvin 函数 kei(x) = Im K_0(x e^(iπ/4))
+///
+/// 方法与 `kelvin_ker` 相同. x < 0 时返回 NaN, x = 0 时返回 -π/4
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_kei;
+/// assert!((kelvin_kei(1.0) + 0.4949946365187199).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_kei(x: f64) -> f64 {
+    kelvin_ke(x).im
+}
+
+/// 计算 Kelvin 函数的导数 ber'(x) = Re(e^(iπ/4) I_1(x e^(iπ/4)))
+///
+/// |x| <= 2 时直接使用 ber' 的实幂级数, 以免小 x 处的相消, 其余方法与 `kelvin_ber` 相同.
+/// ber' 为奇函数, x 为 ±∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_ber_prime;
+/// assert!((kelvin_ber_prime(1.0) + 0.06244575217903096).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_ber_prime(x: f64) -> f64 {
+    if x != 0.0 && x.abs() <= KELVIN_SERIES_MAX_ARG {
+        return x.signum() * kelvin_prime_series(x.abs()).0;
+    }
+    kelvin_be_prime(x).re
+}
+
+/// 计算 Kelvin 函数的导数 bei'(x) = Im(e^(iπ/4) I_1(x e^(iπ/4)))
+///
+/// 方法与 `kelvin_ber` 相同. bei' 为奇函数, x 为 ±∞ 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_bei_prime;
+/// assert!((kelvin_bei_prime(1.0) - 0.4973965114680973).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_bei_prime(x: f64) -> f64 {
+    kelvin_be_prime(x).im
+}
+
+/// 计算 Kelvin 函数的导数 ker'(x) = -Re(e^(iπ/4) K_1(x e^(iπ/4)))
+///
+/// 方法与 `kelvin_ker` 相同. x < 0 时返回 NaN, x = 0 时返回 -∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_ker_prime;
+/// assert!((kelvin_ker_prime(1.0) + 0.6946038911006905).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_ker_prime(x: f64) -> f64 {
+    kelvin_ke_prime(x).re
+}
+
+/// 计算 Kelvin 函数的导数 kei'(x) = -Im(e^(iπ/4) K_1(x e^(iπ/4)))
+///
+/// x <= 2 时直接使用 kei' 的实幂级数, 以免小 x 处的相消, 其余方法与 `kelvin_ker` 相同.
+/// x < 0 时返回 NaN, x = 0 时返回 0
+///
+/// # Example
+///
+/// ```
+/// use special_functions::kelvin::kelvin_kei_prime;
+/// assert!((kelvin_kei_prime(1.0) - 0.3523699133361705).abs() < 1.0e-15);
+/// ```
+pub fn kelvin_kei_prime(x: f64) -> f64 {
+    if x > 0.0 && x <= KELVIN_SERIES_MAX_ARG {
+        return kelvin_prime_series(x).1;
+    }
+    kelvin_ke_prime(x).im
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_kelvin_ber() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_ber(0.5), 0.9990234639908383, tol));
+        assert!(approx_eq(kelvin_ber(-0.5), 0.9990234639908383, tol));
+        assert!(approx_eq(kelvin_ber(5.0) / -6.230082478666358, 1.0, tol));
+        assert!(approx_eq(kelvin_ber(10.0) / 138.84046594163266, 1.0, tol));
+        assert!(approx_eq(
+            kelvin_ber(40.0) / -112596696872.07408,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_ber(0.0), 1.0);
+        assert!(kelvin_ber(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_kelvin_bei() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_bei(0.5), 0.062493218382199456, tol));
+        assert!(approx_eq(kelvin_bei(-0.5), 0.062493218382199456, tol));
+        assert!(approx_eq(kelvin_bei(5.0), 0.11603438155020038, 1.0e-14));
+        assert!(approx_eq(
+            kelvin_bei(10.0) / 56.37045855390664,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            kelvin_bei(40.0) / 45628130285.64126,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_bei(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_kelvin_ker() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_ker(0.5), 0.8559058721186342, tol));
+        assert!(approx_eq(kelvin_ker(5.0) / -0.011511727199490663, 1.0, tol));
+        assert!(approx_eq(
+            kelvin_ker(10.0) / 0.0001294663302148061,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_ker(40.0) / -9.474811649099432e-14,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_ker(0.0), f64::INFINITY);
+        assert_eq!(kelvin_ker(f64::INFINITY), 0.0);
+        assert!(kelvin_ker(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_kelvin_kei() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_kei(0.5), -0.6715816950943676, tol));
+        assert!(approx_eq(kelvin_kei(5.0) / 0.011187586509869639, 1.0, tol));
+        assert!(approx_eq(
+            kelvin_kei(10.0) / -0.0003075245690881442,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_kei(40.0) / 4.011081399400742e-14,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_kei(0.0), -FRAC_PI_4);
+        assert!(kelvin_kei(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_kelvin_ber_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_ber_prime(0.5), -0.007812076147507734, tol));
+        assert!(approx_eq(kelvin_ber_prime(-0.5), 0.007812076147507734, tol));
+        assert!(approx_eq(
+            kelvin_ber_prime(5.0) / -3.8453394732621544,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_ber_prime(10.0) / 51.19525839359961,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_ber_prime(40.0) / -110470788109.85251,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_ber_prime(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_kelvin_bei_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_bei_prime(0.5), 0.24991862111621022, tol));
+        assert!(approx_eq(kelvin_bei_prime(-0.5), -0.24991862111621022, tol));
+        assert!(approx_eq(
+            kelvin_bei_prime(5.0) / -4.3541405148431105,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_bei_prime(10.0) / 135.3093017159646,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_bei_prime(40.0) / -47933243273.88891,
+            1.0,
+            1.0e-14
+        ));
+    }
+
+    #[test]
+    fn test_kelvin_ker_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_ker_prime(0.5), -1.8197997533173527, tol));
+        assert!(approx_eq(
+            kelvin_ker_prime(5.0) / 0.017193403828393114,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_ker_prime(10.0) / -0.0003155969344208975,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_ker_prime(40.0) / 9.65409259611519e-14,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_ker_prime(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_kelvin_kei_prime() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(kelvin_kei_prime(0.5), 0.33320379160332986, tol));
+        assert!(approx_eq(
+            kelvin_kei_prime(5.0) / -0.0008199865436307895,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            kelvin_kei_prime(10.0) / 0.00014091383761261612,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            kelvin_kei_prime(40.0) / 3.814028992938506e-14,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(kelvin_kei_prime(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_kelvin_prime_small_x() {
+        let tol = 1.0e-14;
+        for &(x, ber_p, bei_p, ker_p, kei_p) in &[
+            (
+                1.0e-8,
+                -6.25e-26,
+                5.0e-9,
+                -99999999.99999999,
+                9.51830612980539e-8,
+            ),
+            (
+                1.17e-6,
+                -1.001008125e-19,
+                5.85e-7,
+                -854700.8547003952,
+                8.350546420015696e-6,
+            ),
+            (
+                1.0e-4,
+                -6.25e-14,
+                5.0e-5,
+                -9999.999960730093,
+                0.0004913135944308171,
+            ),
+            (
+                1.0e-2,
+                -6.249999999945747e-8,
+                0.004999999999739583,
+                -99.99607338237707,
+                0.02610555759395425,
+            ),
+            (
+                1.9,
+                -0.4238445164986484,
+                0.8857369503741903,
+                -0.13412818813278278,
+                0.23506569021296197,
+            ),
+        ] {
+            assert!(approx_eq(kelvin_ber_prime(x) / ber_p, 1.0, tol));
+            assert!(approx_eq(kelvin_ber_prime(-x) / ber_p, -1.0, tol));
+            assert!(approx_eq(kelvin_bei_prime(x) / bei_p, 1.0, tol));
+            assert!(approx_eq(kelvin_ker_prime(x) / ker_p, 1.0, tol));
+            assert!(approx_eq(kelvin_kei_prime(x) / kei_p, 1.0, tol));
+        }
+    }
+}
@@ -0,0 +1,623 @@
+use crate::elliptic::PI_LO;
+use crate::utils::eval_poly;
+use num_complex::Complex64;
+use std::f64::consts::{E, PI};
+
+/// Euler 数 e 的低位部分, e = E + E_LO
+const E_LO: f64 = 1.4456468917292502e-16;
+
+/// 分支点 x = -1/e 处的级数 W = Σ_(k>=0) μ_k p^k 的系数 (降幂排列), 其中 p = ±√(2(ex + 1)),
+/// W_0 取正号, W_(-1) 取负号. 系数由 Corless 等给出的递推关系求得
+const LAMBERT_BRANCH_SERIES: [f64; 24] = [
+    1.7790345805079586e-05,
+    -2.63380647472311e-05,
+    3.907668486743905e-05,
+    -5.811360750441382e-05,
+    8.665035805208128e-05,
+    -0.00012957426685274883,
+    0.00019438727605453933,
+    -0.00029267722472962746,
+    0.0004424730618146209,
+    -0.0006720616311561362,
+    0.0010262633205076071,
+    -0.0015769303446867841,
+    0.0024408779911439826,
+    -0.0038112980348919993,
+    0.006014543252956118,
+    -0.009616892024299432,
+    0.01563563253233392,
+    -0.02598471487360376,
+    0.044502314814814814,
+    -0.07962962962962963,
+    0.1527777777777778,
+    -0.3333333333333333,
+    1.0,
+    -1.0,
+];
+
+/// |p| 小于此值时直接使用分支点处的级数, 截断误差约为 1e-18
+const LAMBERT_BRANCH_RADIUS: f64 = 0.25;
+
+/// |p| 小于此值时以分支点处级数的前几项作为迭代初值
+const LAMBERT_BRANCH_GUESS: f64 = 1.0;
+
+/// Fritsch 迭代的最大次数, 迭代三阶收敛, 由下面的初值出发通常只需一两次
+const LAMBERT_MAX_ITER: usize = 8;
+
+/// |Re z| 不超过此值时 e^z 为正规数, Wright ω 函数由 W_K(e^z) 求出
+const WRIGHT_OMEGA_EXP_MAX: f64 = 700.0;
+
+/// 复数情形 Halley 迭代的最大次数, 由渐近展开给出的初值通常只需数次
+const LAMBERT_HALLEY_MAX_ITER: usize = 64;
+
+/// 计算 p = √(2(ex + 1)), 其中 ex + 1 由 fma 与 e 的低位部分精确求出.
+/// x 因舍入略小于 -1/e 时返回 0, 真正超出定义域时返回 NaN
+fn lambert_branch_distance(x: f64) -> f64 {
+    let q = x.mul_add(E, 1.0) + x * E_LO;
+    if q < 0.0 && q > -4.0 * f64::EPSILON {
+        return 0.0;
+    }
+    (2.0 * q).sqrt()
+}
+
+/// 分支点附近级数 W = -1 + p - p^2/3 + 11p^3/72 - ... 的前四项, 用作迭代初值
+fn lambert_branch_guess(p: f64) -> f64 {
+    eval_poly(p, &LAMBERT_BRANCH_SERIES[20..])
+}
+
+/// 使用 Fritsch–Shafer–Crowley 迭代求解 w e^w = e^y, 其中 `residual(w)` 给出 z = y - w - ln w:
+/// q = 2(1 + w)(1 + w + 2z/3), w ← w (1 + z/(1 + w) · (q - z)/(q - 2z))
+fn fritsch_iterate(mut w: f64, residual: impl Fn(f64) -> f64) -> f64 {
+    for _ in 0..LAMBERT_MAX_ITER {
+        let z = residual(w);
+        let v = 1.0 + w;
+        // h = q/(2(1 + w)), 避免 |w| 很大时 q 上溢
+        let h = (2.0 / 3.0f64).mul_add(z, v);
+        let u = z / v;
+        let eps = u * (0.5f64).mul_add(-u, h) / (h - u);
+        w += w * eps;
+        if eps.abs() <= f64::EPSILON {
+            break;
+        }
+    }
+    w
+}
+
+/// 以 z = ln(x/w) - w 做 Fritsch 迭代求解 w e^w = x
+fn lambert_fritsch(x: f64, w: f64) -> f64 {
+    fritsch_iterate(w, |w| {
+        let ratio = x / w;
+        // W_(-1) 在次正规数 x 处 x/w 会下溢, 改为分别取对数
+        let log = if ratio.is_normal() {
+            ratio.ln()
+        } else {
+            (-x).ln() - (-w).ln()
+        };
+        log - w
+    })
+}
+
+/// 复数情形的 Fritsch 迭代, 求解 ω + ln ω = z, ln 取主值
+fn wright_fritsch(z: Complex64, mut w: Complex64) -> Complex64 {
+    for _ in 0..LAMBERT_HALLEY_MAX_ITER {
+        let r = z - w - w.ln();
+        let v = w + 1.0;
+        let h = v + r * (2.0 / 3.0);
+        let u = r / v;
+        let eps = u * (h - u * 0.5) / (h - u);
+        w += w * eps;
+        if eps.norm() <= f64::EPSILON {
+            break;
+        }
+    }
+    w
+}
+
+/// Newton 迭代求解 ν - ln ν = s, 要求解 ν 位于右半平面且 |ν| 较大, 迭代二次收敛
+fn wright_reflected(s: Complex64) -> Complex64 {
+    let mut nu = s + s.ln();
+    for _ in 0..LAMBERT_HALLEY_MAX_ITER {
+        let step = (nu - nu.ln() - s) / (1.0 - nu.inv());
+        nu -= step;
+        if step.norm() <= f64::EPSILON * nu.norm() {
+            break;
+        }
+    }
+    nu
+}
+
+/// Wright ω 函数的展开数 K(z) = ⌈(Im z - π)/(2π)⌉, 满足 (2K - 1)π < Im z < (2K + 1)π.
+/// 借助 π 的低位部分判断 Im z 靠近 (2K ± 1)π 的情形
+fn wright_unwinding(y: f64) -> f64 {
+    let k = ((y - PI) / (2.0 * PI)).ceil();
+    // y - mπ
+    let offset = |m: f64| (-m).mul_add(PI, y) - m * PI_LO;
+    if offset(2.0 * k + 1.0) > 0.0 {
+        k + 1.0
+    } else if offset(2.0 * k - 1.0) < 0.0 {
+        k - 1.0
+    } else {
+        k
+    }
+}
+
+/// 求解 w e^w = z 的 Halley 迭代. 以 t = w - z e^(-w) 代替 w e^w - z 以免 e^w 上溢:
+/// w ← w - t / ((w + 1) - (w + 2) t / (2w + 2)); Re w 很小 (k != 0, z -> 0) 时 z e^(-w) 由 exp(ln z - w) 求出
+fn lambert_halley(z: Complex64, mut w: Complex64) -> Complex64 {
+    for _ in 0..LAMBERT_HALLEY_MAX_ITER {
+        let s = if w.re > -700.0 {
+            z * (-w).exp()
+        } else {
+            (z.ln() - w).exp()
+        };
+        let t = w - s;
+        let v = w + 1.0;
+        let step = t / (v - (w + 2.0) * t / (2.0 * v));
+        w -= step;
+        if step.norm() <= f64::EPSILON * w.norm() {
+            break;
+        }
+    }
+    w
+}
+
+/// Im z >= +0 时的第 k 分支. 分支点附近 W_0 取 p, W_(-1) 取 -p, 其中 p 取主值, 位于第一象限
+fn lambert_w_upper(k: f64, z: Complex64) -> Complex64 {
+    if z.im == 0.0 {
+        let w = if k == 0.0 {
+            lambert_w0(z.re)
+        } else if k == -1.0 && z.re < 0.0 {
+            lambert_wm1(z.re)
+        } else {
+            f64::NAN
+        };
+        if !w.is_nan() {
+            return Complex64::new(w, 0.0);
+        }
+    }
+    let q = Complex64::new(z.re.mul_add(E, 1.0) + z.re * E_LO, z.im * E);
+    let mut p = (2.0 * q).sqrt();
+    if k == -1.0 {
+        p = -p;
+    }
+    let near_branch = k == 0.0 || k == -1.0;
+    let series = |arr: &[f64]| {
+        arr.iter()
+            .fold(Complex64::new(0.0, 0.0), |acc, &c| acc * p + c)
+    };
+    if near_branch && p.norm() < LAMBERT_BRANCH_RADIUS {
+        return series(&LAMBERT_BRANCH_SERIES);
+    }
+    let guess = if near_branch && p.norm() < LAMBERT_BRANCH_GUESS {
+        series(&LAMBERT_BRANCH_SERIES[20..])
+    } else if k == 0.0 && z.im != 0.0 && (z + 1.0).norm() > 0.5 {
+        let l = (z + 1.0).ln();
+        l * (1.0 - (l + 1.0).ln() / (l + 2.0))
+    } else {
+        let l1 = z.ln() + Complex64::new(0.0, 2.0 * PI * k);
+        let l2 = l1.ln();
+        l1 - l2 + l2 / l1
+    };
+    lambert_halley(z, guess)
+}
+
+/// 计算 Lambert W 函数的主分支 W_0(x), 即 w e^w = x 满足 w >= -1 的实数解, 要求 x >= -1/e
+///
+/// 令 p = √(2(ex + 1)), 其中 ex + 1 借助 e 的高低位拆分与 fma 精确计算. p < 1/4 时直接使用分支点处的
+/// 24 项级数, 在 x = -1/e 附近保持精度; 其余情形以分支点级数 (p < 1) 或 W ≈ L(1 - ln(1 + L)/(2 + L)),
+/// L = ln(1 + x) 为初值做 Fritsch 迭代. 因舍入略小于 -1/e 的 x 视为分支点, 返回 -1; x < -1/e 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::lambert::lambert_w0;
+/// // Ω 常数满足 Ω e^Ω = 1
+/// assert!((lambert_w0(1.0) - 0.5671432904097838).abs() < 1.0e-16);
+/// assert!((lambert_w0(std::f64::consts::E) - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn lambert_w0(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    let p = lambert_branch_distance(x);
+    if p.is_nan() {
+        return f64::NAN;
+    }
+    if p < LAMBERT_BRANCH_RADIUS {
+        return eval_poly(p, &LAMBERT_BRANCH_SERIES);
+    }
+    let guess = if p < LAMBERT_BRANCH_GUESS {
+        lambert_branch_guess(p)
+    } else {
+        let l = x.ln_1p();
+        l * (1.0 - l.ln_1p() / (2.0 + l))
+    };
+    lambert_fritsch(x, guess)
+}
+
+/// 计算 Lambert W 函数的 -1 分支 W_(-1)(x), 即 w e^w = x 满足 w <= -1 的实数解, 要求 -1/e <= x < 0
+///
+/// 分支点附近与 `lambert_w0` 相同, 改用 -p 代入级数; x 接近 0 时以渐近展开 W ≈ L_1 - L_2 + L_2/L_1,
+/// L_1 = ln(-x), L_2 = ln(-L_1) 为初值做 Fritsch 迭代. W_(-1)(0) = -∞, x 不在定义域内时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::lambert::lambert_wm1;
+/// // W_(-1)(-2 e^(-2)) = -2
+/// let x = -2.0 * (-2.0f64).exp();
+/// assert!((lambert_wm1(x) + 2.0).abs() < 1.0e-14);
+/// ```
+pub fn lambert_wm1(x: f64) -> f64 {
+    if x.is_nan() || x > 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let p = lambert_branch_distance(x);
+    if p.is_nan() {
+        return f64::NAN;
+    }
+    if p < LAMBERT_BRANCH_RADIUS {
+        return eval_poly(-p, &LAMBERT_BRANCH_SERIES);
+    }
+    let guess = if p < LAMBERT_BRANCH_GUESS {
+        lambert_branch_guess(-p)
+    } else {
+        let l1 = (-x).ln();
+        let l2 = (-l1).ln();
+        l1 - l2 + l2 / l1
+    };
+    lambert_fritsch(x, guess)
+}
+
+/// 计算 Lambert W 函数的第 k 分支 W_k(z), 即 w e^w = z 的复数解, 分支的划分与割线采用 Corless 等的约定
+///
+/// W_0 的割线为 (-∞, -1/e], 其余分支的割线为 (-∞, 0], W_(-1) 与 W_1 还分别在上下半平面与 W_0 共享分支点 -1/e.
+/// 割线上的点按虚部零的符号取值, 满足 W_k(conj z) = conj W_(-k)(z); 由此只需处理 Im z >= +0.
+/// 实轴上结果为实数时 (W_0 在 [-1/e, ∞), W_(-1) 在 [-1/e, 0)) 使用 `lambert_w0` 与 `lambert_wm1`;
+/// W_0 与 W_(-1) 在分支点附近使用 p 的级数, 其余以 W ≈ ln(1 + z) 的修正 (W_0, |z| < 1/2)
+/// 或渐近展开 W ≈ L_1 - L_2 + L_2/L_1, L_1 = ln z + 2πik, L_2 = ln L_1 为初值做 Halley 迭代.
+/// W_0(0) = 0, 其余分支在 z = 0 处为 -∞; |z| 为无穷时返回 ∞ + i(arg z + 2πk)
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::lambert::lambert_w;
+/// let z = Complex64::new(1.0, 1.0);
+/// for k in -3..=3 {
+///     let w = lambert_w(k, z);
+///     assert!((w * w.exp() - z).norm() < 1.0e-14);
+/// }
+/// ```
+pub fn lambert_w(k: i32, z: Complex64) -> Complex64 {
+    if z.re.is_nan() || z.im.is_nan() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if z.re.is_infinite() || z.im.is_infinite() {
+        return Complex64::new(f64::INFINITY, z.im.atan2(z.re) + 2.0 * PI * k as f64);
+    }
+    if z.re == 0.0 && z.im == 0.0 {
+        return if k == 0 {
+            z
+        } else {
+            Complex64::new(f64::NEG_INFINITY, 0.0)
+        };
+    }
+    if z.im.is_sign_negative() {
+        lambert_w_upper(-(k as f64), z.conj()).conj()
+    } else {
+        lambert_w_upper(k as f64, z)
+    }
+}
+
+/// 计算 Wright ω 函数 ω(x), 即 ω + ln ω = x 的实数解, ω(x) = W_0(e^x)
+///
+/// x <= 700 时为 `lambert_w0(e^x)`, x 充分小时 ω ≈ e^x; x > 700 时 e^x 上溢, 以 ω ≈ x - ln x + ln x / x
+/// 为初值对 ω + ln ω = x 做 Fritsch 迭代. ω(-∞) = 0, ω(+∞) = +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::lambert::wright_omega;
+/// // ω(1) = 1
+/// assert!((wright_omega(1.0) - 1.0).abs() < 1.0e-15);
+/// assert!((wright_omega(1000.0) - 993.0991694723891).abs() < 1.0e-12);
+/// ```
+pub fn wright_omega(x: f64) -> f64 {
+    if x == f64::INFINITY {
+        return x;
+    }
+    if x <= WRIGHT_OMEGA_EXP_MAX || x.is_nan() {
+        return lambert_w0(x.exp());
+    }
+    let l = x.ln();
+    fritsch_iterate(x - l + l / x, |w| x - w - w.ln())
+}
+
+/// 计算复变量的 Wright ω 函数 ω(z), 即 ω + ln ω = z 的解 (ln 取主值), ω(z) = W_K(e^z), K = ⌈(Im z - π)/(2π)⌉
+///
+/// ω 在 Re z <= -1, Im z = ±π 两条射线上不连续, 其余处满足 ω(conj z) = conj ω(z), 由此只需处理 Im z >= 0,
+/// Im z 与 π 的大小借助 π 的高低位拆分精确判断. |Re z| <= 700 时为 `lambert_w(K, e^z)`, 但在分支点 z = -1 + iπ
+/// 附近由 δ = z + 1 - iπ 直接求出 p = √(-2 expm1(δ)) 并使用分支点级数. Re z > 700 时以 ω ≈ z - ln z + ln z / z
+/// 为初值对 ω + ln ω = z 做 Fritsch 迭代; Re z < -700 时 Im z < π 有 ω = e^z, 否则 ω 位于第二象限,
+/// 令 ω = -ν 求解 ν - ln ν = iπ - z 以免 ln ω 在负实轴两侧跳变. 实部或虚部为无穷或 NaN 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::lambert::wright_omega_complex;
+/// let z = Complex64::new(1.0, 5.0);
+/// let w = wright_omega_complex(z);
+/// assert!((w + w.ln() - z).norm() < 1.0e-14);
+/// ```
+pub fn wright_omega_complex(z: Complex64) -> Complex64 {
+    if !z.re.is_finite() || !z.im.is_finite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if z.im < 0.0 {
+        return wright_omega_complex(z.conj()).conj();
+    }
+    // Im z - π
+    let b = (z.im - PI) - PI_LO;
+    if z.re > WRIGHT_OMEGA_EXP_MAX {
+        let l = z.ln();
+        return wright_fritsch(z, z - l + l / z);
+    }
+    if z.re < -WRIGHT_OMEGA_EXP_MAX {
+        if b < 0.0 {
+            return z.exp();
+        }
+        // ω = -ν 位于第二象限, ln ω = ln ν + iπ
+        return -wright_reflected(Complex64::new(-z.re, -b));
+    }
+    // 分支点 z = -1 + iπ 附近 e·e^z + 1 = -expm1(δ), δ = z + 1 - iπ
+    let a = z.re + 1.0;
+    if a.abs() < 1.0 && b.abs() < 1.0 {
+        let (sin_b, cos_b) = b.sin_cos();
+        let half = (0.5 * b).sin();
+        let expm1 = Complex64::new(
+            a.exp_m1().mul_add(cos_b, -2.0 * half * half),
+            a.exp() * sin_b,
+        );
+        let mut p = (-2.0 * expm1).sqrt();
+        if p.norm() < LAMBERT_BRANCH_RADIUS {
+            if b > 0.0 {
+                p = -p;
+            }
+            return LAMBERT_BRANCH_SERIES
+                .iter()
+                .fold(Complex64::new(0.0, 0.0), |acc, &c| acc * p + c);
+        }
+    }
+    lambert_w(wright_unwinding(z.im) as i32, z.exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn test_lambert_w0() {
+        let tol = 1.0e-15;
+        assert_eq!(lambert_w0(0.0), 0.0);
+        assert_eq!(lambert_w0(-1.0 / E), -1.0);
+        assert!(approx_eq(
+            lambert_w0(-0.36787944117144),
+            -0.9999998877164061,
+            tol
+        ));
+        assert!(approx_eq(lambert_w0(-0.36), -0.8060843159708176, tol));
+        assert!(approx_eq(lambert_w0(-0.3), -0.4894022271802149, tol));
+        assert!(approx_eq(lambert_w0(-0.1), -0.11183255915896297, tol));
+        assert!(approx_eq(
+            lambert_w0(-1.0e-10) / -1.0000000001000001e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(lambert_w0(1.0e-10) / 9.999999999e-11, 1.0, tol));
+        assert!(approx_eq(lambert_w0(0.5), 0.35173371124919584, tol));
+        assert!(approx_eq(lambert_w0(10.0), 1.7455280027406994, tol));
+        assert!(approx_eq(lambert_w0(1.0e100) / 224.8431064451185, 1.0, tol));
+        assert!(approx_eq(lambert_w0(1.0e300) / 684.2472086297608, 1.0, tol));
+        assert_eq!(lambert_w0(f64::INFINITY), f64::INFINITY);
+        assert!(lambert_w0(-0.4).is_nan());
+        assert!(lambert_w0(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_lambert_wm1() {
+        let tol = 1.0e-15;
+        assert_eq!(lambert_wm1(-1.0 / E), -1.0);
+        assert!(approx_eq(
+            lambert_wm1(-0.36787944117144),
+            -1.0000001122836022,
+            tol
+        ));
+        assert!(approx_eq(lambert_wm1(-0.36), -1.2227701339785062, tol));
+        assert!(approx_eq(lambert_wm1(-0.3), -1.7813370234216277, tol));
+        assert!(approx_eq(lambert_wm1(-0.1) / -3.577152063957297, 1.0, tol));
+        assert!(approx_eq(
+            lambert_wm1(-1.0e-10) / -26.295238819246926,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            lambert_wm1(-1.0e-310) / -720.3811592879879,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            lambert_wm1(-5.0e-324) / -751.0615595398791,
+            1.0,
+            tol
+        ));
+        assert_eq!(lambert_wm1(0.0), f64::NEG_INFINITY);
+        assert!(lambert_wm1(0.1).is_nan());
+        assert!(lambert_wm1(-0.4).is_nan());
+    }
+    #[test]
+    fn test_lambert_w() {
+        let tol = 1.0e-15;
+        let close =
+            |k: i32, z: Complex64, w: Complex64| (lambert_w(k, z) - w).norm() <= tol * w.norm();
+        assert!(close(
+            0,
+            c(1.0, 1.0),
+            c(0.6569660692304364, 0.32545033941341506)
+        ));
+        assert!(close(
+            1,
+            c(1.0, 1.0),
+            c(-1.3428489407008044, 5.2472493742914015)
+        ));
+        assert!(close(
+            -1,
+            c(1.0, 1.0),
+            c(-0.9869695732212752, -3.663857003284792)
+        ));
+        assert!(close(
+            3,
+            c(-2.0, 5.0),
+            c(-1.27157202827674, 19.16380662965494)
+        ));
+        assert!(close(
+            -5,
+            c(1.0e10, -1.0e10),
+            c(19.76343266714919, -31.19524518647292)
+        ));
+        assert!(close(
+            2,
+            c(0.5, 0.0),
+            c(-3.1049770718920247, 10.713483311301252)
+        ));
+        assert!(close(0, c(1.0e-10, 1.0e-10), c(1.0e-10, 9.999999998e-11)));
+        // 分支点附近
+        assert!(close(
+            0,
+            c(-0.36, 1.0e-3),
+            c(-0.8056759373070329, 0.011520797151639306)
+        ));
+        assert!(close(
+            -1,
+            c(-0.36, 1.0e-3),
+            c(-1.2231736017214014, -0.015220931652646779)
+        ));
+        // 割线两侧
+        let upper = c(-0.31813150520476413, 1.3372357014306895);
+        assert!(close(0, c(-1.0, 0.0), upper));
+        assert!(close(0, c(-1.0, -0.0), upper.conj()));
+        assert!(close(-1, c(-1.0, 0.0), upper.conj()));
+        assert!(close(
+            1,
+            c(-1.0, 0.0),
+            c(-2.062277729598284, 7.588631178472513)
+        ));
+        assert!(close(
+            1,
+            c(-0.3, 0.0),
+            c(-3.3002378364383755, 7.436294411632747)
+        ));
+        assert!(close(1, c(-0.3, -0.0), c(lambert_wm1(-0.3), 0.0)));
+        // 实轴上与实数版本一致
+        assert_eq!(lambert_w(0, c(10.0, 0.0)), c(lambert_w0(10.0), 0.0));
+        assert_eq!(lambert_w(-1, c(-0.3, 0.0)), c(lambert_wm1(-0.3), 0.0));
+        assert_eq!(lambert_w(0, c(0.0, 0.0)), c(0.0, 0.0));
+        assert_eq!(lambert_w(2, c(0.0, 0.0)), c(f64::NEG_INFINITY, 0.0));
+        assert_eq!(
+            lambert_w(1, c(f64::INFINITY, 0.0)),
+            c(f64::INFINITY, 2.0 * PI)
+        );
+        assert!(lambert_w(0, c(f64::NAN, 0.0)).re.is_nan());
+    }
+    #[test]
+    fn test_wright_omega() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(wright_omega(0.0), 0.5671432904097838, tol));
+        assert!(approx_eq(wright_omega(-1.0), 0.2784645427610738, tol));
+        assert!(approx_eq(wright_omega(0.5), 0.7662486081617502, tol));
+        assert!(approx_eq(wright_omega(10.0), 7.929420095019697, tol));
+        assert!(approx_eq(
+            wright_omega(-10.0) / 4.539786874921543e-05,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            wright_omega(1000.0) / 993.0991694723891,
+            1.0,
+            tol
+        ));
+        assert_eq!(wright_omega(1.0e300), 1.0e300);
+        assert_eq!(wright_omega(-1000.0), 0.0);
+        assert_eq!(wright_omega(f64::INFINITY), f64::INFINITY);
+        assert_eq!(wright_omega(f64::NEG_INFINITY), 0.0);
+        assert!(wright_omega(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_wright_omega_complex() {
+        let tol = 1.0e-15;
+        let close =
+            |z: Complex64, w: Complex64| (wright_omega_complex(z) - w).norm() <= tol * w.norm();
+        assert!(close(
+            c(1.0, 5.0),
+            c(-0.21554640750824614, 3.365240224446521)
+        ));
+        assert!(close(c(0.0, 0.0), c(0.5671432904097838, 0.0)));
+        assert!(close(
+            c(-2.0, 1.0),
+            c(0.07766541196354085, 0.09822772794583291)
+        ));
+        assert!(close(
+            c(2.0, -20.0),
+            c(-0.9124721137029447, -18.37959847865106)
+        ));
+        assert!(close(
+            c(800.0, -3.0),
+            c(793.3237614554188, -2.996223220430441)
+        ));
+        assert!(close(
+            c(-800.0, 4.0),
+            c(-806.69294367232, 0.859472773416793)
+        ));
+        // 分支点 -1 ± iπ 附近
+        assert!(close(
+            c(-1.0, PI),
+            c(-0.9999999889336239, 1.1066376015107582e-08)
+        ));
+        assert!(close(
+            c(-1.0, -PI),
+            c(-0.9999999889336239, -1.1066376015107582e-08)
+        ));
+        assert!(close(
+            c(-1.0, PI - 1.0e-3),
+            c(-0.96837899506334, 0.030957866727136053)
+        ));
+        assert!(close(
+            c(-0.99, PI),
+            c(-0.9933318513823238, 0.14134280194063334)
+        ));
+        // 不连续线 Im z = π 两侧, PI 略小于 π
+        assert!(close(
+            c(-2.0, PI),
+            c(-0.15859433956303937, 2.3083045365763377e-17)
+        ));
+        assert!(close(
+            c(-2.0, 3.1415926535897936),
+            c(-3.1461932206205825, 4.71482672643619e-16)
+        ));
+        assert_eq!(wright_omega_complex(c(-800.0, 1.0)), c(0.0, 0.0));
+        assert!(wright_omega_complex(c(f64::INFINITY, 0.0)).re.is_nan());
+    }
+}
@@ -1,3 +1,63 @@
 pub mod utils;
 
 pub mod triangle;
+
+pub mod gamma;
+
+pub mod incomplete_gamma;
+
+pub mod factorial;
+
+pub mod beta;
+
+pub mod incomplete_beta;
+
+pub mod erf;
+
+pub mod faddeeva;
+
+pub mod fresnel;
+
+pub mod normal;
+
+pub mod bessel;
+
+pub mod airy;
+
+pub mod kelvin;
+
+pub mod struve;
+
+pub mod anger_weber;
+
+pub mod bickley;
+
+pub mod elliptic;
+
+pub mod jacobi;
+
+pub mod theta;
+
+pub mod weierstrass;
+
+pub mod modular;
+
+pub mod zeta;
+
+pub mod polylog;
+
+pub mod clausen;
+
+pub mod bernoulli;
+
+pub mod harmonic;
+
+pub mod expint;
+
+pub mod sici;
+
+pub mod lambert;
+
+pub mod abramowitz;
+
+pub mod hypergeometric;
@@ -0,0 +1,268 @@
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// 约化到基本区域的最多步数. 每次反演 τ → -1/τ 至少使 Im τ 增大为原来的 1/|τ|^2 倍, 除非 Im τ 极小, 远少于此数
+const MODULAR_MAX_STEPS: usize = 256;
+
+/// 将 Im τ > 0 的 τ 以模群 SL(2, Z) 的生成元 T: τ → τ + 1, S: τ → -1/τ 约化到基本区域 |Re τ| <= 1/2, |τ| >= 1.
+/// 返回约化后的 τ 与依次执行的步骤 (n, inverted): 先 τ → τ - n, inverted 为 true 时再 τ → -1/τ.
+/// 基本区域内 |q| = |e^(iπτ)| <= e^(-π√3/2) ≈ 0.066, theta 常数的级数收敛极快
+fn modular_reduce(tau: Complex64) -> (Complex64, Vec<(f64, bool)>) {
+    let mut tau = tau;
+    let mut steps = Vec::new();
+    for _ in 0..MODULAR_MAX_STEPS {
+        let n = tau.re.round();
+        tau.re -= n;
+        let inverted = tau.norm_sqr() < 1.0 - 4.0 * f64::EPSILON;
+        if inverted {
+            tau = -1.0 / tau;
+        }
+        steps.push((n, inverted));
+        if !inverted {
+            break;
+        }
+    }
+    (tau, steps)
+}
+
+/// 计算 q = e^(iπτ) 及 theta 常数 (Σ_(n>=0) q^(n(n+1)), θ_3(0, q), θ_4(0, q)), 其中 θ_2(0, q) = 2 q^(1/4) Σ_(n>=0) q^(n(n+1)).
+/// 将 q^(1/4) 分离出来, 使 θ_2^4 = 16 q (Σ)^4 中不出现 q^(1/4) 的分支选取
+fn theta_constants(tau: Complex64) -> (Complex64, Complex64, Complex64, Complex64) {
+    let q = (Complex64::new(0.0, PI) * tau).exp();
+    let q2 = q * q;
+    let one = Complex64::new(1.0, 0.0);
+    let (mut t2, mut t3, mut t4) = (one, one, one);
+    // odd = q^(n(n+1)), even = q^(n^2)
+    let (mut odd, mut even) = (one, one);
+    let (mut step_odd, mut step_even) = (one, q);
+    let mut n = 0;
+    while even.norm() > 0.5 * f64::EPSILON {
+        n += 1;
+        even *= step_even;
+        step_even *= q2;
+        step_odd *= q2;
+        odd *= step_odd;
+        let sign = if n % 2 == 0 { 2.0 } else { -2.0 };
+        t2 += odd;
+        t3 += 2.0 * even;
+        t4 += sign * even;
+    }
+    (q, t2, t3, t4)
+}
+
+/// 计算模 lambda 函数 λ(τ) = θ_2^4(0, q) / θ_3^4(0, q), q = e^(iπτ), 要求 Im τ > 0
+///
+/// λ 在模群的子群 Γ(2) 下不变, 在生成元下满足 λ(τ + 1) = λ/(λ - 1), λ(-1/τ) = 1 - λ. 先将 τ 约化到 SL(2, Z) 的
+/// 基本区域, 由收敛极快的 theta 级数同时计算 λ 与 1 - λ = θ_4^4/θ_3^4, 再按约化步骤逆序作用
+/// (λ, 1 - λ) → (1 - λ, λ) 与 (λ, 1 - λ) → (-λ/(1 - λ), 1/(1 - λ)), 全程不出现 1 - λ 的相消.
+/// Im τ <= 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::modular::modular_lambda;
+/// let l = modular_lambda(Complex64::new(0.1, 0.5));
+/// assert!((l - Complex64::new(0.985975015698046, 0.035090212573133335)).norm() < 1.0e-15);
+/// ```
+pub fn modular_lambda(tau: Complex64) -> Complex64 {
+    if tau.im.is_nan() || tau.im <= 0.0 || !tau.re.is_finite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if tau.im.is_infinite() {
+        return Complex64::new(0.0, 0.0);
+    }
+    let (reduced, steps) = modular_reduce(tau);
+    let (q, t2, t3, t4) = theta_constants(reduced);
+    let mut lambda = 16.0 * q * (t2 / t3).powi(4);
+    let mut complement = (t4 / t3).powi(4);
+    for &(n, inverted) in steps.iter().rev() {
+        if inverted {
+            (lambda, complement) = (complement, lambda);
+        }
+        if n % 2.0 != 0.0 {
+            (lambda, complement) = (-lambda / complement, 1.0 / complement);
+        }
+    }
+    lambda
+}
+
+/// 计算 Klein j 不变量 j(τ) = 32 (θ_2^8 + θ_3^8 + θ_4^8)^3 / (θ_2 θ_3 θ_4)^8, 要求 Im τ > 0
+///
+/// 取 j(i) = 1728 的通常归一化, 即 j(τ) = 1/q^2 + 744 + 196884 q^2 + ..., Klein 绝对不变量 J = j/1728.
+/// j 在模群 SL(2, Z) 下不变, 先将 τ 约化到基本区域再由 theta 常数计算. Im τ 很大 (约 113 以上) 时上溢,
+/// Im τ <= 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::modular::klein_j;
+/// let j = klein_j(Complex64::new(0.0, 1.0));
+/// assert!((j - Complex64::new(1728.0, 0.0)).norm() < 1.0e-11);
+/// ```
+pub fn klein_j(tau: Complex64) -> Complex64 {
+    if tau.im.is_nan() || tau.im <= 0.0 || !tau.re.is_finite() || tau.im.is_infinite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    let (reduced, _) = modular_reduce(tau);
+    let (q, t2, t3, t4) = theta_constants(reduced);
+    // θ_2^8 = 256 q^2 t2^8
+    let q2 = q * q;
+    let sum = 256.0 * q2 * t2.powi(8) + t3.powi(8) + t4.powi(8);
+    let value = sum * sum * sum / (8.0 * (t2 * t3 * t4).powi(8));
+    // 1/q^2 = e^(-2πiτ) 以相位与模长 e^(2π Im τ) 分别相乘: 复数除法会计算 |q^2|^2, Im τ > 56 时即下溢.
+    // 模长拆为两个因子, 以免结果有限时提前上溢
+    let value = value * Complex64::from_polar(1.0, -2.0 * PI * reduced.re);
+    let half = (PI * reduced.im).exp();
+    Complex64::new(value.re * half * half, value.im * half * half)
+}
+
+/// 计算 Dedekind eta 函数 η(τ) = e^(iπτ/12) Π_(n>=1) (1 - e^(2πinτ)), 要求 Im τ > 0
+///
+/// 由 Euler 五边形数定理 η(τ) = e^(iπτ/12) Σ_(n∈Z) (-1)^n x^(n(3n-1)/2), x = e^(2πiτ) 求和. |q| 接近 1 时该级数
+/// 收敛很慢, 先将 τ 约化到模群的基本区域 (此时 |x| <= e^(-π√3) ≈ 0.0043), 再由变换关系
+/// η(τ + 1) = e^(iπ/12) η(τ), η(-1/τ) = √(-iτ) η(τ) 还原. 以对数累积各步的因子, 避免中间结果上溢或下溢.
+/// Im τ <= 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::modular::dedekind_eta;
+/// let eta = dedekind_eta(Complex64::new(0.0, 1.0));
+/// assert!((eta - Complex64::new(0.7682254223260566, 0.0)).norm() < 1.0e-15);
+/// ```
+pub fn dedekind_eta(tau: Complex64) -> Complex64 {
+    if tau.im.is_nan() || tau.im <= 0.0 || !tau.re.is_finite() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if tau.im.is_infinite() {
+        return Complex64::new(0.0, 0.0);
+    }
+    let (reduced, steps) = modular_reduce(tau);
+    // 按约化步骤重放 τ 的变换, 累积 ln √(-iτ) 与 e^(iπn/12) 的相位 (n 按模 24 累计)
+    let mut current = tau;
+    let mut log_factor = Complex64::new(0.0, 0.0);
+    let mut shift = 0.0;
+    for &(n, inverted) in &steps {
+        current.re -= n;
+        shift = (shift + n).rem_euclid(24.0);
+        if inverted {
+            current = -1.0 / current;
+            log_factor += 0.5 * (Complex64::new(0.0, -1.0) * current).ln();
+        }
+    }
+    log_factor += Complex64::new(0.0, PI * shift / 12.0);
+    let x = (Complex64::new(0.0, 2.0 * PI) * reduced).exp();
+    let one = Complex64::new(1.0, 0.0);
+    let mut sum = one;
+    // minus = x^(n(3n-1)/2), plus = x^(n(3n+1)/2)
+    let (mut minus, mut plus) = (one, one);
+    let mut n = 0.0;
+    while plus.norm() > 0.5 * f64::EPSILON * sum.norm() {
+        n += 1.0;
+        minus *= x.powi(3 * n as i32 - 2);
+        plus = minus * x.powi(n as i32);
+        let sign = if n % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        sum += sign * (minus + plus);
+    }
+    (Complex64::new(0.0, PI / 12.0) * reduced + log_factor).exp() * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modular_lambda() {
+        let tol = 1.0e-14;
+        let c = Complex64::new;
+        assert!((modular_lambda(c(0.0, 1.0)) - c(0.5, 0.0)).norm() < tol);
+        assert!((modular_lambda(c(1.0, 1.0)) - c(-1.0, 0.0)).norm() < tol);
+        assert!(
+            (modular_lambda(c(2.3, 0.2)) - c(0.9251025745226056, 0.09733437564619535)).norm() < tol
+        );
+        assert!(
+            (modular_lambda(c(-0.7, 0.07)) - c(1.1620515849890547, 0.2515664969986108)).norm()
+                < tol
+        );
+        let l = modular_lambda(c(0.0, 5.0));
+        assert!((l / c(2.4112247336171454e-06, 0.0) - 1.0).norm() < tol);
+        // λ(τ + 2) = λ(τ), λ(-1/τ) = 1 - λ(τ)
+        let tau = c(0.3, 0.4);
+        assert!((modular_lambda(tau + 2.0) - modular_lambda(tau)).norm() < tol);
+        assert!((modular_lambda(-1.0 / tau) + modular_lambda(tau) - 1.0).norm() < tol);
+        assert!(modular_lambda(c(0.5, 0.0)).re.is_nan());
+        assert!(modular_lambda(c(0.5, -1.0)).re.is_nan());
+    }
+
+    #[test]
+    fn test_klein_j() {
+        let c = Complex64::new;
+        let close =
+            |tau: Complex64, expected: Complex64| (klein_j(tau) / expected - 1.0).norm() < 1.0e-14;
+        assert!(close(c(0.0, 1.0), c(1728.0, 0.0)));
+        assert!(close(
+            c(0.1, 0.5),
+            c(-131629.83029532523, 117271.5045604853)
+        ));
+        assert!(close(
+            c(2.3, 0.2),
+            c(-4855.820311312892, 14742.066710322224)
+        ));
+        assert!(close(
+            c(-0.7, 0.07),
+            c(35.67111461957317, -3266.290953558924)
+        ));
+        assert!(close(c(0.0, 5.0), c(44031505861376.03, 0.0)));
+        // 约化后 Im τ 很大, |j| 超过 1e154; 期望值的模平方上溢, 不能用复数除法比较.
+        // e^(2π Im τ) 的相对误差约为 2π Im τ ε
+        let far = |tau: Complex64, expected: Complex64| {
+            (klein_j(tau) - expected).norm() / expected.norm() < 1.0e-13
+        };
+        assert!(far(c(0.0, 60.0), c(5.310830748060338e163, 0.0)));
+        assert!(far(
+            c(0.001, 0.01),
+            c(1.211834139543992e270, -8.689824709482753e269)
+        ));
+        let j = klein_j(c(0.5, 0.003));
+        assert!((j.re / -2.489195307707012e227 - 1.0).abs() < 1.0e-13);
+        assert!(klein_j(c(0.0, 200.0)).re.is_infinite());
+        // j(e^(2πi/3)) = 0
+        assert!(klein_j(c(-0.5, 0.75f64.sqrt())).norm() < 1.0e-9);
+        let tau = c(0.3, 0.4);
+        assert!((klein_j(-1.0 / (tau + 5.0)) / klein_j(tau) - 1.0).norm() < 1.0e-13);
+        assert!(klein_j(c(0.5, 0.0)).re.is_nan());
+    }
+    #[test]
+    fn test_dedekind_eta() {
+        let c = Complex64::new;
+        let close = |tau: Complex64, expected: Complex64| {
+            (dedekind_eta(tau) / expected - 1.0).norm() < 1.0e-14
+        };
+        assert!(close(c(0.0, 1.0), c(0.7682254223260566, 0.0)));
+        assert!(close(
+            c(0.3, 0.05),
+            c(1.5644264457324133, -0.17705529841961695)
+        ));
+        assert!(close(
+            c(-1.7, 0.4),
+            c(0.8091329673036352, -0.45895511024353625)
+        ));
+        assert!(close(c(0.0, 10.0), c(0.07294906084933912, 0.0)));
+        assert!(close(
+            c(100.25, 2.0),
+            c(0.262006970975171, 0.5312929082451883)
+        ));
+        assert!(close(
+            c(0.5, 0.01),
+            c(0.010076340416720153, 0.001326575382402029)
+        ));
+        // η(-1/τ) = √(-iτ) η(τ)
+        let tau = c(0.2, 0.3);
+        let expected = (c(0.0, -1.0) * tau).sqrt() * dedekind_eta(tau);
+        assert!((dedekind_eta(-1.0 / tau) / expected - 1.0).norm() < 1.0e-14);
+        assert!(dedekind_eta(c(0.5, 0.0)).re.is_nan());
+    }
+}
@@ -0,0 +1,716 @@
+use crate::gamma::{
+    digamma, ln_gamma_diff_positive, ln_gamma_sign, BERNOULLI_OVER_FACTORIAL, EULER,
+};
+use crate::triangle::{cospi, sinpi};
+use crate::zeta::{zeta, zeta_minus_pole};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// π^2/6 = ζ(2)
+const PI2_6: f64 = PI * PI / 6.0;
+
+/// 级数求和的最大项数
+const POLYLOG_MAX_TERMS: usize = 1000;
+
+/// -1 <= x <= 1/2 时以 u = -ln(1 - x) 为变量计算 Li_2(x) = u - u^2/4 + Σ_(k>=1) B_(2k) u^(2k+1) / (2k + 1)!.
+/// |u| <= ln 2, 级数的项按 (u/(2π))^2 衰减, 十项以内即达到机器精度
+fn dilog_series(x: f64) -> f64 {
+    let u = -(-x).ln_1p();
+    let u2 = u * u;
+    let mut sum = 0.0;
+    let mut power = u * u2;
+    for (j, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate() {
+        let term = c * power / (2 * j + 3) as f64;
+        sum += term;
+        if term.abs() <= 0.5 * f64::EPSILON * u.abs() {
+            break;
+        }
+        power *= u2;
+    }
+    u - 0.25 * u2 + sum
+}
+
+/// 计算双对数函数 (Spence 函数) Li_2(x) = -∫_0^x ln(1 - t)/t dt = Σ_(k>=1) x^k / k^2, x 为任意实数
+///
+/// 由反演公式 Li_2(x) + Li_2(1/x) = -π^2/6 - ln^2(-x)/2 与反射公式 Li_2(x) + Li_2(1 - x) = π^2/6 - ln x ln(1 - x)
+/// 将 x 约化到 [-1, 1/2], 再由 u = -ln(1 - x) 的 Bernoulli 级数计算. x > 1 时 Li_2(x) 有虚部 -π ln x (取割线下沿时
+/// 为 +π ln x), 返回其实部 Re Li_2(x). Li_2(1) = π^2/6, Li_2(±∞) 的实部为 -∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::polylog::dilog;
+/// assert!((dilog(0.5) - 0.5822405264650125).abs() < 1.0e-15);
+/// ```
+pub fn dilog(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return f64::NEG_INFINITY;
+    }
+    if x < -1.0 {
+        let l = (-x).ln();
+        return -PI2_6 - 0.5 * l * l - dilog_series(1.0 / x);
+    }
+    if x <= 0.5 {
+        return dilog_series(x);
+    }
+    if x < 1.0 {
+        return PI2_6 - x.ln() * (-x).ln_1p() - dilog_series(1.0 - x);
+    }
+    if x == 1.0 {
+        return PI2_6;
+    }
+    if x <= 2.0 {
+        return PI2_6 - x.ln() * (x - 1.0).ln() - dilog_series(1.0 - x);
+    }
+    let l = x.ln();
+    2.0 * PI2_6 - 0.5 * l * l - dilog(1.0 / x)
+}
+
+/// 计算 Spence 函数 spence(x) = Li_2(1 - x) = ∫_1^x ln t/(1 - t) dt, 约定与 SciPy 的 `scipy.special.spence` 相同, 要求 x >= 0
+///
+/// 不先计算 1 - x 再调用 `dilog`, 以免 x 很小时丢失精度: x < 1/2 时使用反射公式
+/// Li_2(1 - x) = π^2/6 - ln x ln(1 - x) - Li_2(x); 1/2 <= x <= 2 时 1 - x 的减法是精确的, 直接使用 Bernoulli 级数;
+/// x > 2 时使用反演公式 Li_2(1 - x) = -π^2/6 - ln^2(x - 1)/2 - Li_2(1/(1 - x)). x < 0 时 Li_2(1 - x) 为复数, 返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::polylog::spence;
+/// // spence(0) = Li_2(1) = π^2/6, spence(1) = 0
+/// assert!((spence(0.0) - std::f64::consts::PI.powi(2) / 6.0).abs() < 1.0e-15);
+/// assert_eq!(spence(1.0), 0.0);
+/// ```
+pub fn spence(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return PI2_6;
+    }
+    if x < 0.5 {
+        return PI2_6 - x.ln() * (-x).ln_1p() - dilog_series(x);
+    }
+    if x <= 2.0 {
+        return dilog_series(1.0 - x);
+    }
+    let l = (x - 1.0).ln();
+    -PI2_6 - 0.5 * l * l - dilog_series(1.0 / (1.0 - x))
+}
+
+/// 复数 w 的 ln(1 + w). |w| < 1/2 时实部写作 ln(1 + 2 Re w + |w|^2)/2, 以保留 w 很小时的相对精度;
+/// 1 + w 以实数加法计算, 保留虚部零的符号
+fn complex_ln_1p(w: Complex64) -> Complex64 {
+    if w.norm() < 0.5 {
+        return Complex64::new(
+            0.5 * (2.0 * w.re + w.norm_sqr()).ln_1p(),
+            w.im.atan2(1.0 + w.re),
+        );
+    }
+    (w + 1.0).ln()
+}
+
+/// 复数 z 的 Bernoulli 级数 Li_2(z) = u - u^2/4 + Σ_(k>=1) B_(2k) u^(2k+1) / (2k + 1)!, u = -ln(1 - z).
+/// 要求 |z| <= 1 且 Re z <= 1/2, 此时 |u| < 1.1, 项按 (|u|/(2π))^2 < 0.031 衰减, 十二项以内即达到机器精度
+fn dilog_series_complex(z: Complex64) -> Complex64 {
+    let u = -complex_ln_1p(-z);
+    let u2 = u * u;
+    let mut sum = Complex64::new(0.0, 0.0);
+    let mut power = u * u2;
+    for (j, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate() {
+        let term = power * (c / (2 * j + 3) as f64);
+        sum += term;
+        if term.norm() <= 0.5 * f64::EPSILON * u.norm() {
+            break;
+        }
+        power *= u2;
+    }
+    u - u2 * 0.25 + sum
+}
+
+/// 计算复数 z 的双对数函数 Li_2(z) = -∫_0^z ln(1 - t)/t dt, 取主值分支, 割线为 [1, +∞)
+///
+/// |z| > 1 时由反演公式 Li_2(z) = -Li_2(1/z) - π^2/6 - ln^2(-z)/2 化到单位圆内, Re z > 1/2 时由反射公式
+/// Li_2(z) = π^2/6 - ln z ln(1 - z) - Li_2(1 - z) 化到 Re z <= 1/2, 再计算 u = -ln(1 - z) 的 Bernoulli 级数.
+/// 割线上的点按虚部零的符号取值: z = x + 0i (x > 1) 时虚部为 +π ln x, z = x - 0i 时为 -π ln x
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::polylog::dilog_complex;
+/// let w = dilog_complex(Complex64::new(0.5, 1.0));
+/// assert!((w - Complex64::new(0.20335376120592422, 1.131939585677709)).norm() < 1.0e-15);
+/// ```
+pub fn dilog_complex(z: Complex64) -> Complex64 {
+    if z.re.is_nan() || z.im.is_nan() {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if z == Complex64::new(1.0, 0.0) {
+        return Complex64::new(PI2_6, 0.0);
+    }
+    if z.norm() > 1.0 {
+        let l = (-z).ln();
+        return -dilog_complex(z.inv()) - PI2_6 - l * l * 0.5;
+    }
+    if z.re > 0.5 {
+        let w = Complex64::new(1.0, 0.0) - z;
+        return -z.ln() * w.ln() + PI2_6 - dilog_series_complex(w);
+    }
+    dilog_series_complex(z)
+}
+
+/// 直接求和 Li_s(x) = Σ_(k>=1) x^k / k^s, 要求 |x| <= 1/2. s < 0 时项的绝对值先增后减, 越过最大项后才判断截断
+fn polylog_series(s: f64, x: f64) -> f64 {
+    let peak = if s < 0.0 { s / x.abs().ln() } else { 0.0 };
+    let mut sum = 0.0;
+    let mut power = 1.0;
+    for k in 1..=POLYLOG_MAX_TERMS {
+        let fk = k as f64;
+        power *= x;
+        let term = power * fk.powf(-s);
+        sum += term;
+        if fk > peak && term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+/// 复数 z 的直接求和 Li_s(z) = Σ_(k>=1) z^k / k^s, 要求 |z| <= 1/2
+fn polylog_series_complex(s: f64, z: Complex64) -> Complex64 {
+    let peak = if s < 0.0 { s / z.norm().ln() } else { 0.0 };
+    let mut sum = Complex64::new(0.0, 0.0);
+    let mut power = Complex64::new(1.0, 0.0);
+    for k in 1..=POLYLOG_MAX_TERMS {
+        let fk = k as f64;
+        power *= z;
+        let term = power * fk.powf(-s);
+        sum += term;
+        if fk > peak && term.norm() <= 0.5 * f64::EPSILON * sum.norm() {
+            break;
+        }
+    }
+    sum
+}
+
+/// Euler 数 A(n, k), k = 0, ..., n - 1, 由 A(n, k) = (k + 1) A(n-1, k) + (n - k) A(n-1, k-1) 逐行递推
+fn eulerian_row(n: usize) -> Vec<f64> {
+    let mut row = vec![1.0; n];
+    for m in 2..=n {
+        for k in (1..m - 1).rev() {
+            row[k] = (k + 1) as f64 * row[k] + (m - k) as f64 * row[k - 1];
+        }
+    }
+    row
+}
+
+/// 负整数阶的有理函数形式 Li_(-n)(x) = x Σ_(k=0)^(n-1) A(n, k) x^k / (1 - x)^(n+1), A(n, k) 为 Euler 数.
+/// |x| > 1 时使用 Li_(-n)(x) = (-1)^(n+1) Li_(-n)(1/x)
+fn polylog_negative_integer(n: usize, x: f64) -> f64 {
+    if n == 0 {
+        return x / (1.0 - x);
+    }
+    if x.abs() > 1.0 {
+        let value = polylog_negative_integer(n, 1.0 / x);
+        return if n.is_multiple_of(2) { -value } else { value };
+    }
+    let mut sum = 0.0;
+    for a in eulerian_row(n).iter().rev() {
+        sum = sum * x + a;
+    }
+    x * sum / (1.0 - x).powi(n as i32 + 1)
+}
+
+/// 复数 z 的负整数阶有理函数形式, 同 `polylog_negative_integer`
+fn polylog_negative_integer_complex(n: usize, z: Complex64) -> Complex64 {
+    let one = Complex64::new(1.0, 0.0);
+    if n == 0 {
+        return z / (one - z);
+    }
+    if z.norm() > 1.0 {
+        let value = polylog_negative_integer_complex(n, z.inv());
+        return if n.is_multiple_of(2) { -value } else { value };
+    }
+    let mut sum = Complex64::new(0.0, 0.0);
+    for a in eulerian_row(n).iter().rev() {
+        sum = sum * z + a;
+    }
+    z * sum / (one - z).powi(n as i32 + 1)
+}
+
+/// 复数 w 的 e^w - 1, 实部分解为 expm1(Re w) cos(Im w) - 2 sin^2(Im w / 2) 以保留 w 很小时的相对精度
+pub(crate) fn complex_exp_m1(w: Complex64) -> Complex64 {
+    let half_sin = (0.5 * w.im).sin();
+    Complex64::new(
+        w.re.exp_m1() * w.im.cos() - 2.0 * half_sin * half_sin,
+        w.re.exp() * w.im.sin(),
+    )
+}
+
+/// 对数级数中 Γ(1 - s)(-μ)^(s-1) 与 ζ(s - n) μ^n/n! 两项之和除以 μ^n/n!, 其中 s = n + 1 + δ, ln_neg_mu = ln(-μ).
+///
+/// 由余元公式 Γ(-n-δ) = (-1)^(n+1) π / (sin(πδ) Γ(n + 1 + δ)), 两项之和为
+/// ζ(1 + δ) - 1/δ - [exp(δ ln(-μ) + ln(πδ / sin πδ) - ln(Γ(n + 1 + δ) / n!)) - 1] / δ,
+/// 其中两个极点 1/δ 已解析地相消. δ = 0 时极限为 H_n - ln(-μ)
+fn polylog_pole_pair(n: f64, delta: f64, ln_neg_mu: Complex64) -> Complex64 {
+    if delta == 0.0 {
+        return Complex64::new(EULER + digamma(n + 1.0), 0.0) - ln_neg_mu;
+    }
+    // ln(πδ / sin πδ) = Σ_(k>=1) ζ(2k) δ^(2k) / k
+    let ln_sinc = if delta.abs() < 0.05 {
+        let delta2 = delta * delta;
+        let mut sum = 0.0;
+        let mut power = 1.0;
+        for k in 1..=POLYLOG_MAX_TERMS {
+            power *= delta2;
+            let term = zeta(2.0 * k as f64) * power / k as f64;
+            sum += term;
+            if term <= 0.5 * f64::EPSILON * sum {
+                break;
+            }
+        }
+        sum
+    } else {
+        (PI * delta / sinpi(delta)).ln()
+    };
+    let w = ln_neg_mu * delta + (ln_sinc - ln_gamma_diff_positive(n + 1.0, delta));
+    zeta_minus_pole(delta) - complex_exp_m1(w) / delta
+}
+
+/// |μ| < 2π 时的对数级数 Li_s(e^μ) = Γ(1 - s)(-μ)^(s-1) + Σ_(k>=0) ζ(s - k) μ^k / k!, 取主值分支.
+/// s >= 1/2 时与 Γ(1 - s) 的极点相消的一项 k = round(s) - 1 由 `polylog_pole_pair` 合并计算; 整数 s 的 ζ(s - k)
+/// 每隔一项为零, 因此以相邻两项判断截断. 级数的项按 k^(-s) (|μ| / 2π)^k 衰减
+fn polylog_log_series(s: f64, mu: Complex64) -> Complex64 {
+    let ln_neg_mu = (-mu).ln();
+    let pole = s.round() - 1.0;
+    let mut sum = Complex64::new(0.0, 0.0);
+    if pole < 0.0 {
+        let (ln_gamma_value, sign) = ln_gamma_sign(1.0 - s);
+        sum += ((s - 1.0) * ln_neg_mu + ln_gamma_value).exp() * sign as f64;
+    }
+    let peak = if s < 0.0 {
+        s / (mu.norm() / (2.0 * PI)).ln()
+    } else {
+        0.0
+    };
+    let mut power = Complex64::new(1.0, 0.0);
+    let mut previous = 0.0;
+    for k in 0..POLYLOG_MAX_TERMS {
+        let fk = k as f64;
+        if k > 0 {
+            power *= mu / fk;
+        }
+        let term = if fk == pole {
+            power * polylog_pole_pair(pole, s - 1.0 - pole, ln_neg_mu)
+        } else {
+            power * zeta(s - fk)
+        };
+        sum += term;
+        let size = term.norm();
+        if fk > pole && fk > peak && size + previous <= 0.5 * f64::EPSILON * sum.norm() {
+            break;
+        }
+        previous = size;
+    }
+    sum
+}
+
+/// 复数 a (Re a > 0) 的 Hurwitz zeta 函数 ζ(1 - s, a) 的 Euler–Maclaurin 求和, 记 σ = 1 - s: 先直接求和 shift 项,
+/// 再以 b = a + shift 处的余项 b^s/(-s) + b^(-σ)/2 + Σ_j B_(2j)/(2j)! σ(σ+1)...(σ+2j-2) b^(1-σ-2j) 补足.
+/// 余项的分母直接使用 -s 而非 σ - 1, 以免 s 接近 0 时 1 - s 的舍入误差被极点放大
+fn hurwitz_zeta_reflected(s: f64, a: Complex64, shift: usize) -> Complex64 {
+    let sigma = 1.0 - s;
+    let mut sum = Complex64::new(0.0, 0.0);
+    for k in 0..shift {
+        sum += (a + k as f64).powf(-sigma);
+    }
+    let b = a + shift as f64;
+    let b_pow = b.powf(-sigma);
+    sum += b_pow * b / -s + b_pow * 0.5;
+    let inv_b2 = (b * b).inv();
+    let mut factor = b_pow * sigma / b;
+    for (j, c) in BERNOULLI_OVER_FACTORIAL.iter().enumerate() {
+        let term = factor * *c;
+        sum += term;
+        if term.norm() <= 0.5 * f64::EPSILON * sum.norm() {
+            break;
+        }
+        let m = sigma + (2 * j + 1) as f64;
+        factor *= m * (m + 1.0) * inv_b2;
+    }
+    sum
+}
+
+/// |ln z| 较大时的 Jonquière 反演公式 Li_s(z) = (2π)^s e^(iπs/2) / Γ(s) ζ(1 - s, a) - e^(iπs) Li_s(1/z),
+/// a = 1/2 + ln(-z)/(2πi), 对 z ∉ [0, 1] 成立, 且 Re a ∈ [0, 1]. s > 1 时 ζ(1 - s, a) 的 Euler–Maclaurin 求和有相消,
+/// 直接求和的项数取为使 |a + N| >= 4.5 的最小值, 在截断误差与相消之间折中
+fn polylog_inversion(s: f64, z: Complex64) -> Complex64 {
+    let l = (-z).ln();
+    let a = Complex64::new(0.5 + l.im / (2.0 * PI), -l.re / (2.0 * PI));
+    let target = if s < 1.0 { (11.0 - s).max(15.0) } else { 4.5 };
+    let mut shift = 0;
+    while (a + shift as f64).norm() < target {
+        shift += 1;
+    }
+    let (ln_gamma_value, sign) = ln_gamma_sign(s);
+    let factor = Complex64::new(cospi(0.5 * s), sinpi(0.5 * s))
+        * (s * (2.0 * PI).ln() - ln_gamma_value).exp()
+        * sign as f64;
+    factor * hurwitz_zeta_reflected(s, a, shift)
+        - Complex64::new(cospi(s), sinpi(s)) * polylog_complex(s, z.inv())
+}
+
+/// 正整数阶 n, |x| > 1 时的反演公式, 其中 L = ln|x|:
+/// x < -1 时 Li_n(x) = -(-1)^n Li_n(1/x) - L^n/n! - 2 Σ_(j=1)^(⌊n/2⌋) η(2j) L^(n-2j)/(n-2j)!,
+/// x > 1 时 Re Li_n(x) = -(-1)^n Li_n(1/x) - L^n/n! + 2 Σ_(j=1)^(⌊n/2⌋) ζ(2j) L^(n-2j)/(n-2j)!,
+/// 其中 η(2j) = (1 - 2^(1-2j)) ζ(2j)
+fn polylog_integer_inversion(n: u32, x: f64) -> f64 {
+    let l = x.abs().ln();
+    let l2 = l * l;
+    // power = L^m / m!, m 从 n mod 2 开始每次增加 2
+    let mut m = n % 2;
+    let mut power = if m == 0 { 1.0 } else { l };
+    let mut sum = 0.0;
+    for j in (1..=n / 2).rev() {
+        let zeta_2j = zeta(2.0 * j as f64);
+        let c = if x < 0.0 {
+            -(1.0 - 2.0_f64.powi(1 - 2 * j as i32)) * zeta_2j
+        } else {
+            zeta_2j
+        };
+        sum += 2.0 * c * power;
+        power *= l2 / ((m + 1) * (m + 2)) as f64;
+        m += 2;
+    }
+    let inverse = polylog(n as f64, 1.0 / x);
+    sum - power
+        - if n.is_multiple_of(2) {
+            inverse
+        } else {
+            -inverse
+        }
+}
+
+/// 计算实数阶 s 的多重对数函数 Li_s(x) = Σ_(k>=1) x^k / k^s, x 为实数
+///
+/// 级数在 |x| <= 1/2 时直接求和; 非正整数 s 使用 Euler 数给出的有理函数形式; 其余情形在 |ln x| 不太大时使用
+/// ln x 的幂级数 Li_s(e^μ) = Γ(1 - s)(-μ)^(s-1) + Σ_(k>=0) ζ(s - k) μ^k / k!, 当 s 接近正整数时将相互抵消的两个
+/// 极点合并计算; |ln x| 较大时由反演公式化为 Li_s(1/x), 正整数 s 时反演公式只含 ζ(2j) (即 Bernoulli 数),
+/// 非整数 s 时则含复参数的 Hurwitz zeta 函数. s = 1, 2 时分别为 -ln(1 - x) 和 `dilog`.
+/// x > 1 时 Li_s(x) 有虚部, 返回其实部 Re Li_s(x) (割线两侧的实部相同); Li_s(1) = ζ(s), s <= 1 时返回 +∞.
+/// s > 1 且 |ln x| > 5 时反演公式中存在相消, 大 s 时精度有所下降
+///
+/// # Example
+///
+/// ```
+/// use special_functions::polylog::polylog;
+/// assert!((polylog(3.0, 0.5) - 0.5372131936080402).abs() < 1.0e-15);
+/// ```
+pub fn polylog(s: f64, x: f64) -> f64 {
+    if s.is_nan() || x.is_nan() || s == f64::NEG_INFINITY {
+        return f64::NAN;
+    }
+    if x == 0.0 || s == f64::INFINITY {
+        return x;
+    }
+    let negative_integer = s <= 0.0 && s == s.floor();
+    if x.is_infinite() {
+        return if s == 0.0 {
+            -1.0
+        } else if s < 0.0 {
+            0.0
+        } else {
+            f64::NEG_INFINITY
+        };
+    }
+    if x == 1.0 {
+        return if s > 1.0 { zeta(s) } else { f64::INFINITY };
+    }
+    if negative_integer {
+        return polylog_negative_integer(-s as usize, x);
+    }
+    if s == 1.0 {
+        return if x < 1.0 {
+            -(-x).ln_1p()
+        } else {
+            -(x - 1.0).ln()
+        };
+    }
+    if s == 2.0 {
+        return dilog(x);
+    }
+    if x.abs() > 1.0 && s == s.floor() && s < u32::MAX as f64 {
+        return polylog_integer_inversion(s as u32, x);
+    }
+    if x.abs() <= 0.5 {
+        return polylog_series(s, x);
+    }
+    let mu = Complex64::new(x.abs().ln(), if x < 0.0 { PI } else { 0.0 });
+    let radius = if s < 1.0 { PI + 0.25 } else { 5.0 };
+    if mu.norm() < radius {
+        return polylog_log_series(s, mu).re;
+    }
+    // 取 x - 0i, 使 x < -1 时 ln(-x) 为实数; x > 1 时割线两侧的实部相同
+    polylog_inversion(s, Complex64::new(x, -0.0)).re
+}
+
+/// 正整数阶 n, |z| > 1 时的反演公式 Li_n(z) = -(-1)^n Li_n(1/z) - L^n/n! - 2 Σ_(j=1)^(⌊n/2⌋) η(2j) L^(n-2j)/(n-2j)!,
+/// 其中 L = ln(-z) 取主值, 对 z ∉ (0, 1] 成立
+fn polylog_integer_inversion_complex(n: u32, z: Complex64) -> Complex64 {
+    let l = (-z).ln();
+    let l2 = l * l;
+    let mut m = n % 2;
+    let mut power = if m == 0 { Complex64::new(1.0, 0.0) } else { l };
+    let mut sum = Complex64::new(0.0, 0.0);
+    for j in (1..=n / 2).rev() {
+        let eta_2j = (1.0 - 2.0_f64.powi(1 - 2 * j as i32)) * zeta(2.0 * j as f64);
+        sum -= power * (2.0 * eta_2j);
+        power *= l2 / ((m + 1) * (m + 2)) as f64;
+        m += 2;
+    }
+    let inverse = polylog_complex(n as f64, z.inv());
+    sum - power
+        - if n.is_multiple_of(2) {
+            inverse
+        } else {
+            -inverse
+        }
+}
+
+/// 计算实数阶 s 的多重对数函数 Li_s(z) = Σ_(k>=1) z^k / k^s 在复数 z 处的值, 取主值分支, 割线为 [1, +∞)
+///
+/// 与实数情形 `polylog` 相同: |z| <= 1/2 时直接求和, 非正整数 s 使用有理函数形式, |ln z| 不太大时使用 ln z 的幂级数,
+/// 否则使用反演公式, 其中 ln 均取主值. s = 1, 2 时分别为 -ln(1 - z) 和 `dilog_complex`.
+/// 割线上的点按虚部零的符号取值, 例如 z = x + 0i (x > 1) 时虚部为 +π ln^(s-1) x / Γ(s)
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::polylog::polylog_complex;
+/// let w = polylog_complex(3.0, Complex64::new(0.5, 1.0));
+/// assert!((w - Complex64::new(0.3631190987339678, 1.0911433542899198)).norm() < 1.0e-14);
+/// ```
+pub fn polylog_complex(s: f64, z: Complex64) -> Complex64 {
+    if s.is_nan() || z.re.is_nan() || z.im.is_nan() || s == f64::NEG_INFINITY {
+        return Complex64::new(f64::NAN, f64::NAN);
+    }
+    if z == Complex64::new(0.0, 0.0) || s == f64::INFINITY {
+        return z;
+    }
+    if z == Complex64::new(1.0, 0.0) {
+        return Complex64::new(if s > 1.0 { zeta(s) } else { f64::INFINITY }, 0.0);
+    }
+    if s <= 0.0 && s == s.floor() {
+        return polylog_negative_integer_complex(-s as usize, z);
+    }
+    if s == 1.0 {
+        return -complex_ln_1p(-z);
+    }
+    if s == 2.0 {
+        return dilog_complex(z);
+    }
+    if z.norm() > 1.0 && s == s.floor() && s < u32::MAX as f64 {
+        return polylog_integer_inversion_complex(s as u32, z);
+    }
+    if z.norm() <= 0.5 {
+        return polylog_series_complex(s, z);
+    }
+    // z 接近 1 时 μ = ln z 需要相对精度
+    let mu = complex_ln_1p(z - 1.0);
+    let radius = if s < 1.0 { PI + 0.25 } else { 5.0 };
+    if mu.norm() < radius {
+        return polylog_log_series(s, mu);
+    }
+    polylog_inversion(s, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn test_dilog() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(dilog(0.5), 0.5822405264650125, tol));
+        assert!(approx_eq(dilog(0.25), 0.2676526390827326, tol));
+        assert!(approx_eq(dilog(0.9), 1.2997147230049588, tol));
+        assert!(approx_eq(dilog(-1.0), -PI2_6 / 2.0, tol));
+        assert!(approx_eq(dilog(-3.5), -2.162096799077975, tol));
+        assert!(approx_eq(dilog(1.0e-20), 1.0e-20, 1.0e-35));
+        assert!(approx_eq(dilog(1.0), PI2_6, tol));
+        assert!(approx_eq(dilog(1.0000001), 1.6449357786577095, tol));
+        assert!(approx_eq(dilog(2.0), PI * PI / 4.0, tol));
+        assert!(approx_eq(dilog(10.0), 0.5363012873578628, tol));
+        assert!(approx_eq(dilog(1.0e10), -261.80503739032343, 1.0e-12));
+        assert!(approx_eq(dilog(-1.0e10), -266.7398395906681, 1.0e-12));
+        assert_eq!(dilog(0.0), 0.0);
+        assert_eq!(dilog(f64::INFINITY), f64::NEG_INFINITY);
+        assert!(dilog(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_spence() {
+        let tol = 1.0e-15;
+        assert_eq!(spence(0.0), PI2_6);
+        assert_eq!(spence(1.0), 0.0);
+        assert!(approx_eq(spence(1.0e-10), 1.6449340644456414, tol));
+        assert!(approx_eq(spence(0.25), 0.9784693929303061, tol));
+        assert!(approx_eq(spence(0.75), dilog(0.25), tol));
+        assert!(approx_eq(
+            spence(1.0000000001) / -1.000000082715371e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            spence(0.9999999999) / 1.000000082765371e-10,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(spence(1.5), -0.4484142069236462, tol));
+        assert!(approx_eq(spence(3.0), -1.4367463668836808, tol));
+        assert!(approx_eq(spence(10.0), -3.9506637782441576, tol));
+        assert!(approx_eq(spence(1.0e10) / -266.73983958836556, 1.0, tol));
+        assert!(approx_eq(spence(1.0e300) / -238587.05990559477, 1.0, tol));
+        assert!(spence(-1.0).is_nan());
+        assert!(spence(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_polylog() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(polylog(3.0, 0.5), 0.5372131936080402, tol));
+        assert!(approx_eq(polylog(2.5, 0.9), 1.1390030252021568, tol));
+        assert!(approx_eq(polylog(1.5, -0.75), -0.6063631167346767, tol));
+        assert!(approx_eq(polylog(0.5, 0.99), 16.221830753428105, tol));
+        assert!(approx_eq(polylog(-1.5, 0.5), 3.2931439195129135, tol));
+        assert!(approx_eq(polylog(-0.5, -3.0), -0.4462896844146637, tol));
+        assert!(approx_eq(polylog(2.9999999, 0.7), 0.7800639408299862, tol));
+        assert!(approx_eq(
+            polylog(0.3, 0.999999) / 20571.87884150603,
+            1.0,
+            tol
+        ));
+        // 负整数阶的有理函数形式与整数阶的反演公式
+        assert!(approx_eq(polylog(-2.0, 0.5), 6.0, tol));
+        assert!(approx_eq(polylog(-3.0, -2.0), 2.0 / 27.0, tol));
+        assert!(approx_eq(polylog(0.0, 3.0), -1.5, tol));
+        assert!(approx_eq(polylog(3.0, -10.0), -5.921064803756973, tol));
+        assert!(approx_eq(polylog(4.0, 20.0), 13.520968266702392, tol));
+        // 非整数阶的 Jonquière 反演公式
+        assert!(approx_eq(
+            polylog(1.5, -1000.0) / -14.01866349359066,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            polylog(3.5, 1.0e4) / -134.2723419571903,
+            1.0,
+            1.0e-12
+        ));
+        assert!(approx_eq(polylog(1.0, 0.5), std::f64::consts::LN_2, tol));
+        assert!(approx_eq(polylog(2.0, -1.0), -PI2_6 / 2.0, tol));
+        assert!(approx_eq(polylog(2.5, 1.0), 1.341487257250917, tol));
+        assert_eq!(polylog(0.5, 1.0), f64::INFINITY);
+        assert_eq!(polylog(1.5, 0.0), 0.0);
+        assert_eq!(polylog(5.0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(polylog(f64::NAN, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_dilog_complex() {
+        let tol = 1.0e-15;
+        assert!(
+            (dilog_complex(c(0.5, 1.0)) - c(0.20335376120592422, 1.131939585677709)).norm() < tol
+        );
+        assert!(
+            (dilog_complex(c(0.6, 0.8)) - c(0.40331124988898537, 1.0084130373169353)).norm() < tol
+        );
+        assert!(
+            (dilog_complex(c(0.9, 0.1)) - c(1.264186732338754, 0.24373567998101406)).norm() < tol
+        );
+        assert!(
+            (dilog_complex(c(-10.0, 5.0)) - c(-4.372711008401554, 1.157792604988844)).norm() < tol
+        );
+        assert!(
+            (dilog_complex(c(3.0, -4.0)) - c(-0.6048070120612, -3.733619532294386)).norm() < tol
+        );
+        assert!(
+            (dilog_complex(c(1.0e-10, 1.0e-10)) / c(1.0e-10, 1.00000000005e-10) - 1.0).norm() < tol
+        );
+        // 割线两侧: Li_2(2 ± i0) = π^2/4 ± iπ ln 2
+        let upper = c(PI * PI / 4.0, PI * std::f64::consts::LN_2);
+        assert!((dilog_complex(c(2.0, 0.0)) - upper).norm() < tol);
+        assert!((dilog_complex(c(2.0, -0.0)) - upper.conj()).norm() < tol);
+        assert!((dilog_complex(c(-3.0, 0.0)) - c(dilog(-3.0), 0.0)).norm() < tol);
+        assert_eq!(dilog_complex(c(1.0, 0.0)), c(PI2_6, 0.0));
+        assert!(dilog_complex(c(f64::NAN, 0.0)).re.is_nan());
+    }
+
+    #[test]
+    fn test_polylog_complex() {
+        let tol = 1.0e-14;
+        assert!(
+            (polylog_complex(3.0, c(0.5, 1.0)) - c(0.3631190987339678, 1.0911433542899198)).norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(0.5, c(0.0, 0.9)) - c(-0.37304558822342043, 0.6371956283180452))
+                .norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(1.5, c(-0.8, -0.6)) - c(-0.6858060586656693, -0.383891587728431))
+                .norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(6.5, c(0.95, 0.1)) - c(0.960637944210457, 0.10237256538284857)).norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(-2.0, c(0.3, 0.4)) - c(-1.2152935821574875, 0.45516613563950825))
+                .norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(4.0, c(-5.0, 2.0)) - c(-4.168597255275954, 1.4085627355197732)).norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(3.5, c(300.0, 200.0)) - c(-24.80796117894334, 68.50496778356272))
+                .norm()
+                < 1.0e-11
+        );
+        // 割线上沿 z = x + 0i
+        assert!(
+            (polylog_complex(2.5, c(2.0, 0.0)) - c(2.7896603323827773, 1.3638037005393528)).norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(0.5, c(1.5, 0.0)) - c(-1.5466407024391606, 2.783544653661024)).norm()
+                < tol
+        );
+        assert!(
+            (polylog_complex(-1.5, c(3.0, 0.0)) - c(-0.014251100321794096, 1.0508121080198594))
+                .norm()
+                < tol
+        );
+        assert!((polylog_complex(1.0, c(3.0, 0.0)) - c(-std::f64::consts::LN_2, PI)).norm() < tol);
+        assert!(
+            (polylog_complex(1.0, c(3.0, -0.0)) - c(-std::f64::consts::LN_2, -PI)).norm() < tol
+        );
+        assert!((polylog_complex(2.0, c(0.5, 1.0)) - dilog_complex(c(0.5, 1.0))).norm() < tol);
+        assert!((polylog_complex(2.5, c(0.7, 0.0)) - c(polylog(2.5, 0.7), 0.0)).norm() < tol);
+        assert_eq!(polylog_complex(1.5, c(0.0, 0.0)), c(0.0, 0.0));
+        assert!(polylog_complex(f64::NAN, c(0.5, 0.0)).re.is_nan());
+    }
+}
@@ -0,0 +1,346 @@
+use crate::gamma::EULER;
+use crate::utils::eval_chebyshev;
+use std::f64::consts::FRAC_PI_2;
+
+/// 0 <= x <= 4 时 Si(x)/x 的 Chebyshev 展开系数, t = x^2/8 - 1
+const SI_SMALL: [f64; 12] = [
+    0.684217670090758,
+    -0.2776578526973602,
+    0.03544140548666592,
+    -0.0025631631447933976,
+    0.00011623653904970093,
+    -3.5904327241606043e-06,
+    8.023421237057102e-08,
+    -1.3562997692540251e-09,
+    1.7944072159973677e-11,
+    -1.9083873430871455e-13,
+    1.6669989586824331e-15,
+    -1.2173098836850304e-17,
+];
+
+/// 0 < x <= 4 时 (Ci(x) - γ - ln x)/x^2 的 Chebyshev 展开系数, t = x^2/8 - 1
+const CI_SMALL: [f64; 12] = [
+    -0.18537250875454844,
+    0.058935748963644466,
+    -0.005381896421135691,
+    0.00029860052841962137,
+    -1.09557257532162e-05,
+    2.840545487734663e-07,
+    -5.469739948753849e-09,
+    8.124187461318157e-11,
+    -9.58685931177066e-13,
+    9.20266004392351e-15,
+    -7.325887999017895e-17,
+    4.914372667584291e-19,
+];
+
+/// 4 < x <= 12 时 x f(x) 的 Chebyshev 展开系数, t = (x - 8)/4
+const SICI_F_MID: [f64; 28] = [
+    0.9632753458839839,
+    0.03191261071401981,
+    -0.010481328610292918,
+    0.003026651280480805,
+    -0.0008106100289037465,
+    0.00020675324624945917,
+    -5.101007487426214e-05,
+    1.2298504753679893e-05,
+    -2.9183002669321716e-06,
+    6.850604906264974e-07,
+    -1.5970241229596032e-07,
+    3.7078809493900054e-08,
+    -8.592355320555965e-09,
+    1.990568874585789e-09,
+    -4.615797881713438e-10,
+    1.0722773619668769e-10,
+    -2.4970993350511565e-11,
+    5.8321166415318456e-12,
+    -1.3664909615157331e-12,
+    3.212597532814467e-13,
+    -7.579072016702194e-14,
+    1.7943155898994032e-14,
+    -4.262817901356239e-15,
+    1.0162178396485283e-15,
+    -2.430721133028361e-16,
+    5.833115959258001e-17,
+    -1.404227022922617e-17,
+    3.3907560510010054e-18,
+];
+
+/// 4 < x <= 12 时 x^2 g(x) 的 Chebyshev 展开系数, t = (x - 8)/4
+const SICI_G_MID: [f64; 28] = [
+    0.9035735144462813,
+    0.07773202361383977,
+    -0.023197205850204693,
+    0.005987999853419635,
+    -0.0014117692392466878,
+    0.00031206142941805616,
+    -6.560492508857865e-05,
+    1.3220655463040688e-05,
+    -2.561868759241873e-06,
+    4.766803495555305e-07,
+    -8.451950046873092e-08,
+    1.4013496655897932e-08,
+    -2.0742125679990233e-09,
+    2.3650045241316704e-10,
+    -4.236438223766652e-12,
+    -9.497411005614645e-12,
+    4.383600391328815e-12,
+    -1.4745791371539054e-12,
+    4.39303489021915e-13,
+    -1.228670361482266e-13,
+    3.309326913693811e-14,
+    -8.699660666073432e-15,
+    2.2498697478264055e-15,
+    -5.75289853977171e-16,
+    1.459322344390698e-16,
+    -3.6810109374738135e-17,
+    9.248291542732562e-18,
+    -2.317213564311701e-18,
+];
+
+/// x > 12 时 x f(x) 的 Chebyshev 展开系数, t = 288/x^2 - 1, x -> ∞ 时趋于 1
+const SICI_F_TAIL: [f64; 21] = [
+    0.9934314917999526,
+    -0.0064515369129763455,
+    0.00011241890816422367,
+    -4.266601594906961e-06,
+    2.608945867140754e-07,
+    -2.212959433413361e-08,
+    2.3869778500798743e-09,
+    -3.097807546762358e-10,
+    4.6589913873528265e-11,
+    -7.906234136901258e-12,
+    1.484334030899739e-12,
+    -3.0371659652692493e-13,
+    6.694267612776784e-14,
+    -1.574676000505505e-14,
+    3.923321235172701e-15,
+    -1.0289426771361184e-15,
+    2.8258581266480787e-16,
+    -8.091510071068819e-17,
+    2.4066155392329446e-17,
+    -7.411147319613913e-18,
+    2.356426781961274e-18,
+];
+
+/// x > 12 时 x^2 g(x) 的 Chebyshev 展开系数, t = 288/x^2 - 1, x -> ∞ 时趋于 1
+const SICI_G_TAIL: [f64; 24] = [
+    0.9809543847920559,
+    -0.01850267710281719,
+    0.000514676911619174,
+    -2.608462122740954e-05,
+    1.9553278299406582e-06,
+    -1.9355710147881842e-07,
+    2.3611679845772688e-08,
+    -3.391882394474613e-09,
+    5.559802175660002e-10,
+    -1.0164233795665894e-10,
+    2.0373764057369927e-11,
+    -4.419202064459169e-12,
+    1.0265981047214448e-12,
+    -2.53299798346375e-13,
+    6.593304844044847e-14,
+    -1.8003929742612425e-14,
+    5.133133055998199e-15,
+    -1.52200043848775e-15,
+    4.677126476548062e-16,
+    -1.4852265103566848e-16,
+    4.861144993278442e-17,
+    -1.6362037507658698e-17,
+    5.6522954841729306e-18,
+    -2.0004784390832056e-18,
+];
+
+/// Ci(x) 第一个正零点 x_0 的高位部分
+const CI_ROOT_HI: f64 = 0.6165054856207163;
+
+/// Ci(x) 第一个正零点 x_0 的低位部分, x_0 = CI_ROOT_HI + CI_ROOT_LO
+const CI_ROOT_LO: f64 = -4.209920321433186e-17;
+
+/// |x - x_0| 不超过此值时 Ci 使用以零点 x_0 为基准的幂级数
+const CI_ROOT_SERIES_RADIUS: f64 = 0.25;
+
+/// |x| 不超过此值时 Si 与 Ci 直接使用 Chebyshev 展开
+const SICI_SMALL: f64 = 4.0;
+
+/// 辅助函数的中间区间与渐近区间的分界点
+const SICI_TAIL: f64 = 12.0;
+
+/// 计算辅助函数 (f(x), g(x)), 要求 x > 4, 其中 f(x) = Ci(x) sin x - (Si(x) - π/2) cos x,
+/// g(x) = -Ci(x) cos x - (Si(x) - π/2) sin x. x 很大时 f(x) ~ 1/x, g(x) ~ 1/x^2
+fn sici_aux(x: f64) -> (f64, f64) {
+    let r = 1.0 / x;
+    let (f, g) = if x <= SICI_TAIL {
+        let t = 0.25 * (x - 8.0);
+        (
+            eval_chebyshev(t, &SICI_F_MID),
+            eval_chebyshev(t, &SICI_G_MID),
+        )
+    } else {
+        let t = 2.0 * (SICI_TAIL * r).powi(2) - 1.0;
+        (
+            eval_chebyshev(t, &SICI_F_TAIL),
+            eval_chebyshev(t, &SICI_G_TAIL),
+        )
+    };
+    (f * r, g * r * r)
+}
+
+/// |x - x_0| <= 1/4 时以零点 x_0 为基准的幂级数: 由 Ci(x_0) = 0 得
+/// Ci(x) = ln(x/x_0) + Σ_(k>=1) (-1)^k (x^(2k) - x_0^(2k)) / (2k (2k)!), 所有项都与 x - x_0 成正比, 零点附近保持相对精度.
+/// e_k = (x^(2k) - x_0^(2k))/(2k)! 的递推与 `chi_series` 相同
+fn ci_root_series(x: f64, delta: f64) -> f64 {
+    let log = (delta / CI_ROOT_HI).ln_1p();
+    let x2 = x * x;
+    let root2 = CI_ROOT_HI * CI_ROOT_HI;
+    let d = delta * (x + CI_ROOT_HI);
+    let mut sum = 0.0;
+    let mut e = 0.0;
+    let mut u = 1.0;
+    let mut k = 1.0;
+    loop {
+        let q = (2.0 * k - 1.0) * (2.0 * k);
+        e = x2.mul_add(e, d * u) / q;
+        u *= root2 / q;
+        let term = e / (2.0 * k);
+        sum = if k % 2.0 == 1.0 {
+            sum - term
+        } else {
+            sum + term
+        };
+        if term.abs() <= 0.5 * f64::EPSILON * (log + sum).abs() {
+            return log + sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// 计算正弦积分 Si(x) = ∫_0^x sin t / t dt
+///
+/// |x| <= 4 时使用 Si(x)/x 关于 x^2 的 Chebyshev 展开; 否则由辅助函数计算 Si(x) = π/2 - f(x) cos x - g(x) sin x,
+/// f 与 g 在 (4, 12] 与 (12, ∞) 上分别使用 Chebyshev 展开. Si 为奇函数, Si(±∞) = ±π/2
+///
+/// # Example
+///
+/// ```
+/// use special_functions::sici::si;
+/// assert!((si(1.0) - 0.946083070367183).abs() < 1.0e-15);
+/// ```
+pub fn si(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let a = x.abs();
+    if a <= SICI_SMALL {
+        return x * eval_chebyshev(0.125 * x * x - 1.0, &SI_SMALL);
+    }
+    if a == f64::INFINITY {
+        return FRAC_PI_2.copysign(x);
+    }
+    let (f, g) = sici_aux(a);
+    let (sin, cos) = a.sin_cos();
+    (FRAC_PI_2 - f * cos - g * sin).copysign(x)
+}
+
+/// 计算余弦积分 Ci(x) = γ + ln x + ∫_0^x (cos t - 1)/t dt = -∫_x^∞ cos t / t dt, 要求 x >= 0
+///
+/// x <= 4 时使用 (Ci(x) - γ - ln x)/x^2 关于 x^2 的 Chebyshev 展开; 否则由辅助函数计算 Ci(x) = f(x) sin x - g(x) cos x.
+/// 第一个零点 x_0 ≈ 0.6165 附近改用以 x_0 为基准的幂级数, 保持相对精度; 其余零点附近只保证绝对精度.
+/// Ci(0) = -∞, Ci(+∞) = 0, x < 0 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::sici::ci;
+/// assert!((ci(1.0) - 0.33740392290096816).abs() < 1.0e-15);
+/// ```
+pub fn ci(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let delta = (x - CI_ROOT_HI) - CI_ROOT_LO;
+    if delta.abs() <= CI_ROOT_SERIES_RADIUS {
+        return ci_root_series(x, delta);
+    }
+    if x <= SICI_SMALL {
+        return (x * x).mul_add(
+            eval_chebyshev(0.125 * x * x - 1.0, &CI_SMALL),
+            EULER + x.ln(),
+        );
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    let (f, g) = sici_aux(x);
+    let (sin, cos) = x.sin_cos();
+    f * sin - g * cos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_si() {
+        let tol = 1.0e-15;
+        assert_eq!(si(0.0), 0.0);
+        assert!(approx_eq(si(1.0e-10) / 1.0e-10, 1.0, tol));
+        assert!(approx_eq(si(0.5), 0.4931074180430667, tol));
+        assert!(approx_eq(si(2.0), 1.6054129768026948, tol));
+        assert!(approx_eq(si(4.0), 1.7582031389490531, tol));
+        assert!(approx_eq(si(6.0), 1.4246875512805066, tol));
+        assert!(approx_eq(si(-10.0), -1.6583475942188741, tol));
+        assert!(approx_eq(si(12.0), 1.5049712415263734, tol));
+        assert!(approx_eq(si(20.0), 1.54824170104344, tol));
+        assert!(approx_eq(si(100.0), 1.5622254668890563, tol));
+        assert!(approx_eq(si(1.0e6), 1.570795390043119, tol));
+        assert!(approx_eq(si(1.0e15), 1.5707963267948972, tol));
+        assert_eq!(si(f64::NEG_INFINITY), -FRAC_PI_2);
+        assert!(si(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ci() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(ci(1.0e-10), -22.448635265038924, 1.0e-14));
+        assert!(approx_eq(ci(0.5), -0.1777840788066129, tol));
+        assert!(approx_eq(ci(1.0), 0.33740392290096816, tol));
+        assert!(approx_eq(ci(2.0), 0.422980828774865, tol));
+        assert!(approx_eq(ci(4.0), -0.1409816978869304, tol));
+        assert!(approx_eq(ci(6.0), -0.06805724389324713, tol));
+        assert!(approx_eq(ci(10.0), -0.04545643300445537, tol));
+        assert!(approx_eq(ci(12.0), -0.04978000688411367, tol));
+        assert!(approx_eq(ci(20.0), 0.044419820845353314, tol));
+        assert!(approx_eq(ci(100.0), -0.005148825142610492, tol));
+        assert!(approx_eq(ci(1.0e6) / -3.499944389227205e-7, 1.0, 1.0e-14));
+        assert_eq!(ci(0.0), f64::NEG_INFINITY);
+        assert_eq!(ci(f64::INFINITY), 0.0);
+        assert!(ci(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ci_near_root() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(
+            ci(0.6165054956207163) / 1.3234333447526578e-8,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ci(0.6165054756207162) / -1.3234333644547173e-8,
+            1.0,
+            tol
+        ));
+        assert!(approx_eq(
+            ci(0.6165054856207163) / 5.571548945612863e-17,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(ci(0.8165054856207162), 0.21238556357872782, tol));
+        assert!(approx_eq(ci(0.41650548562071626), -0.3416969110481193, tol));
+    }
+}
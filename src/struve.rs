@@ -0,0 +1,357 @@
+use crate::bessel::{bessel_iv, bessel_kv, bessel_yv};
+use crate::gamma::rgamma;
+use crate::triangle::sinpi;
+use crate::utils::double_exponential_sum;
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+
+/// 1 / √π
+const FRAC_1_SQRT_PI: f64 = 0.5641895835477563;
+
+/// x 不超过此值时以幂级数计算 H_ν(x), 并由 L_ν(x) - I_ν(x) 计算 M_ν(x)
+const STRUVE_SERIES_MAX_ARG: f64 = 3.0;
+
+/// x 不小于此值且不小于 2ν 时使用渐近展开
+const STRUVE_ASYMPTOTIC_MIN_ARG: f64 = 40.0;
+
+/// x 不超过此值时以幂级数计算 L_ν(x), 级数各项同号, 没有抵消
+const STRUVE_L_SERIES_MAX_ARG: f64 = 30.0;
+
+/// 双指数求积公式的步长
+const DE_STEP: f64 = 1.0 / 32.0;
+
+/// 双指数求积公式的截断范围 |s| <= DE_RANGE
+const DE_RANGE: f64 = 4.0;
+
+/// 以幂级数 (x/2)^(ν+1) Σ (±1)^k (x/2)^(2k) / (Γ(k + 3/2) Γ(k + ν + 3/2)) 计算 H_ν(x) 或 L_ν(x),
+/// modified 为真时对应 L_ν(x). 要求 x > 0
+fn struve_series(nu: f64, x: f64, modified: bool) -> f64 {
+    let x2 = 0.5 * x;
+    let q = if modified { x2 * x2 } else { -x2 * x2 };
+    let mut term = x2.powf(nu + 1.0) * 2.0 * FRAC_1_SQRT_PI * rgamma(nu + 1.5);
+    let mut sum = term;
+    let mut k = 0.0;
+    loop {
+        term *= q / ((k + 1.5) * (k + nu + 1.5));
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        k += 1.0;
+    }
+    sum
+}
+
+/// 以渐近展开 (1/π) Σ (∓1)^k Γ(k + 1/2) (x/2)^(ν-2k-1) / Γ(ν + 1/2 - k) 计算 H_ν(x) - Y_ν(x),
+/// modified 为真时改变符号交替方式, 得到 I_(-ν)(x) - L_ν(x)
+fn struve_asymptotic(nu: f64, x: f64, modified: bool) -> f64 {
+    let x2 = 0.5 * x;
+    let q = if modified {
+        -1.0 / (x2 * x2)
+    } else {
+        1.0 / (x2 * x2)
+    };
+    let mut term = x2.powf(nu - 1.0) * FRAC_1_SQRT_PI * rgamma(nu + 0.5);
+    let mut sum = term;
+    let mut k = 0.0;
+    loop {
+        let next = term * q * (k + 0.5) * (nu - 0.5 - k);
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        k += 1.0;
+    }
+    sum
+}
+
+/// 由双指数 (exp-sinh) 求积公式计算 H_ν(x) - Y_ν(x) = 2 (x/2)^ν / (√π Γ(ν + 1/2)) ∫_0^∞ e^(-xt) (1 + t^2)^(ν-1/2) dt,
+/// 其中代换 xt = c exp((π/2) sinh s), c 取被积函数峰值 xt ≈ 2ν - 1 附近以使节点集中于峰值处. 要求 ν > -1/2, x > 0
+fn struve_k_integral(nu: f64, x: f64) -> f64 {
+    let p = nu - 0.5;
+    let c = (2.0 * p).max(1.0);
+    let sum = double_exponential_sum(DE_STEP, DE_RANGE, |y, dy| {
+        let u = c * y.exp();
+        let t = u / x;
+        // 以对数形式计算 e^(-u) (1 + t^2)^p, 避免 u 很大时出现 0 · ∞
+        dy * u * p.mul_add((t * t).ln_1p(), -u).exp()
+    });
+    2.0 * FRAC_1_SQRT_PI * rgamma(nu + 0.5) * (0.5 * x).powf(nu) * sum / x
+}
+
+/// 由双指数 (tanh-sinh) 求积公式计算 L_ν(x) - I_ν(x) = -2 (x/2)^ν / (√π Γ(ν + 1/2)) ∫_0^(π/2) e^(-x cos θ) sin^(2ν) θ dθ,
+/// 其中 θ = (π/4) (1 + tanh((π/2) sinh s)), 两端的 θ 与 π/2 - θ 分别直接计算以保持精度. 要求 ν > -1/2, x > 0
+fn struve_m_integral(nu: f64, x: f64) -> f64 {
+    let sum = double_exponential_sum(DE_STEP, DE_RANGE, |y, dy| {
+        let ey = (2.0 * y).exp();
+        // θ = (π/2) / (1 + e^(-2y)), π/2 - θ = (π/2) / (1 + e^(2y))
+        let theta = FRAC_PI_2 * ey / (1.0 + ey);
+        let theta_c = FRAC_PI_2 / (1.0 + ey);
+        let ch = (0.5 * (ey.sqrt() + 1.0 / ey.sqrt())).powi(2);
+        let weight = 0.25 * PI * dy / ch;
+        weight * (-x * theta_c.sin()).exp() * theta.sin().powf(2.0 * nu)
+    });
+    -2.0 * FRAC_1_SQRT_PI * rgamma(nu + 0.5) * (0.5 * x).powf(nu) * sum
+}
+
+/// 判断 Struve 函数的渐近展开在 (ν, x) 处能否收敛到机器精度
+fn struve_asymptotic_valid(nu: f64, x: f64) -> bool {
+    x >= STRUVE_ASYMPTOTIC_MIN_ARG && x >= 2.0 * nu
+}
+
+/// 计算 H_ν(x) - Y_ν(x), 要求 ν > -1/2, x > 0 有限
+fn struve_k_positive(nu: f64, x: f64) -> f64 {
+    if x <= STRUVE_SERIES_MAX_ARG {
+        struve_series(nu, x, false) - bessel_yv(nu, x)
+    } else if !struve_asymptotic_valid(nu, x) {
+        struve_k_integral(nu, x)
+    } else {
+        struve_asymptotic(nu, x, false)
+    }
+}
+
+/// 计算 L_ν(x) - I_ν(x), 要求 ν > -1/2, x > 0 有限
+fn struve_m_positive(nu: f64, x: f64) -> f64 {
+    if x <= STRUVE_SERIES_MAX_ARG {
+        struve_series(nu, x, true) - bessel_iv(nu, x)
+    } else if !struve_asymptotic_valid(nu, x) {
+        struve_m_integral(nu, x)
+    } else {
+        // L_ν - I_ν = (L_ν - I_(-ν)) + (I_(-ν) - I_ν), 后者等于 (2/π) sin(νπ) K_ν(x)
+        FRAC_2_PI * sinpi(nu) * bessel_kv(nu, x) - struve_asymptotic(nu, x, true)
+    }
+}
+
+/// x → +∞ 时 H_ν(x) 与 H_ν(x) - Y_ν(x) 的极限, 二者均渐近于 (x/2)^(ν-1) / (√π Γ(ν + 1/2))
+fn struve_limit(nu: f64) -> f64 {
+    if nu < 1.0 {
+        0.0
+    } else if nu == 1.0 {
+        FRAC_2_PI
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// 对整数阶 n 由 H_n(-x) = (-1)^(n+1) H_n(x) (对 L_n 同样成立) 计算负自变量处的值, 非整数阶时返回 NaN
+fn struve_reflect(nu: f64, v: f64) -> f64 {
+    if nu != nu.floor() {
+        f64::NAN
+    } else if (nu % 2.0).abs() == 1.0 {
+        v
+    } else {
+        -v
+    }
+}
+
+/// 计算 Struve 函数 H_ν(x), 要求 ν > -1/2
+///
+/// x <= 3 时使用幂级数, 否则由 H_ν(x) = (H_ν(x) - Y_ν(x)) + Y_ν(x) 计算, 其中差值见 `struve_k`.
+/// x < 0 时对整数阶由 H_n(-x) = (-1)^(n+1) H_n(x) 计算, 对非整数阶返回 NaN; ν <= -1/2 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::struve::struve_h;
+/// assert!((struve_h(0.0, 1.0) - 0.5686566270482879).abs() < 1.0e-15);
+/// ```
+pub fn struve_h(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu <= -0.5 || nu.is_infinite() {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        return struve_reflect(nu, struve_h(nu, -x));
+    }
+    if x == 0.0 {
+        return 0.0;
+    }
+    if x.is_infinite() {
+        return struve_limit(nu);
+    }
+    if x <= STRUVE_SERIES_MAX_ARG {
+        struve_series(nu, x, false)
+    } else {
+        struve_k_positive(nu, x) + bessel_yv(nu, x)
+    }
+}
+
+/// 计算修正 Struve 函数 L_ν(x), 要求 ν > -1/2
+///
+/// x <= 30 时使用幂级数, 否则由 L_ν(x) = (L_ν(x) - I_ν(x)) + I_ν(x) 计算, 其中差值见 `struve_m`.
+/// x < 0 时对整数阶由 L_n(-x) = (-1)^(n+1) L_n(x) 计算, 对非整数阶返回 NaN; ν <= -1/2 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::struve::struve_l;
+/// assert!((struve_l(0.0, 1.0) - 0.7102431859378909).abs() < 1.0e-15);
+/// ```
+pub fn struve_l(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu <= -0.5 || nu.is_infinite() {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        return struve_reflect(nu, struve_l(nu, -x));
+    }
+    if x == 0.0 {
+        return 0.0;
+    }
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x <= STRUVE_L_SERIES_MAX_ARG {
+        struve_series(nu, x, true)
+    } else {
+        struve_m_positive(nu, x) + bessel_iv(nu, x)
+    }
+}
+
+/// 计算 Struve 函数与第二类 Bessel 函数之差 K_ν(x) = H_ν(x) - Y_ν(x), 要求 ν > -1/2
+///
+/// x 较大时 H_ν 与 Y_ν 近似相消, 此处不经过二者相减: x <= 3 时使用幂级数, 其余情形以双指数求积公式计算积分
+/// K_ν(x) = 2 (x/2)^ν / (√π Γ(ν + 1/2)) ∫_0^∞ e^(-xt) (1 + t^2)^(ν-1/2) dt, x >= max(40, 2ν) 时使用渐近展开.
+/// x < 0 或 ν <= -1/2 时返回 NaN, x = 0 时返回 +∞
+///
+/// # Example
+///
+/// ```
+/// use special_functions::struve::struve_k;
+/// assert!((struve_k(0.0, 10.0) - 0.06307251640386188).abs() < 1.0e-16);
+/// ```
+pub fn struve_k(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu <= -0.5 || nu.is_infinite() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+    if x.is_infinite() {
+        return struve_limit(nu);
+    }
+    struve_k_positive(nu, x)
+}
+
+/// 计算修正 Struve 函数与第一类修正 Bessel 函数之差 M_ν(x) = L_ν(x) - I_ν(x), 要求 ν > -1/2
+///
+/// x 较大时 L_ν 与 I_ν 近似相消, 此处不经过二者相减: x <= 3 时使用幂级数, 其余情形以双指数求积公式计算积分
+/// M_ν(x) = -2 (x/2)^ν / (√π Γ(ν + 1/2)) ∫_0^1 e^(-xt) (1 - t^2)^(ν-1/2) dt, x >= max(40, 2ν) 时使用渐近展开.
+/// x < 0 或 ν <= -1/2 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::struve::struve_m;
+/// assert!((struve_m(0.0, 10.0) + 0.06437909165961592).abs() < 1.0e-16);
+/// ```
+pub fn struve_m(nu: f64, x: f64) -> f64 {
+    if nu.is_nan() || x.is_nan() || nu <= -0.5 || nu.is_infinite() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return -bessel_iv(nu, 0.0);
+    }
+    if x.is_infinite() {
+        return -struve_limit(nu);
+    }
+    struve_m_positive(nu, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_struve_h() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(struve_h(0.0, 1.0), 0.5686566270482879, tol));
+        assert!(approx_eq(struve_h(0.0, -1.0), -0.5686566270482879, tol));
+        assert!(approx_eq(struve_h(0.0, 5.0), -0.1852168157766849, tol));
+        assert!(approx_eq(struve_h(0.0, 20.0), 0.09439369808132345, tol));
+        assert!(approx_eq(struve_h(0.0, 100.0), -0.07087875168964734, tol));
+        assert!(approx_eq(struve_h(1.0, 1.0), 0.1984573362019444, tol));
+        assert!(approx_eq(struve_h(1.0, -1.0), 0.1984573362019444, tol));
+        assert!(approx_eq(struve_h(1.0, 5.0), 0.8078119457940645, tol));
+        assert!(approx_eq(struve_h(1.0, 20.0), 0.47268818429104287, tol));
+        assert!(approx_eq(struve_h(1.0, 100.0), 0.6163111032720134, tol));
+        assert!(approx_eq(struve_h(2.5, 5.0) / 1.630681337960218, 1.0, tol));
+        assert!(approx_eq(
+            struve_h(2.5, 100.0) / 99.84548277945791,
+            1.0,
+            tol
+        ));
+        assert_eq!(struve_h(0.0, 0.0), 0.0);
+        assert_eq!(struve_h(1.0, f64::INFINITY), FRAC_2_PI);
+        assert!(struve_h(0.5, -1.0).is_nan());
+        assert!(struve_h(-1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_struve_l() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(struve_l(0.0, 1.0), 0.7102431859378909, tol));
+        assert!(approx_eq(struve_l(0.0, -1.0), -0.7102431859378909, tol));
+        assert!(approx_eq(struve_l(0.0, 5.0) / 27.105917126558147, 1.0, tol));
+        assert!(approx_eq(struve_l(0.0, 20.0) / 43558282.52764104, 1.0, tol));
+        assert!(approx_eq(
+            struve_l(0.0, 100.0) / 1.0737517071310738e42,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(struve_l(1.0, 1.0), 0.22676438105580865, tol));
+        assert!(approx_eq(struve_l(1.0, -1.0), 0.22676438105580865, tol));
+        assert!(approx_eq(struve_l(1.0, 5.0) / 23.72821578040828, 1.0, tol));
+        assert!(approx_eq(struve_l(1.0, 20.0) / 42454972.75011198, 1.0, tol));
+        assert!(approx_eq(
+            struve_l(1.0, 100.0) / 1.0683693903381625e42,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(struve_l(2.5, 5.0) / 12.791533337719354, 1.0, tol));
+        assert_eq!(struve_l(0.0, 0.0), 0.0);
+        assert_eq!(struve_l(0.0, f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_struve_k() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(struve_k(0.0, 1.0), 0.480399662832611, tol));
+        assert!(approx_eq(struve_k(0.0, 5.0), 0.1233008094723489, tol));
+        assert!(approx_eq(struve_k(0.0, 20.0), 0.03175310127193962, tol));
+        assert!(approx_eq(struve_k(0.0, 100.0), 0.006365561675435809, tol));
+        assert!(approx_eq(struve_k(1.0, 1.0), 0.9796701575022331, tol));
+        assert!(approx_eq(struve_k(1.0, 5.0), 0.6599488024028376, tol));
+        assert!(approx_eq(struve_k(1.0, 20.0), 0.6381997986535641, tol));
+        assert!(approx_eq(struve_k(1.0, 100.0), 0.6366834152747731, tol));
+        assert!(approx_eq(struve_k(2.5, 1.0) / 2.892331532910387, 1.0, tol));
+        assert!(approx_eq(struve_k(2.5, 20.0) / 9.011164879658608, 1.0, tol));
+        assert_eq!(struve_k(0.0, 0.0), f64::INFINITY);
+        assert_eq!(struve_k(0.0, f64::INFINITY), 0.0);
+        assert!(struve_k(0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_struve_m() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(struve_m(0.0, 1.0), -0.5558226918141175, tol));
+        assert!(approx_eq(struve_m(0.0, 5.0), -0.13395469704630034, tol));
+        assert!(approx_eq(struve_m(0.0, 20.0), -0.03191248655448039, tol));
+        assert!(approx_eq(struve_m(0.0, 100.0), -0.006366834917845447, tol));
+        assert!(approx_eq(struve_m(1.0, 1.0), -0.3383947229366764, tol));
+        assert!(approx_eq(struve_m(1.0, 5.0), -0.6074263620422448, tol));
+        assert!(approx_eq(struve_m(1.0, 20.0), -0.6350157907325777, tol));
+        assert!(approx_eq(struve_m(1.0, 100.0), -0.6365560912630026, tol));
+        assert!(approx_eq(struve_m(2.5, 1.0), -0.03976968767516296, tol));
+        assert!(approx_eq(
+            struve_m(2.5, 20.0) / -8.832752467617677,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(struve_m(0.0, 0.0), -1.0);
+        assert_eq!(struve_m(1.0, 0.0), 0.0);
+        assert!(struve_m(0.0, -1.0).is_nan());
+    }
+}
@@ -0,0 +1,446 @@
+use crate::elliptic::{ellip_reduce, PI_LO};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// q 不超过此值 (即 -ln q / π >= 1) 时直接对 q-级数求和, 否则使用虚变换后的级数
+const THETA_DIRECT_MAX_NOME: f64 = 0.04321391826377226;
+
+/// nome 关于 λ = (1 - √k') / (2(1 + √k')) 的级数 q = Σ c_n λ^(4n+1) 的系数, k' = √(1 - m).
+/// m <= 1/2 时 λ <= e^(-π), 前六项即达到机器精度
+const NOME_SERIES: [f64; 6] = [1.0, 2.0, 15.0, 150.0, 1707.0, 20910.0];
+
+/// 0 <= q <= e^(-π), |z| <= π/2 时直接由 q-级数计算 θ_n(z, q):
+/// θ_1 = 2 q^(1/4) Σ_(k>=0) (-1)^k q^(k(k+1)) sin((2k+1)z), θ_2 = 2 q^(1/4) Σ_(k>=0) q^(k(k+1)) cos((2k+1)z),
+/// θ_3 = 1 + 2 Σ_(k>=1) q^(k^2) cos(2kz), θ_4 = 1 + 2 Σ_(k>=1) (-1)^k q^(k^2) cos(2kz).
+/// q <= e^(-π) 时 q^(k^2) 衰减极快, 且 θ_3, θ_4 的首项占绝对优势, 不会发生相消
+fn theta_direct(n: u32, z: f64, q: f64) -> f64 {
+    let odd = n <= 2;
+    let mut sum = 0.0;
+    // power = q^(k(k+1)) 或 q^(k^2)
+    let mut power = 1.0;
+    let mut k = 0.0;
+    loop {
+        let sign = if (n == 1 || n == 4) && k % 2.0 == 1.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let term = if odd {
+            let arg = (2.0 * k + 1.0) * z;
+            power * if n == 1 { arg.sin() } else { arg.cos() }
+        } else if k == 0.0 {
+            0.5
+        } else {
+            power * (2.0 * k * z).cos()
+        };
+        sum += sign * term;
+        k += 1.0;
+        power *= if odd {
+            q.powf(2.0 * k)
+        } else {
+            q.powf(2.0 * k - 1.0)
+        };
+        if power <= 0.5 * f64::EPSILON {
+            break;
+        }
+    }
+    if odd {
+        2.0 * q.sqrt().sqrt() * sum
+    } else {
+        2.0 * sum
+    }
+}
+
+/// e^(-π) < q < 1, 0 <= z <= π/2 时由虚变换 τ → -1/τ 计算 θ_n(z, q). 记 t = -ln q / π, a = π/t, w = z/π, 则
+/// √t θ_3 = Σ_(k∈Z) e^(-a(k - w)^2), √t θ_4 = Σ_(k∈Z) e^(-a(k + 1/2 - w)^2),
+/// √t θ_1 = Σ_(k>=0) (-1)^k e^(-a(k + 1/2 - w)^2) (1 - e^(-2a(2k + 1)w)),
+/// √t θ_2 = Σ_(k>=0) (-1)^k e^(-a(k + w)^2) (1 - e^(-a(2k + 1)(1 - 2w))).
+/// 后两式由级数中互相抵消的项两两配对得到, 以 expm1 计算括号内的差, 1 - 2w 按 (π - 2z)/π 计算,
+/// 在零点 (w = 0 或 w = 1/2) 附近仍有相对精度.
+/// q → 1 时 a → ∞, 级数只需很少几项
+fn theta_transformed(n: u32, z: f64, q: f64) -> f64 {
+    let t = -q.ln() / PI;
+    let a = PI / t;
+    let w = z / PI;
+    let complement = ((PI - 2.0 * z) + PI_LO) / PI;
+    let gauss = |x: f64| (-a * x * x).exp();
+    let mut sum = 0.0;
+    let mut k = 0.0;
+    loop {
+        let term = match n {
+            1 => gauss(k + 0.5 - w) * -(-2.0 * a * (2.0 * k + 1.0) * w).exp_m1(),
+            2 => gauss(k + w) * -(-a * (2.0 * k + 1.0) * complement).exp_m1(),
+            3 => gauss(k - w) + gauss(k + 1.0 + w),
+            _ => gauss(k + 0.5 - w) + gauss(k + 0.5 + w),
+        };
+        let sign = if n <= 2 && k % 2.0 == 1.0 { -1.0 } else { 1.0 };
+        sum += sign * term;
+        k += 1.0;
+        if term <= 0.5 * f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    sum / t.sqrt()
+}
+
+/// 计算 θ_n(z, q), n = 1, 2, 3, 4. 先以 θ_1(z + π) = -θ_1(z), θ_2(z + π) = -θ_2(z), θ_3, θ_4 以 π 为周期
+/// 将 z 约化到 [-π/2, π/2], 再按 q 的大小选择级数.
+/// θ_3, θ_4 对 -1 < q < 0 由 θ_3(z, -q) = θ_4(z, q) 互相转化; θ_1, θ_2 含 q^(1/4), 只接受 0 <= q < 1
+fn jacobi_theta(n: u32, z: f64, q: f64) -> f64 {
+    if n >= 3 && q < 0.0 && q > -1.0 {
+        return jacobi_theta(7 - n, z, -q);
+    }
+    if z.is_nan() || q.is_nan() || z.is_infinite() || !(0.0..1.0).contains(&q) {
+        return f64::NAN;
+    }
+    if q == 0.0 {
+        return if n <= 2 { 0.0 } else { 1.0 };
+    }
+    // |z| <= π/2 时不做约化, 保留 z 的全部精度以便在 θ_2 的零点 π/2 附近计算 π - 2z
+    let (j, r) = if z.abs() <= FRAC_PI_2 {
+        (0.0, z)
+    } else {
+        ellip_reduce(z)
+    };
+    let sign = if n <= 2 && j % 2.0 != 0.0 { -1.0 } else { 1.0 };
+    let value = if q <= THETA_DIRECT_MAX_NOME {
+        theta_direct(n, r, q)
+    } else if n == 1 {
+        // θ_1 为奇函数, 其余为偶函数
+        r.signum() * theta_transformed(n, r.abs(), q)
+    } else {
+        theta_transformed(n, r.abs(), q)
+    };
+    sign * value
+}
+
+/// 由参数 m ∈ [0, 1] 及其补 p = 1 - m 计算 nome q(m).
+/// m <= 1/2 时使用 λ 的级数, λ = m / (2(1 + k')(1 + √k')^2) 中没有相消, 因而 m → 0 时 q ≈ m/16 有相对精度;
+/// m > 1/2 时由 ln q(m) ln q(1 - m) = π^2 化为 q(m) = e^(π^2 / ln q(p)), m → 1 时指数趋于零, 同样没有精度损失
+fn nome_complement(m: f64, p: f64) -> f64 {
+    if m > 0.5 {
+        return (PI * PI / nome_complement(p, m).ln()).exp();
+    }
+    let kp = p.sqrt();
+    let root = kp.sqrt();
+    let lambda = m / (2.0 * (1.0 + kp) * (1.0 + root) * (1.0 + root));
+    let lambda4 = lambda * lambda * lambda * lambda;
+    lambda
+        * NOME_SERIES
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &c| acc * lambda4 + c)
+}
+
+/// 由 nome q ∈ [0, 1) 计算 (m, 1 - m).
+/// q <= e^(-π) 时 m = (θ_2(0, q)/θ_3(0, q))^4, 1 - m = (θ_4(0, q)/θ_3(0, q))^4, 各 theta 常数的级数没有相消;
+/// q 较大时对 q' = e^(π^2 / ln q) 计算并交换两者
+fn inverse_nome_complement(q: f64) -> (f64, f64) {
+    if q > THETA_DIRECT_MAX_NOME {
+        let (p, m) = inverse_nome_complement((PI * PI / q.ln()).exp());
+        return (m, p);
+    }
+    if q == 0.0 {
+        return (0.0, 1.0);
+    }
+    let theta3 = theta_direct(3, 0.0, q);
+    let ratio2 = theta_direct(2, 0.0, q) / theta3;
+    let ratio4 = theta_direct(4, 0.0, q) / theta3;
+    (ratio2.powi(4), ratio4.powi(4))
+}
+
+/// 计算 Jacobi theta 函数 θ_1(z, q) = 2 Σ_(n>=0) (-1)^n q^((n+1/2)^2) sin((2n+1)z), 要求 0 <= q < 1
+///
+/// z 先以 θ_1(z + π) = -θ_1(z) 约化到 [-π/2, π/2]. q <= e^(-π) 时直接对 q-级数求和;
+/// q 较大时 q-级数收敛变慢且有相消, 改用虚变换 τ → -1/τ 后的级数 (q = e^(iπτ)), 其收敛速度随 q → 1 而加快.
+/// q 超出 [0, 1) 或 z 为无穷大时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::jacobi_theta1;
+/// assert!((jacobi_theta1(1.0, 0.5) - 1.3303784981792746).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_theta1(z: f64, q: f64) -> f64 {
+    jacobi_theta(1, z, q)
+}
+
+/// 计算 Jacobi theta 函数 θ_2(z, q) = 2 Σ_(n>=0) q^((n+1/2)^2) cos((2n+1)z), 要求 0 <= q < 1
+///
+/// 方法与 `jacobi_theta1` 相同, θ_2(z + π) = -θ_2(z)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::jacobi_theta2;
+/// assert!((jacobi_theta2(1.0, 0.5) - 0.5001981385144562).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_theta2(z: f64, q: f64) -> f64 {
+    jacobi_theta(2, z, q)
+}
+
+/// 计算 Jacobi theta 函数 θ_3(z, q) = 1 + 2 Σ_(n>=1) q^(n^2) cos(2nz), 要求 -1 < q < 1
+///
+/// 方法与 `jacobi_theta1` 相同, θ_3 以 π 为周期. θ_3(z, e^(-π t)) = Σ_(n∈Z) e^(-(z - nπ)^2 / (π t)) / √t
+/// 即圆周上的热核. q < 0 时 θ_3(z, q) = θ_4(z, -q)
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::jacobi_theta3;
+/// assert!((jacobi_theta3(1.0, 0.5) - 0.5058938857304847).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_theta3(z: f64, q: f64) -> f64 {
+    jacobi_theta(3, z, q)
+}
+
+/// 计算 Jacobi theta 函数 θ_4(z, q) = 1 + 2 Σ_(n>=1) (-1)^n q^(n^2) cos(2nz), 要求 -1 < q < 1
+///
+/// 方法与 `jacobi_theta1` 相同, θ_4 以 π 为周期. q < 0 时 θ_4(z, q) = θ_3(z, -q),
+/// 因此 m < 0 时 [nome] 给出的负 nome 也可以直接代入
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::jacobi_theta4;
+/// assert!((jacobi_theta4(1.0, 0.5) - 1.3306863284854333).abs() < 1.0e-15);
+/// ```
+pub fn jacobi_theta4(z: f64, q: f64) -> f64 {
+    jacobi_theta(4, z, q)
+}
+
+/// 计算椭圆 nome q(m) = e^(-π K(1 - m) / K(m)), 要求参数 m <= 1
+///
+/// Jacobi theta 函数与椭圆函数的参数化之间的转换: m = θ_2^4(0, q) / θ_3^4(0, q).
+/// 0 <= m <= 1/2 时使用关于 λ = (1 - √k')/(2(1 + √k')) 的级数, m > 1/2 时由 ln q(m) ln q(1 - m) = π^2 转化,
+/// 在 m → 0 (q ≈ m/16) 与 m → 1 (q → 1) 两端均有相对精度. m < 0 时 q(m) = -q(-m/(1 - m)) 为负数,
+/// 可代入 [jacobi_theta3] 与 [jacobi_theta4], 但不能代入 θ_1, θ_2.
+/// q(0) = 0, q(1) = 1, q(-∞) = -1, m > 1 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::nome;
+/// assert!((nome(0.5) - 0.04321391826377226).abs() < 1.0e-15);
+/// ```
+pub fn nome(m: f64) -> f64 {
+    if m.is_nan() || m > 1.0 {
+        return f64::NAN;
+    }
+    if m == f64::NEG_INFINITY {
+        return -1.0;
+    }
+    if m < 0.0 {
+        // -m/(1 - m) 的补为 1/(1 - m)
+        let p = 1.0 / (1.0 - m);
+        return -nome_complement(-m * p, p);
+    }
+    nome_complement(m, 1.0 - m)
+}
+
+/// 计算 nome 的反函数, 即由 q 求参数 m = θ_2^4(0, q) / θ_3^4(0, q), 要求 -1 <= q <= 1
+///
+/// 0 <= q <= e^(-π) 时直接由 theta 常数的级数计算, q 较大时先作虚变换 q' = e^(π^2 / ln q), 由 1 - m = m(q') 计算;
+/// m → 0 与 m → 1 两端均有相对精度. q < 0 时 m = -m(|q|) / (1 - m(|q|)).
+/// m(0) = 0, m(1) = 1, m(-1) = -∞, |q| > 1 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::theta::inverse_nome;
+/// assert!((inverse_nome(0.04321391826377226) - 0.5).abs() < 1.0e-15);
+/// ```
+pub fn inverse_nome(q: f64) -> f64 {
+    if q.is_nan() || q.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if q == 1.0 {
+        return 1.0;
+    }
+    if q == -1.0 {
+        return f64::NEG_INFINITY;
+    }
+    let (m, p) = inverse_nome_complement(q.abs());
+    if q < 0.0 {
+        -m / p
+    } else {
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::elliptic::ellip_k;
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    #[test]
+    fn test_jacobi_theta1() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_theta1(1.0, 0.5), 1.3303784981792746, tol));
+        assert!(approx_eq(
+            jacobi_theta1(0.3, 0.01),
+            0.18685384758759174,
+            tol
+        ));
+        assert!(approx_eq(jacobi_theta1(2.0, 0.2), 1.2310696165389623, tol));
+        assert!(approx_eq(
+            jacobi_theta1(-1.0, 0.9),
+            -0.24788965933656903,
+            tol
+        ));
+        assert!(approx_eq(
+            jacobi_theta1(10.0, 0.6),
+            -0.3559576427862224,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta1(0.5, 0.99) / 5.0173126615848686e-49,
+            1.0,
+            1.0e-12
+        ));
+        assert_eq!(jacobi_theta1(0.0, 0.5), 0.0);
+        assert_eq!(jacobi_theta1(1.0, 0.0), 0.0);
+        assert!(jacobi_theta1(1.0, 1.0).is_nan());
+        assert!(jacobi_theta1(f64::INFINITY, 0.5).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_theta2() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_theta2(1.0, 0.5), 0.5001981385144562, tol));
+        assert!(approx_eq(jacobi_theta2(0.3, 0.01), 0.6042471615674839, tol));
+        assert!(approx_eq(jacobi_theta2(2.0, 0.2), -0.505291781881642, tol));
+        assert!(approx_eq(
+            jacobi_theta2(-1.0, 0.9) / 0.0004123356010866887,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta2(10.0, 0.6),
+            -1.29756203618383,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta2(0.0, 0.999),
+            56.035895596774616,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            jacobi_theta2(FRAC_PI_2, 0.3) / 6.64928465524366e-17,
+            1.0,
+            1.0e-14
+        ));
+        assert!(jacobi_theta2(1.0, -0.5).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_theta3() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_theta3(1.0, 0.5), 0.5058938857304847, tol));
+        assert!(approx_eq(jacobi_theta3(0.3, 0.01), 1.0165067195453485, tol));
+        assert!(approx_eq(jacobi_theta3(2.0, 0.2), 0.7380778156402692, tol));
+        assert!(approx_eq(
+            jacobi_theta3(-1.0, 0.9) / 0.0004123356010866901,
+            1.0,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta3(10.0, 0.6),
+            1.2975745087052555,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta3(0.0, 0.999),
+            56.035895596774616,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            jacobi_theta3(FRAC_PI_2, 0.3),
+            0.41616064260917474,
+            tol
+        ));
+        assert_eq!(jacobi_theta3(1.0, 0.0), 1.0);
+        assert!(approx_eq(
+            jacobi_theta3(1.0, -0.5),
+            jacobi_theta4(1.0, 0.5),
+            tol
+        ));
+        assert!(jacobi_theta3(f64::NAN, 0.5).is_nan());
+        assert!(jacobi_theta3(1.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_jacobi_theta4() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(jacobi_theta4(1.0, 0.5), 1.3306863284854333, tol));
+        assert!(approx_eq(jacobi_theta4(0.3, 0.01), 0.9834932949489615, tol));
+        assert!(approx_eq(jacobi_theta4(2.0, 0.2), 1.2609909841182512, tol));
+        assert!(approx_eq(
+            jacobi_theta4(-1.0, 0.9),
+            0.24788965933656903,
+            tol
+        ));
+        assert!(approx_eq(
+            jacobi_theta4(10.0, 0.6),
+            0.35656026790652046,
+            1.0e-14
+        ));
+        assert!(approx_eq(
+            jacobi_theta4(0.5, 0.99) / 5.0173126615848686e-49,
+            1.0,
+            1.0e-12
+        ));
+        assert!(approx_eq(
+            jacobi_theta4(FRAC_PI_2, 0.3),
+            1.6162393746095136,
+            tol
+        ));
+        assert_eq!(jacobi_theta4(0.0, 0.999), 0.0);
+        assert!(jacobi_theta4(1.0, 1.5).is_nan());
+    }
+
+    #[test]
+    fn test_nome() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(nome(0.3), 0.022277436157153507, tol));
+        assert!(approx_eq(nome(1.0e-10) / 6.2500000003125e-12, 1.0, tol));
+        assert!(approx_eq(nome(0.999999), 0.5515730190298974, tol));
+        assert!(approx_eq(nome(-2.0), -0.06823782774533833, tol));
+        assert_eq!(nome(0.0), 0.0);
+        assert_eq!(nome(1.0), 1.0);
+        assert_eq!(nome(f64::NEG_INFINITY), -1.0);
+        assert!(nome(1.5).is_nan());
+        for m in [0.1, 0.6, 0.95, -0.7] {
+            assert!(approx_eq(inverse_nome(nome(m)), m, 1.0e-14));
+        }
+        // m < 0 时负的 nome 代回 theta 常数: 1 - m = θ_4^4(0, q) / θ_3^4(0, q), θ_3^2(0, q) = 2K(m)/π
+        for m in [-0.3, -2.0, -50.0] {
+            let q = nome(m);
+            let ratio = jacobi_theta4(0.0, q) / jacobi_theta3(0.0, q);
+            assert!(approx_eq(ratio.powi(4) / (1.0 - m), 1.0, 1.0e-14));
+            let theta3 = jacobi_theta3(0.0, q);
+            assert!(approx_eq(
+                theta3 * theta3 / (2.0 * ellip_k(m) / PI),
+                1.0,
+                1.0e-14
+            ));
+        }
+    }
+
+    #[test]
+    fn test_inverse_nome() {
+        let tol = 1.0e-15;
+        assert!(approx_eq(inverse_nome(0.5), 0.9999895221373104, tol));
+        assert!(approx_eq(inverse_nome(0.99), 1.0, tol));
+        assert!(approx_eq(inverse_nome(1.0e-300) / 1.6e-299, 1.0, tol));
+        assert!(approx_eq(
+            inverse_nome(-0.5) / -95438.31139616248,
+            1.0,
+            1.0e-14
+        ));
+        assert_eq!(inverse_nome(0.0), 0.0);
+        assert_eq!(inverse_nome(1.0), 1.0);
+        assert_eq!(inverse_nome(-1.0), f64::NEG_INFINITY);
+        assert!(inverse_nome(1.5).is_nan());
+    }
+}
@@ -6,7 +6,7 @@ pub(crate) fn sinpi_kernel(x: f64) -> f64 {
     let x_square = x * x;
     let x_forth = x_square * x_square;
     let r = eval_poly(
-        x,
+        x_square,
         &[
             -2.1717412523382308e-5,
             4.662827319453555e-4,
@@ -61,8 +61,8 @@ pub fn sinpi(_x: f64) -> f64 {
         panic!("函数 `sinpi` 只接受有限值的参数");
     }
     let x = _x.abs();
-    // 对于特别大的 x, 返回 0
-    if x >= f64::MAX.floor() {
+    // x >= 2^53 时 x 必为偶数, 返回 0
+    if x >= 9007199254740992.0 {
         return 0.0f64.copysign(_x);
     }
 
@@ -76,7 +76,11 @@ pub fn sinpi(_x: f64) -> f64 {
         2 => 0.0f64 - sinpi_kernel(rx),
         _ => 0.0f64 - cospi_kernel(rx),
     };
-    res.copysign(_x)
+    if _x.is_sign_negative() {
+        -res
+    } else {
+        res
+    }
 }
 
 /// 计算 `cos(pi x)`
@@ -96,9 +100,9 @@ pub fn cospi(_x: f64) -> f64 {
         panic!("函数 `cospi` 只接受有限值的参数");
     }
     let x = _x.abs();
-    // 对于特别大的 x, 返回 1
-    if x >= f64::MAX.floor() {
-        return 1.0f64.copysign(_x);
+    // x >= 2^53 时 x 必为偶数, 返回 1
+    if x >= 9007199254740992.0 {
+        return 1.0;
     }
 
     // 根据正弦函数的周期性，将 x 转化为 [0, 1/2]
@@ -130,9 +134,9 @@ pub fn sincospi(_x: f64) -> (f64, f64) {
         panic!("函数 `sincospi` 只接受有限值的参数");
     }
     let x = _x.abs();
-    // 对于特别大的 x, 返回 1
-    if x >= f64::MAX.floor() {
-        return (0.0f64.copysign(_x), 1.0f64.copysign(_x));
+    // x >= 2^53 时 x 必为偶数, 返回 (0, 1)
+    if x >= 9007199254740992.0 {
+        return (0.0f64.copysign(_x), 1.0);
     }
 
     // 根据正弦函数的周期性，将 x 转化为 [0, 1/2]
@@ -141,11 +145,16 @@ pub fn sincospi(_x: f64) -> (f64, f64) {
     let n = n as i64 & 3i64;
     let si = sinpi_kernel(rx);
     let co = cospi_kernel(rx);
-    match n {
-        0 => (si.copysign(_x), co),
-        1 => (co.copysign(_x), 0.0f64 - si),
-        2 => ((0.0f64 - si).copysign(_x), 0.0f64 - co),
-        _ => ((0.0f64 - co).copysign(_x), si),
+    let (s, c) = match n {
+        0 => (si, co),
+        1 => (co, 0.0f64 - si),
+        2 => (0.0f64 - si, 0.0f64 - co),
+        _ => (0.0f64 - co, si),
+    };
+    if _x.is_sign_negative() {
+        (-s, c)
+    } else {
+        (s, c)
     }
 }
 
@@ -165,6 +174,6 @@ mod tests {
     fn test_cospi() {
         let tol = 1.0e-3;
         assert!(approx_eq(cospi(1.0), -1.0, tol));
-        assert!(approx_eq(sinpi(1.0 / 3.0), 0.5, tol));
+        assert!(approx_eq(cospi(1.0 / 3.0), 0.5, tol));
     }
 }
@@ -1,3 +1,6 @@
+use std::f64::consts::FRAC_PI_2;
+use std::ops::{Add, Mul};
+
 /// 判断两个浮点数在容许误差 `tol` 内是否相等
 #[inline]
 pub fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
@@ -34,12 +37,73 @@ pub fn float_eq(a: f64, b: f64) -> bool {
 ///
 /// ```
 /// use special_functions::utils::eval_poly;
-/// eval_poly(0.5, &[16., 0., 20., 0., 5., 0.]) // 6th first-kind Chebyshev polynomial
+/// eval_poly(0.5, &[16., 0., 20., 0., 5., 0.]); // 6th first-kind Chebyshev polynomial
 /// ```
 pub fn eval_poly(x: f64, arr: &[f64]) -> f64 {
     arr.iter().fold(0.0, |acc, &a| acc * x + a)
 }
 
+/// Clenshaw 算法求 Chebyshev 级数 Σ a_k T_k(x) 的值
+///
+/// # Arguments
+///
+/// - `x`:  自变量的值, 通常位于 [-1, 1]
+/// - `arr`:  级数的系数数组, 按次数升序, 首项不折半
+///
+/// # Example
+///
+/// ```
+/// use special_functions::utils::eval_chebyshev;
+/// eval_chebyshev(0.5, &[0., 0., 0., 0., 0., 0., 1.]); // T_6(0.5) = 1
+/// ```
+pub fn eval_chebyshev(x: f64, arr: &[f64]) -> f64 {
+    let two_x = 2.0 * x;
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &a in arr.iter().skip(1).rev() {
+        (b1, b2) = (two_x.mul_add(b1, a) - b2, b1);
+    }
+    x.mul_add(b1, arr.first().copied().unwrap_or(0.0)) - b2
+}
+
+/// 双指数求积公式的梯形和 h Σ_(|kh| <= range) f(y_k, y_k'), 其中 y = (π/2) sinh s, y' = (π/2) cosh s.
+/// tanh-sinh 与 exp-sinh 公式分别由调用者再取 x = tanh y 与 x = e^y, 并把 dx/ds 乘入 f
+pub(crate) fn double_exponential_sum<T>(
+    step: f64,
+    range: f64,
+    mut f: impl FnMut(f64, f64) -> T,
+) -> T
+where
+    T: Default + Add<Output = T> + Mul<f64, Output = T>,
+{
+    let n = (range / step).floor() as i64;
+    let mut sum = T::default();
+    for k in -n..=n {
+        let s = k as f64 * step;
+        sum = sum + f(FRAC_PI_2 * s.sinh(), FRAC_PI_2 * s.cosh());
+    }
+    sum * step
+}
+
+/// 计算 `ln(1 + x) - x`, 在 `x` 接近零时保持相对精度
+pub(crate) fn log1pmx(x: f64) -> f64 {
+    if x.abs() >= 0.5 {
+        return x.ln_1p() - x;
+    }
+    // 级数 -x^2/2 + x^3/3 - x^4/4 + ...
+    let mut power = x;
+    let mut sum = 0.0;
+    let mut k = 2.0;
+    loop {
+        power *= -x;
+        let term = power / k;
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        k += 1.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,4 +130,34 @@ mod tests {
             1.0e-5,
         ));
     }
+    #[test]
+    fn test_eval_chebyshev() {
+        assert!(approx_eq(
+            eval_chebyshev(0.5, &[0., 0., 0., 0., 0., 0., 1.]),
+            1.0,
+            1.0e-15
+        ));
+        // 1 + 2x + 3(2x^2 - 1) + 4(4x^3 - 3x) 在 x = 0.3 处
+        assert!(approx_eq(
+            eval_chebyshev(0.3, &[1., 2., 3., 4.]),
+            -4.028,
+            1.0e-14
+        ));
+        assert_eq!(eval_chebyshev(0.3, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_double_exponential_sum() {
+        // exp-sinh: ∫_0^∞ e^(-t) dt = 1
+        let value = double_exponential_sum(1.0 / 32.0, 4.0, |y, dy| {
+            let t = y.exp();
+            dy * t * (-t).exp()
+        });
+        assert!(approx_eq(value, 1.0, 1.0e-14));
+        // tanh-sinh: ∫_(-1)^1 dx / (1 + x^2) = π/2
+        let value = double_exponential_sum(1.0 / 32.0, 4.0, |y, dy| {
+            dy / y.cosh().powi(2) / (1.0 + y.tanh().powi(2))
+        });
+        assert!(approx_eq(value, FRAC_PI_2, 1.0e-14));
+    }
 }
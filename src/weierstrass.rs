@@ -0,0 +1,767 @@
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// 复算术-几何平均与格基约化的最多迭代次数
+const WEIERSTRASS_MAX_ITER: usize = 64;
+
+/// 复参数的算术-几何平均 M(a, b). 每步在 ±√(a_n b_n) 中选取满足 |a_(n+1) - b_(n+1)| <= |a_(n+1) + b_(n+1)| 的一个
+/// (最优选取), 此时迭代总是二次收敛
+fn complex_agm(a: Complex64, b: Complex64) -> Complex64 {
+    let (mut a, mut b) = (a, b);
+    for _ in 0..WEIERSTRASS_MAX_ITER {
+        if (a - b).norm() <= f64::EPSILON * a.norm() {
+            break;
+        }
+        let next_a = 0.5 * (a + b);
+        let mut next_b = (a * b).sqrt();
+        if (next_a - next_b).norm() > (next_a + next_b).norm() {
+            next_b = -next_b;
+        }
+        a = next_a;
+        b = next_b;
+    }
+    a
+}
+
+/// 计算 4t^3 - g_2 t - g_3 = 0 的三个根 e_1, e_2, e_3
+///
+/// 由 Cardano 公式给出初值 (取模较大的立方根以避免相消), 再各做两步 Newton 迭代.
+/// 三个根均为实数时按 e_1 > e_2 > e_3 排列
+fn weierstrass_roots(g2: Complex64, g3: Complex64) -> [Complex64; 3] {
+    // t^3 + pt + q = 0
+    let p = -0.25 * g2;
+    let q = -0.25 * g3;
+    let d = (0.25 * q * q + p * p * p / 27.0).sqrt();
+    let s = if (-0.5 * q - d).norm() > (-0.5 * q + d).norm() {
+        -0.5 * q - d
+    } else {
+        -0.5 * q + d
+    };
+    let omega = Complex64::new(-0.5, 0.75f64.sqrt());
+    let mut roots = if s.norm() == 0.0 {
+        [Complex64::new(0.0, 0.0); 3]
+    } else {
+        let c = s.powf(1.0 / 3.0);
+        let c = [c, c * omega, c * omega * omega];
+        c.map(|c| c - p / (3.0 * c))
+    };
+    for root in roots.iter_mut() {
+        for _ in 0..2 {
+            let derivative = 12.0 * *root * *root - g2;
+            if derivative.norm() == 0.0 {
+                break;
+            }
+            *root -= (4.0 * *root * *root * *root - g2 * *root - g3) / derivative;
+        }
+    }
+    if g2.im == 0.0 && g3.im == 0.0 && g2.re.powi(3) > 27.0 * g3.re * g3.re {
+        let mut real = roots.map(|e| e.re);
+        real.sort_by(|a, b| b.total_cmp(a));
+        roots = real.map(|e| Complex64::new(e, 0.0));
+    }
+    roots
+}
+
+/// 将半周期 (ω_1, ω_3) 约化为同一格的一组基, 使 τ = ω_3/ω_1 满足 Im τ > 0, |Re τ| <= 1/2, |τ| >= 1.
+/// 此时 |q| = |e^(iπτ)| <= e^(-π√3/2) ≈ 0.066, theta 级数收敛极快
+fn weierstrass_reduce(omega1: Complex64, omega3: Complex64) -> (Complex64, Complex64) {
+    let (mut w1, mut w3) = (omega1, omega3);
+    if (w3 / w1).im < 0.0 {
+        w3 = -w3;
+    }
+    for _ in 0..WEIERSTRASS_MAX_ITER {
+        let tau = w3 / w1;
+        let n = tau.re.round();
+        w3 -= n * w1;
+        if (tau - n).norm() >= 1.0 - 4.0 * f64::EPSILON {
+            break;
+        }
+        // τ → -1/τ
+        (w1, w3) = (w3, -w1);
+    }
+    (w1, w3)
+}
+
+/// 在 q = e^(iπτ) 下计算 (θ_1(ν), θ_1'(ν), θ_2(ν), θ_3(ν), θ_4(ν)), 其中 q14 = e^(iπτ/4).
+/// 各级数以 q^(n(n+1)) 或 q^(n^2) 的递推求和, 截断条件与 ν 的模无关, 因而在 θ_1 的零点 ν = 0 附近仍有相对精度
+fn theta_series(nu: Complex64, q: Complex64, q14: Complex64) -> [Complex64; 5] {
+    let zero = Complex64::new(0.0, 0.0);
+    let (mut t1, mut t1p, mut t2) = (zero, zero, zero);
+    let (mut t3, mut t4) = (Complex64::new(0.5, 0.0), Complex64::new(0.5, 0.0));
+    // odd = q^(n(n+1)), even = q^(n^2)
+    let mut odd = Complex64::new(1.0, 0.0);
+    let mut even = Complex64::new(1.0, 0.0);
+    let q2 = q * q;
+    // step_odd = q^(2n), step_even = q^(2n - 1)
+    let (mut step_odd, mut step_even) = (Complex64::new(1.0, 0.0), q);
+    let growth = (2.0 * nu.im.abs()).exp();
+    let mut n = 0.0;
+    loop {
+        let arg = (2.0 * n + 1.0) * nu;
+        let (s, c) = (arg.sin(), arg.cos());
+        let sign = if n % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        t1 += sign * odd * s;
+        t1p += sign * (2.0 * n + 1.0) * odd * c;
+        t2 += odd * c;
+        n += 1.0;
+        even *= step_even;
+        step_even *= q2;
+        let c = (2.0 * n * nu).cos();
+        t3 += even * c;
+        t4 -= sign * even * c;
+        step_odd *= q2;
+        odd *= step_odd;
+        let bound = growth.powf(n);
+        if odd.norm() * bound <= 0.5 * f64::EPSILON && even.norm() * bound <= 0.5 * f64::EPSILON {
+            break;
+        }
+    }
+    let scale = 2.0 * q14;
+    [scale * t1, scale * t1p, scale * t2, 2.0 * t3, 2.0 * t4]
+}
+
+/// 由约化后的半周期 (ω_1, ω_3) 计算 (q, q14, θ_2(0), θ_3(0), θ_4(0), η_1), 其中 η_1 = ζ(ω_1).
+/// η_1 = (π^2 / (12 ω_1)) S_3 / S_1, S_k = Σ_(n>=0) (-1)^n (2n + 1)^k q^(n(n+1)), 即 -θ_1'''(0) / θ_1'(0) 的级数
+fn weierstrass_constants(
+    w1: Complex64,
+    w3: Complex64,
+) -> (Complex64, Complex64, [Complex64; 3], Complex64) {
+    let i_pi_tau = Complex64::new(0.0, PI) * (w3 / w1);
+    let q = i_pi_tau.exp();
+    let q14 = (0.25 * i_pi_tau).exp();
+    let [_, _, t2, t3, t4] = theta_series(Complex64::new(0.0, 0.0), q, q14);
+    let (mut s1, mut s3) = (Complex64::new(0.0, 0.0), Complex64::new(0.0, 0.0));
+    let (mut power, mut step) = (Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0));
+    let mut n: f64 = 0.0;
+    while power.norm() * (2.0 * n + 1.0).powi(3) > 0.5 * f64::EPSILON * s3.norm() {
+        let sign = if n % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        let k = 2.0 * n + 1.0;
+        s1 += sign * k * power;
+        s3 += sign * k * k * k * power;
+        n += 1.0;
+        step *= q * q;
+        power *= step;
+    }
+    let eta1 = PI * PI / (12.0 * w1) * s3 / s1;
+    (q, q14, [t2, t3, t4], eta1)
+}
+
+/// 将 z 约化为 z = z_0 + 2mω_1 + 2nω_3, z_0 位于以原点为中心的基本平行四边形内, 返回 (m, n, z_0)
+fn weierstrass_reduce_argument(
+    z: Complex64,
+    w1: Complex64,
+    w3: Complex64,
+) -> (f64, f64, Complex64) {
+    let tau = w3 / w1;
+    let u = z / (2.0 * w1);
+    let y = u.im / tau.im;
+    let x = u.re - y * tau.re;
+    let (m, n) = (x.round(), y.round());
+    (m, n, z - 2.0 * m * w1 - 2.0 * n * w3)
+}
+
+/// Weierstrass 函数族的公共部分. 计算格的约化基与 theta 常数, 约化 z 后返回
+/// (ω_1, ω_3, η_1, m, n, z_0, ν = πz_0/(2ω_1), θ 常数 [θ_2, θ_3, θ_4], θ_n(ν) 的级数值)
+type WeierstrassState = (
+    Complex64,
+    Complex64,
+    Complex64,
+    f64,
+    f64,
+    Complex64,
+    [Complex64; 3],
+    [Complex64; 5],
+);
+
+fn weierstrass_state(z: Complex64, g2: Complex64, g3: Complex64) -> Option<WeierstrassState> {
+    let (omega1, omega3) = weierstrass_half_periods(g2, g3);
+    if omega1.re.is_nan()
+        || z.re.is_nan()
+        || z.im.is_nan()
+        || z.re.is_infinite()
+        || z.im.is_infinite()
+    {
+        return None;
+    }
+    let (w1, w3) = weierstrass_reduce(omega1, omega3);
+    let (q, q14, constants, eta1) = weierstrass_constants(w1, w3);
+    let (m, n, z0) = weierstrass_reduce_argument(z, w1, w3);
+    let theta = theta_series(PI * z0 / (2.0 * w1), q, q14);
+    Some((w1, w3, eta1, m, n, z0, constants, theta))
+}
+
+/// 由不变量 (g_2, g_3) 计算 Weierstrass 椭圆函数的一对半周期 (ω_1, ω_3), 满足 Im(ω_3/ω_1) > 0
+///
+/// 记 e_1, e_2, e_3 为 4t^3 - g_2 t - g_3 的根, 则 ω_1 = π / (2M(√(e_1 - e_3), √(e_1 - e_2))),
+/// ω_3 = iπ / (2M(√(e_1 - e_3), √(e_2 - e_3))), 其中 M 为按最优分支选取的复算术-几何平均 (Cremona–Thongjunthug),
+/// 对任意次序的根都给出周期格 Λ = {2mω_1 + 2nω_3} 的一组基. g_2, g_3 为实数且判别式 g_2^3 - 27g_3^2 > 0 时,
+/// ω_1 为正实数, ω_3 为纯虚数. 判别式为零时格退化, 返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_half_periods;
+/// let (w1, w3) = weierstrass_half_periods(Complex64::new(4.0, 0.0), Complex64::new(0.0, 0.0));
+/// assert!((w1 - Complex64::new(1.3110287771460598, 0.0)).norm() < 1.0e-15);
+/// assert!((w3 - Complex64::new(0.0, 1.3110287771460598)).norm() < 1.0e-15);
+/// ```
+pub fn weierstrass_half_periods(g2: Complex64, g3: Complex64) -> (Complex64, Complex64) {
+    let nan = Complex64::new(f64::NAN, f64::NAN);
+    let finite = |c: Complex64| c.re.is_finite() && c.im.is_finite();
+    if !finite(g2) || !finite(g3) || g2 * g2 * g2 == 27.0 * g3 * g3 {
+        return (nan, nan);
+    }
+    let [e1, e2, e3] = weierstrass_roots(g2, g3);
+    let a = (e1 - e3).sqrt();
+    let omega1 = PI / (2.0 * complex_agm(a, (e1 - e2).sqrt()));
+    let mut omega3 = Complex64::new(0.0, PI) / (2.0 * complex_agm(a, (e2 - e3).sqrt()));
+    if (omega3 / omega1).im < 0.0 {
+        omega3 = -omega3;
+    }
+    (omega1, omega3)
+}
+
+/// 由半周期 (ω_1, ω_3) 计算 Weierstrass 椭圆函数的不变量 (g_2, g_3)
+///
+/// 先将 (ω_1, ω_3) 约化为同一格的基本基, 再由 theta 常数
+/// g_2 = (2/3) (π/(2ω_1))^4 (θ_2^8 + θ_3^8 + θ_4^8), g_3 = (4/27) (π/(2ω_1))^6 (θ_2^4 + θ_3^4)(θ_3^4 + θ_4^4)(θ_4^4 - θ_2^4)
+/// 计算. ω_3/ω_1 为实数 (不构成格) 时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_invariants;
+/// let (g2, g3) = weierstrass_invariants(Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0));
+/// assert!((g2 - Complex64::new(11.817045008077116, 0.0)).norm() < 1.0e-13);
+/// assert!(g3.norm() < 1.0e-13);
+/// ```
+pub fn weierstrass_invariants(omega1: Complex64, omega3: Complex64) -> (Complex64, Complex64) {
+    let nan = Complex64::new(f64::NAN, f64::NAN);
+    let tau = omega3 / omega1;
+    if !(tau.im.is_finite() && tau.re.is_finite()) || tau.im == 0.0 {
+        return (nan, nan);
+    }
+    let (w1, w3) = weierstrass_reduce(omega1, omega3);
+    let (_, _, [t2, t3, t4], _) = weierstrass_constants(w1, w3);
+    let c2 = (PI / (2.0 * w1)).powi(2);
+    let (a, b, c) = (t2.powi(4), t3.powi(4), t4.powi(4));
+    let g2 = 2.0 / 3.0 * c2 * c2 * (a * a + b * b + c * c);
+    let g3 = 4.0 / 27.0 * c2 * c2 * c2 * (a + b) * (b + c) * (c - a);
+    (g2, g3)
+}
+
+/// 计算复参数的 Weierstrass 椭圆函数 ℘(z; g_2, g_3)
+///
+/// 由 `weierstrass_half_periods` 求出周期格后约化为基本基, 将 z 约化到以原点为中心的基本平行四边形, 再由
+/// ℘(z) = e_1 + (π θ_3 θ_4 θ_2(ν) / (2ω_1 θ_1(ν)))^2, ν = πz/(2ω_1), e_1 = (π/(2ω_1))^2 (θ_3^4 + θ_4^4)/3 计算,
+/// 其中 θ_n 为 nome q = e^(iπω_3/ω_1) 下的 Jacobi theta 函数. z 约化后恰为格点 (如 z = 0) 时返回 ∞, 判别式为零时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_p_complex;
+/// let p = weierstrass_p_complex(Complex64::new(1.0, 1.0), Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0));
+/// assert!((p - Complex64::new(-0.2704500073201653, -0.3946855575781917)).norm() < 1.0e-14);
+/// ```
+pub fn weierstrass_p_complex(z: Complex64, g2: Complex64, g3: Complex64) -> Complex64 {
+    let Some((w1, _, _, _, _, _, [_, t3, t4], theta)) = weierstrass_state(z, g2, g3) else {
+        return Complex64::new(f64::NAN, f64::NAN);
+    };
+    let [theta1, _, theta2, _, _] = theta;
+    if theta1.norm() == 0.0 {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+    let c = PI / (2.0 * w1);
+    let e1 = c * c * (t3.powi(4) + t4.powi(4)) / 3.0;
+    let r = c * t3 * t4 * theta2 / theta1;
+    e1 + r * r
+}
+
+/// 计算复参数的 Weierstrass 函数的导数 ℘'(z; g_2, g_3)
+///
+/// 约化方法与 `weierstrass_p_complex` 相同, 由 ℘'(z) = -2 (π/(2ω_1))^3 θ_1'(0)^2 θ_2(ν) θ_3(ν) θ_4(ν) / θ_1(ν)^3 计算,
+/// 其中 θ_1'(0) = θ_2 θ_3 θ_4. z 约化后恰为格点时返回 ∞
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_p_prime_complex;
+/// let dp = weierstrass_p_prime_complex(Complex64::new(1.0, 1.0), Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0));
+/// assert!((dp - Complex64::new(0.12805494473108955, 1.1487021820751284)).norm() < 1.0e-14);
+/// ```
+pub fn weierstrass_p_prime_complex(z: Complex64, g2: Complex64, g3: Complex64) -> Complex64 {
+    let Some((w1, _, _, _, _, _, [t2, t3, t4], theta)) = weierstrass_state(z, g2, g3) else {
+        return Complex64::new(f64::NAN, f64::NAN);
+    };
+    let [theta1, _, theta2, theta3, theta4] = theta;
+    if theta1.norm() == 0.0 {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+    let c = PI / (2.0 * w1);
+    let derivative = t2 * t3 * t4;
+    let r = c / theta1;
+    -2.0 * derivative * derivative * theta2 * theta3 * theta4 * r * r * r
+}
+
+/// 计算复参数的 Weierstrass zeta 函数 ζ(z; g_2, g_3), ζ'(z) = -℘(z)
+///
+/// ζ 不是椭圆函数, 满足 ζ(z + 2ω_j) = ζ(z) + 2η_j, 其中 η_1 = ζ(ω_1) 由 theta 级数计算, η_3 由 Legendre 关系
+/// η_1 ω_3 - η_3 ω_1 = iπ/2 给出. 约化到基本平行四边形后由 ζ(z) = η_1 z/ω_1 + (π/(2ω_1)) θ_1'(ν)/θ_1(ν) 计算.
+/// z 约化后恰为格点时返回 ∞
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_zeta_complex;
+/// let zeta = weierstrass_zeta_complex(Complex64::new(1.0, 1.0), Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0));
+/// assert!((zeta - Complex64::new(0.5889311073718452, -0.47804650509430063)).norm() < 1.0e-14);
+/// ```
+pub fn weierstrass_zeta_complex(z: Complex64, g2: Complex64, g3: Complex64) -> Complex64 {
+    let Some((w1, w3, eta1, m, n, z0, _, theta)) = weierstrass_state(z, g2, g3) else {
+        return Complex64::new(f64::NAN, f64::NAN);
+    };
+    let [theta1, theta1p, _, _, _] = theta;
+    if theta1.norm() == 0.0 {
+        return Complex64::new(f64::INFINITY, 0.0);
+    }
+    let eta3 = (eta1 * w3 - Complex64::new(0.0, FRAC_PI_2)) / w1;
+    eta1 * z0 / w1 + PI / (2.0 * w1) * theta1p / theta1 + 2.0 * m * eta1 + 2.0 * n * eta3
+}
+
+/// 计算复参数的 Weierstrass sigma 函数 σ(z; g_2, g_3), σ'(z)/σ(z) = ζ(z)
+///
+/// σ 为整函数, 满足 σ(z + 2mω_1 + 2nω_3) = (-1)^(m+n+mn) e^((2mη_1 + 2nη_3)(z + mω_1 + nω_3)) σ(z).
+/// 约化到基本平行四边形后由 σ(z) = (2ω_1/π) e^(η_1 z^2/(2ω_1)) θ_1(ν)/θ_1'(0) 计算
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use special_functions::weierstrass::weierstrass_sigma_complex;
+/// let sigma = weierstrass_sigma_complex(Complex64::new(1.0, 1.0), Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0));
+/// assert!((sigma - Complex64::new(0.9975575673907613, 1.0355949931678181)).norm() < 1.0e-14);
+/// ```
+pub fn weierstrass_sigma_complex(z: Complex64, g2: Complex64, g3: Complex64) -> Complex64 {
+    let Some((w1, w3, eta1, m, n, z0, [t2, t3, t4], theta)) = weierstrass_state(z, g2, g3) else {
+        return Complex64::new(f64::NAN, f64::NAN);
+    };
+    let eta3 = (eta1 * w3 - Complex64::new(0.0, FRAC_PI_2)) / w1;
+    let sigma0 = 2.0 * w1 / PI * (eta1 * z0 * z0 / (2.0 * w1)).exp() * theta[0] / (t2 * t3 * t4);
+    if m == 0.0 && n == 0.0 {
+        return sigma0;
+    }
+    let sign = if (m + n + m * n) % 2.0 == 0.0 {
+        1.0
+    } else {
+        -1.0
+    };
+    let eta = 2.0 * m * eta1 + 2.0 * n * eta3;
+    sign * (eta * (z0 + m * w1 + n * w3)).exp() * sigma0
+}
+
+/// 计算实参数的 Weierstrass 椭圆函数 ℘(x; g_2, g_3), 要求 g_2, g_3 为实数
+///
+/// 方法见 `weierstrass_p_complex`. x = 0 时返回 +∞, 判别式为零时返回 NaN
+///
+/// # Example
+///
+/// ```
+/// use special_functions::weierstrass::weierstrass_p;
+/// assert!((weierstrass_p(1.0, 4.0, 1.0) - 1.2516129883651779).abs() < 1.0e-14);
+/// ```
+pub fn weierstrass_p(x: f64, g2: f64, g3: f64) -> f64 {
+    let value = weierstrass_p_complex(
+        Complex64::new(x, 0.0),
+        Complex64::new(g2, 0.0),
+        Complex64::new(g3, 0.0),
+    );
+    value.re
+}
+
+/// 计算实参数的 Weierstrass 函数的导数 ℘'(x; g_2, g_3), 要求 g_2, g_3 为实数
+///
+/// 方法见 `weierstrass_p_prime_complex`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::weierstrass::weierstrass_p_prime;
+/// assert!((weierstrass_p_prime(1.0, 4.0, 1.0) - -1.3551127706374169).abs() < 1.0e-14);
+/// ```
+pub fn weierstrass_p_prime(x: f64, g2: f64, g3: f64) -> f64 {
+    let value = weierstrass_p_prime_complex(
+        Complex64::new(x, 0.0),
+        Complex64::new(g2, 0.0),
+        Complex64::new(g3, 0.0),
+    );
+    value.re
+}
+
+/// 计算实参数的 Weierstrass zeta 函数 ζ(x; g_2, g_3), 要求 g_2, g_3 为实数
+///
+/// 方法见 `weierstrass_zeta_complex`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::weierstrass::weierstrass_zeta;
+/// assert!((weierstrass_zeta(1.0, 4.0, 1.0) - 0.9240149343538123).abs() < 1.0e-14);
+/// ```
+pub fn weierstrass_zeta(x: f64, g2: f64, g3: f64) -> f64 {
+    let value = weierstrass_zeta_complex(
+        Complex64::new(x, 0.0),
+        Complex64::new(g2, 0.0),
+        Complex64::new(g3, 0.0),
+    );
+    value.re
+}
+
+/// 计算实参数的 Weierstrass sigma 函数 σ(x; g_2, g_3), 要求 g_2, g_3 为实数
+///
+/// 方法见 `weierstrass_sigma_complex`
+///
+/// # Example
+///
+/// ```
+/// use special_functions::weierstrass::weierstrass_sigma;
+/// assert!((weierstrass_sigma(1.0, 4.0, 1.0) - 0.9820419042703917).abs() < 1.0e-14);
+/// ```
+pub fn weierstrass_sigma(x: f64, g2: f64, g3: f64) -> f64 {
+    let value = weierstrass_sigma_complex(
+        Complex64::new(x, 0.0),
+        Complex64::new(g2, 0.0),
+        Complex64::new(g3, 0.0),
+    );
+    value.re
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::utils::approx_eq;
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn test_weierstrass_half_periods() {
+        let tol = 1.0e-15;
+        let (w1, w3) = weierstrass_half_periods(c(4.0, 0.0), c(1.0, 0.0));
+        assert!((w1 - c(1.225694690993395, 0.0)).norm() < tol);
+        assert!((w3 - c(0.0, 1.4967293231159797)).norm() < tol);
+        let (w1, w3) = weierstrass_half_periods(c(1.0, 0.0), c(2.0, 0.0));
+        assert!((w1 - c(1.308364762637097, 0.0)).norm() < tol);
+        // ω_3 只确定到格的基变换, 检查 ω_1 + ω_3 也是半周期
+        assert!((w3 - w1 - c(-0.6541823813185484, 1.229368751632915)).norm() < tol);
+        for (g2, g3) in [
+            (c(1.0, 2.0), c(-3.0, 0.5)),
+            (c(-2.0, 0.0), c(0.5, 0.0)),
+            (c(0.0, 3.0), c(1.0, 0.0)),
+        ] {
+            let (w1, w3) = weierstrass_half_periods(g2, g3);
+            assert!((w3 / w1).im > 0.0);
+            let (h2, h3) = weierstrass_invariants(w1, w3);
+            assert!((h2 - g2).norm() < 1.0e-14);
+            assert!((h3 - g3).norm() < 1.0e-14);
+        }
+        assert!(weierstrass_half_periods(c(3.0, 0.0), c(1.0, 0.0))
+            .0
+            .re
+            .is_nan());
+        assert!(weierstrass_half_periods(c(f64::NAN, 0.0), c(1.0, 0.0))
+            .0
+            .re
+            .is_nan());
+    }
+
+    #[test]
+    fn test_weierstrass_invariants() {
+        let tol = 1.0e-13;
+        let (g2, g3) = weierstrass_invariants(c(1.0, 0.0), c(0.5, 1.2));
+        assert!((g2 - c(7.086925944809648, 0.0)).norm() < tol);
+        assert!((g3 - c(5.622311405755444, 0.0)).norm() < tol);
+        let (g2, g3) = weierstrass_invariants(c(2.0, -1.0), c(1.0, 3.0));
+        assert!((g2 - c(-0.08115990476147635, 0.3183352734890155)).norm() < tol);
+        assert!((g3 - c(-0.03346483508736851, 0.009818258405721097)).norm() < tol);
+        let (g2, g3) = weierstrass_invariants(c(1.0, 0.0), c(0.0, 2.0));
+        assert!((g2 - c(8.124218443053017, 0.0)).norm() < tol);
+        assert!((g3 - c(4.44305204494595, 0.0)).norm() < tol);
+        // 同一格的不同基给出相同的不变量
+        let (h2, h3) = weierstrass_invariants(c(1.0, 0.0), c(3.0, 2.0));
+        let (k2, k3) = weierstrass_invariants(c(4.0, 2.0), c(-1.0, 0.0));
+        assert!((h2 - k2).norm() < tol && (h3 - k3).norm() < tol);
+        assert!(weierstrass_invariants(c(1.0, 0.0), c(2.0, 0.0))
+            .0
+            .re
+            .is_nan());
+    }
+
+    #[test]
+    fn test_weierstrass_p_complex() {
+        let tol = 1.0e-14;
+        assert!(
+            (weierstrass_p_complex(c(0.3, -0.7), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(-1.2125393594252867, 1.174412074735727))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_p_complex(c(5.0, 3.0), c(1.0, 0.0), c(2.0, 0.0))
+                - c(0.40476095740449897, -0.3572152486052127))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_p_complex(c(0.1, 0.0), c(0.0, 3.0), c(1.0, 0.0))
+                - c(100.00000356392857, 0.0015000000145930822))
+            .norm()
+                < 1.0e-12
+        );
+        assert!(
+            (weierstrass_p_complex(c(-4.0, -6.0), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(0.24349207093271688, -0.08238369468430798))
+            .norm()
+                < tol
+        );
+        // 周期性与微分方程 ℘'^2 = 4℘^3 - g_2 ℘ - g_3
+        let (g2, g3) = (c(1.0, 2.0), c(-3.0, 0.5));
+        let (w1, w3) = weierstrass_half_periods(g2, g3);
+        let z = c(0.4, 0.2);
+        let p = weierstrass_p_complex(z, g2, g3);
+        assert!((weierstrass_p_complex(z + 2.0 * w1 - 4.0 * w3, g2, g3) - p).norm() < 1.0e-13);
+        let dp = weierstrass_p_prime_complex(z, g2, g3);
+        assert!((dp * dp - 4.0 * p * p * p + g2 * p + g3).norm() < 1.0e-13 * p.norm().powi(3));
+        assert!(weierstrass_p_complex(c(0.0, 0.0), g2, g3).re.is_infinite());
+        assert!(weierstrass_p_complex(c(1.0, 0.0), c(3.0, 0.0), c(1.0, 0.0))
+            .re
+            .is_nan());
+    }
+
+    #[test]
+    fn test_weierstrass_p_prime_complex() {
+        let tol = 1.0e-13;
+        assert!(
+            (weierstrass_p_prime_complex(c(0.3, -0.7), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(4.42347160281005, 1.4247508051592526))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_p_prime_complex(c(5.0, 3.0), c(1.0, 0.0), c(2.0, 0.0))
+                - c(-0.04896254441067684, 1.6618337084760026))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_p_prime_complex(c(0.1, 0.0), c(0.0, 3.0), c(1.0, 0.0))
+                / c(-1999.9998575928557, 0.030000001167100408)
+                - 1.0)
+                .norm()
+                < 1.0e-14
+        );
+        assert!(
+            (weierstrass_p_prime_complex(c(-4.0, -6.0), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(-0.7930254777730593, 0.40042904091987414))
+            .norm()
+                < tol
+        );
+        // ℘' 为奇函数
+        let (g2, g3) = (c(2.0, 1.0), c(-1.0, 0.5));
+        let z = c(0.8, 0.3);
+        assert!(
+            (weierstrass_p_prime_complex(-z, g2, g3) + weierstrass_p_prime_complex(z, g2, g3))
+                .norm()
+                < 1.0e-14
+        );
+    }
+
+    #[test]
+    fn test_weierstrass_zeta_complex() {
+        let tol = 1.0e-14;
+        assert!(
+            (weierstrass_zeta_complex(c(0.3, -0.7), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(0.5355965164371247, 1.2087251573350937))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_zeta_complex(c(5.0, 3.0), c(1.0, 0.0), c(2.0, 0.0))
+                - c(2.7710371958814437, -1.5707107784923817))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_zeta_complex(c(0.1, 0.0), c(0.0, 3.0), c(1.0, 0.0))
+                - c(9.999999928678571, -5.000000016218033e-05))
+            .norm()
+                < tol
+        );
+        assert!(
+            (weierstrass_zeta_complex(c(-4.0, -6.0), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(-1.439290340626024, 2.579597755019561))
+            .norm()
+                < tol
+        );
+        // Legendre 关系 η_1 ω_3 - η_3 ω_1 = iπ/2, η_j = ζ(ω_j)
+        let (g2, g3) = (c(1.0, 2.0), c(-3.0, 0.5));
+        let (w1, w3) = weierstrass_half_periods(g2, g3);
+        let (eta1, eta3) = (
+            weierstrass_zeta_complex(w1, g2, g3),
+            weierstrass_zeta_complex(w3, g2, g3),
+        );
+        assert!((eta1 * w3 - eta3 * w1 - c(0.0, FRAC_PI_2)).norm() < 1.0e-14);
+    }
+
+    #[test]
+    fn test_weierstrass_sigma_complex() {
+        let tol = 1.0e-14;
+        assert!(
+            (weierstrass_sigma_complex(c(0.3, -0.7), c(2.0, 1.0), c(-1.0, 0.5))
+                - c(0.29841371400241035, -0.7020374057360216))
+            .norm()
+                < tol
+        );
+        let sigma = weierstrass_sigma_complex(c(5.0, 3.0), c(1.0, 0.0), c(2.0, 0.0));
+        assert!((sigma / c(-3517.6489753845935, -2107.8044538755003) - 1.0).norm() < tol);
+        assert!(
+            (weierstrass_sigma_complex(c(0.1, 0.0), c(0.0, 3.0), c(1.0, 0.0))
+                - c(0.09999999988100819, -1.250000000135319e-07))
+            .norm()
+                < tol
+        );
+        let sigma = weierstrass_sigma_complex(c(-4.0, -6.0), c(2.0, 1.0), c(-1.0, 0.5));
+        assert!((sigma / c(43663.87920525879, -31265.087039859853) - 1.0).norm() < tol);
+        assert_eq!(
+            weierstrass_sigma_complex(c(0.0, 0.0), c(2.0, 1.0), c(-1.0, 0.5)),
+            c(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_weierstrass_p() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(
+            weierstrass_p(0.5, 1.0, 0.0),
+            4.012513027096228,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p(-2.5, 2.0, -1.0),
+            -0.3895878204372551,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p(7.0, 4.0, 1.0),
+            7.9979274966170335,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            weierstrass_p(3.0, -2.0, 0.5),
+            1.5136286783467487,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p(1.0e-4, 1.0, 1.0) / 99999999.99999999,
+            1.0,
+            tol
+        ));
+        assert_eq!(weierstrass_p(0.0, 4.0, 1.0), f64::INFINITY);
+        assert!(weierstrass_p(1.0, 3.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_weierstrass_p_prime() {
+        let tol = 1.0e-13;
+        assert!(approx_eq(
+            weierstrass_p_prime(0.5, 1.0, 0.0),
+            -15.949843624719085,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p_prime(-2.5, 2.0, -1.0),
+            1.2420350865406395,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p_prime(7.0, 4.0, 1.0),
+            44.87111565055801,
+            1.0e-12
+        ));
+        assert!(approx_eq(
+            weierstrass_p_prime(3.0, -2.0, 0.5),
+            4.049516654292067,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_p_prime(1.0e-4, 1.0, 1.0) / -1999999999999.9998,
+            1.0,
+            1.0e-14
+        ));
+    }
+
+    #[test]
+    fn test_weierstrass_zeta() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(
+            weierstrass_zeta(0.5, 1.0, 0.0),
+            1.9979157363225009,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_zeta(-2.5, 2.0, -1.0),
+            -0.48811335939995554,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_zeta(7.0, 4.0, 1.0),
+            1.1605769463287408,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_zeta(3.0, -2.0, 0.5),
+            0.014321412117099964,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_zeta(1.0e-4, 1.0, 1.0) / 10000.0,
+            1.0,
+            tol
+        ));
+    }
+
+    #[test]
+    fn test_weierstrass_sigma() {
+        let tol = 1.0e-14;
+        assert!(approx_eq(
+            weierstrass_sigma(0.5, 1.0, 0.0),
+            0.4998697795566857,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_sigma(-2.5, 2.0, -1.0),
+            -2.335729901390916,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_sigma(7.0, 4.0, 1.0) / 196926.33559394992,
+            1.0,
+            1.0e-13
+        ));
+        assert!(approx_eq(
+            weierstrass_sigma(3.0, -2.0, 0.5),
+            3.28722565219291,
+            tol
+        ));
+        assert!(approx_eq(
+            weierstrass_sigma(1.0e-4, 1.0, 1.0),
+            1.0e-4,
+            1.0e-18
+        ));
+    }
+}